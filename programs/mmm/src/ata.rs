@@ -3,7 +3,10 @@ use anchor_lang::{
     prelude::*,
     solana_program::program_pack::{IsInitialized, Pack},
 };
-use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::{
+    associated_token::{get_associated_token_address, get_associated_token_address_with_program_id},
+    token_2022::spl_token_2022::{self, extension::StateWithExtensions},
+};
 use spl_associated_token_account::instruction;
 
 fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
@@ -82,3 +85,60 @@ pub fn init_if_needed_ata<'a>(
 
     assert_is_ata(&ata, &authority.key(), &mint.key(), &authority.key())
 }
+
+fn assert_is_ext_ata(
+    ata: &AccountInfo,
+    wallet: &Pubkey,
+    mint: &Pubkey,
+) -> Result<spl_token_2022::state::Account> {
+    assert_owned_by(ata, &spl_token_2022::ID)?;
+    let ata_account =
+        StateWithExtensions::<spl_token_2022::state::Account>::unpack(&ata.data.borrow())?.base;
+    if !ata_account.is_initialized() {
+        return Err(MMMErrorCode::UninitializedAccount.into());
+    }
+    assert_keys_equal(ata_account.owner, *wallet)?;
+    assert_keys_equal(ata_account.mint, *mint)?;
+    assert_keys_equal(
+        get_associated_token_address_with_program_id(wallet, mint, &spl_token_2022::ID),
+        *ata.key,
+    )?;
+    Ok(ata_account)
+}
+
+// init_if_needed_ext_ata is the token-2022 version of init_if_needed_ata,
+// the token account can be larger than Account::LEN because of the extensions
+#[allow(clippy::too_many_arguments)]
+pub fn init_if_needed_ext_ata<'a>(
+    ata: AccountInfo<'a>,
+    payer: AccountInfo<'a>,
+    authority: AccountInfo<'a>,
+    mint: AccountInfo<'a>,
+    associated_token: AccountInfo<'a>,
+    token_program: AccountInfo<'a>,
+    system_program: AccountInfo<'a>,
+    rent: AccountInfo<'a>,
+) -> Result<spl_token_2022::state::Account> {
+    if ata.data.borrow().is_empty() {
+        anchor_lang::solana_program::program::invoke(
+            &instruction::create_associated_token_account(
+                payer.key,
+                authority.key,
+                mint.key,
+                token_program.key,
+            ),
+            &[
+                payer,
+                ata.to_account_info(),
+                authority.to_account_info(),
+                mint.to_account_info(),
+                associated_token,
+                system_program,
+                rent,
+                token_program,
+            ],
+        )?;
+    }
+
+    assert_is_ext_ata(&ata, authority.key, mint.key)
+}
@@ -0,0 +1,244 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, keccak, program::invoke_signed, pubkey},
+};
+use mpl_token_metadata::state::{
+    Collection as MplCollection, Creator as MplCreator, Data, Key, Metadata,
+    TokenStandard as MplTokenStandard, UseMethod as MplUseMethod, Uses as MplUses,
+};
+
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+pub const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+pub const SPL_NOOP_PROGRAM_ID: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+const ASSET_PREFIX: &str = "asset";
+// sha256("global:transfer")[..8]
+const TRANSFER_DISCRIMINATOR: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+
+// The following types are copied from mpl-bubblegum, and they need to
+// be borsh compatible with the MetadataArgs that the leaf data_hash is built from.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    // In percentages, NOT basis points
+    pub share: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct MetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandard>,
+    pub collection: Option<Collection>,
+    pub uses: Option<Uses>,
+    pub token_program_version: TokenProgramVersion,
+    pub creators: Vec<Creator>,
+}
+
+impl MetadataArgs {
+    // data_hash = keccak(keccak(metadata_args) || seller_fee_basis_points)
+    pub fn data_hash(&self) -> Result<[u8; 32]> {
+        let metadata_args_hash = keccak::hashv(&[self.try_to_vec()?.as_slice()]);
+        Ok(keccak::hashv(&[
+            &metadata_args_hash.to_bytes(),
+            &self.seller_fee_basis_points.to_le_bytes(),
+        ])
+        .to_bytes())
+    }
+
+    // creator_hash = keccak(address || verified || share for each creator)
+    pub fn creator_hash(&self) -> [u8; 32] {
+        let creator_data = self
+            .creators
+            .iter()
+            .map(|c| [c.address.as_ref(), &[c.verified as u8], &[c.share]].concat())
+            .collect::<Vec<_>>();
+        keccak::hashv(
+            creator_data
+                .iter()
+                .map(|c| c.as_slice())
+                .collect::<Vec<&[u8]>>()
+                .as_ref(),
+        )
+        .to_bytes()
+    }
+
+    // to_metadata converts the metadata args into the token metadata format, so that
+    // the allowlist and royalty checks can be shared with the token based assets.
+    // Note that the metadata args are only trusted after the leaf is verified by the
+    // bubblegum transfer cpi with the data_hash and creator_hash derived from them.
+    pub fn to_metadata(&self, asset_id: &Pubkey) -> Metadata {
+        Metadata {
+            key: Key::MetadataV1,
+            update_authority: Pubkey::default(),
+            mint: *asset_id,
+            data: Data {
+                name: self.name.clone(),
+                symbol: self.symbol.clone(),
+                uri: self.uri.clone(),
+                seller_fee_basis_points: self.seller_fee_basis_points,
+                creators: Some(
+                    self.creators
+                        .iter()
+                        .map(|c| MplCreator {
+                            address: c.address,
+                            verified: c.verified,
+                            share: c.share,
+                        })
+                        .collect(),
+                ),
+            },
+            primary_sale_happened: self.primary_sale_happened,
+            is_mutable: self.is_mutable,
+            edition_nonce: self.edition_nonce,
+            token_standard: self.token_standard.map(|v| match v {
+                TokenStandard::NonFungible => MplTokenStandard::NonFungible,
+                TokenStandard::FungibleAsset => MplTokenStandard::FungibleAsset,
+                TokenStandard::Fungible => MplTokenStandard::Fungible,
+                TokenStandard::NonFungibleEdition => MplTokenStandard::NonFungibleEdition,
+            }),
+            collection: self.collection.as_ref().map(|c| MplCollection {
+                verified: c.verified,
+                key: c.key,
+            }),
+            uses: self.uses.as_ref().map(|u| MplUses {
+                use_method: match u.use_method {
+                    UseMethod::Burn => MplUseMethod::Burn,
+                    UseMethod::Multiple => MplUseMethod::Multiple,
+                    UseMethod::Single => MplUseMethod::Single,
+                },
+                remaining: u.remaining,
+                total: u.total,
+            }),
+            collection_details: None,
+            programmable_config: None,
+        }
+    }
+}
+
+pub fn get_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            ASSET_PREFIX.as_bytes(),
+            merkle_tree.as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        &BUBBLEGUM_PROGRAM_ID,
+    )
+    .0
+}
+
+pub struct TransferArgs {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+// transfer the leaf from the leaf_owner to the new_leaf_owner, the leaf is verified
+// by the account compression program with the proof accounts, so a successful
+// transfer means that the data_hash and creator_hash match the leaf.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer<'info>(
+    tree_authority: AccountInfo<'info>,
+    leaf_owner: AccountInfo<'info>,
+    leaf_delegate: AccountInfo<'info>,
+    new_leaf_owner: AccountInfo<'info>,
+    merkle_tree: AccountInfo<'info>,
+    log_wrapper: AccountInfo<'info>,
+    compression_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    bubblegum_program: AccountInfo<'info>,
+    proof_accounts: &[AccountInfo<'info>],
+    args: TransferArgs,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*tree_authority.key, false),
+        AccountMeta::new_readonly(*leaf_owner.key, true),
+        AccountMeta::new_readonly(*leaf_delegate.key, false),
+        AccountMeta::new_readonly(*new_leaf_owner.key, false),
+        AccountMeta::new(*merkle_tree.key, false),
+        AccountMeta::new_readonly(*log_wrapper.key, false),
+        AccountMeta::new_readonly(*compression_program.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+    ];
+    accounts.extend(
+        proof_accounts
+            .iter()
+            .map(|proof| AccountMeta::new_readonly(*proof.key, false)),
+    );
+
+    let mut data = TRANSFER_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&args.root);
+    data.extend_from_slice(&args.data_hash);
+    data.extend_from_slice(&args.creator_hash);
+    data.extend_from_slice(&args.nonce.to_le_bytes());
+    data.extend_from_slice(&args.index.to_le_bytes());
+
+    let mut account_infos = vec![
+        tree_authority,
+        leaf_owner,
+        leaf_delegate,
+        new_leaf_owner,
+        merkle_tree,
+        log_wrapper,
+        compression_program,
+        system_program,
+        bubblegum_program.clone(),
+    ];
+    account_infos.extend_from_slice(proof_accounts);
+
+    invoke_signed(
+        &Instruction {
+            program_id: *bubblegum_program.key,
+            accounts,
+            data,
+        },
+        &account_infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
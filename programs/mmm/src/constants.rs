@@ -1,9 +1,23 @@
 pub const BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX: &str = "mmm_buyside_sol_escrow_account";
 pub const POOL_PREFIX: &str = "mmm_pool";
 pub const SELL_STATE_PREFIX: &str = "mmm_sell_state";
+pub const GLOBAL_CONFIG_PREFIX: &str = "mmm_global_config";
+pub const OVERFLOW_ALLOWLIST_PREFIX: &str = "mmm_overflow_allowlist";
+pub const ALLOWLIST_REGISTRY_PREFIX: &str = "mmm_allowlist_registry";
 
 pub const MAX_TOTAL_PRICE: u64 = 8_000_000 * 1_000_000_000; // 8_000_000 SOL
 pub const MAX_METADATA_CREATOR_ROYALTY_BP: u16 = 3000;
 pub const MAX_REFERRAL_FEE_BP: i16 = 500;
 pub const MAX_LP_FEE_BP: u16 = 1000;
+pub const MAX_PROTOCOL_FEE_BP: u16 = 1000;
+pub const MAX_ALLOWLIST_PRICE_MULTIPLIER_BP: u16 = 20000;
 pub const ALLOWLIST_MAX_LEN: usize = 6;
+pub const OVERFLOW_ALLOWLIST_MAX_LEN: usize = 64;
+pub const ALLOWLIST_REGISTRY_MAX_LEN: usize = 64;
+pub const PRICE_LADDER_MAX_LEN: usize = 6;
+pub const COSIGNER_ROTATION_TIMELOCK: i64 = 7 * 24 * 60 * 60; // 7 days
+// 1: the layout when the version was added
+// 2: allowlist price multipliers, price ladder, dutch auction and asymmetric deltas
+pub const POOL_VERSION: u8 = 2;
+pub const SELL_STATE_VERSION: u8 = 1;
+pub const ALLOWLIST_REGISTRY_VERSION: u8 = 1;
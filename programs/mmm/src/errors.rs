@@ -60,4 +60,36 @@ pub enum MMMErrorCode {
     InvalidTokenStandard, // 0x178b
     #[msg("Unexpected metadata uri")]
     UnexpectedMetadataUri, // 0x178c
+    #[msg("Invalid token extension")]
+    InvalidTokenExtension, // 0x178d
+    #[msg("Invalid transfer hook accounts")]
+    InvalidTransferHookAccounts, // 0x178e
+    #[msg("Invalid mpl core asset")]
+    InvalidMplCoreAsset, // 0x178f
+    #[msg("Invalid mpl core collection")]
+    InvalidMplCoreCollection, // 0x1790
+    #[msg("Invalid pending owner")]
+    InvalidPendingOwner, // 0x1791
+    #[msg("Cosigner rotation is timelocked")]
+    CosignerRotationTimelocked, // 0x1792
+    #[msg("Invalid operator")]
+    InvalidOperator, // 0x1793
+    #[msg("Pool is paused")]
+    PoolPaused, // 0x1794
+    #[msg("Pool is not started yet")]
+    PoolNotStarted, // 0x1795
+    #[msg("Program is paused")]
+    ProgramPaused, // 0x1796
+    #[msg("Invalid treasury")]
+    InvalidTreasury, // 0x1797
+    #[msg("Invalid admin")]
+    InvalidAdmin, // 0x1798
+    #[msg("Invalid curator")]
+    InvalidCurator, // 0x1799
+    #[msg("Invalid price ladder")]
+    InvalidPriceLadder, // 0x179a
+    #[msg("Invalid dutch auction")]
+    InvalidDutchAuction, // 0x179b
+    #[msg("Invalid price multiplier")]
+    InvalidPriceMultiplier, // 0x179c
 }
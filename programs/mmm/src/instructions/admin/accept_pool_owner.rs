@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, errors::MMMErrorCode, state::Pool, util::*};
+
+#[derive(Accounts)]
+pub struct AcceptPoolOwner<'info> {
+    pub new_owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.pending_owner.ne(&Pubkey::default()) @ MMMErrorCode::InvalidPendingOwner,
+        constraint = pool.pending_owner.eq(new_owner.key) @ MMMErrorCode::InvalidPendingOwner,
+        constraint = pool.referral.ne(new_owner.key) @ MMMErrorCode::InvalidReferral,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler(ctx: Context<AcceptPoolOwner>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // the pool pda stays derived from the owner that created the pool, so the
+    // escrow accounts that are owned by the pool pda don't need to be migrated.
+    if pool.original_owner == Pubkey::default() {
+        pool.original_owner = pool.owner;
+    }
+    pool.owner = ctx.accounts.new_owner.key();
+    pool.pending_owner = Pubkey::default();
+    // the delegations of the previous owner don't carry over to the new owner
+    pool.operator = Pubkey::default();
+    pool.pending_cosigner = Pubkey::default();
+    pool.pending_no_cosigner = false;
+    pool.cosigner_rotation_requested_at = 0;
+    log_pool("post_accept_pool_owner", pool)?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Allowlist, AllowlistRegistry},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAllowlistRegistryArgs {
+    pub uuid: Pubkey, // randomly generated keypair
+    pub entries: Vec<Allowlist>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:CreateAllowlistRegistryArgs)]
+pub struct CreateAllowlistRegistry<'info> {
+    #[account(mut)]
+    pub curator: Signer<'info>,
+    #[account(
+        init,
+        payer = curator,
+        seeds = [ALLOWLIST_REGISTRY_PREFIX.as_bytes(), args.uuid.as_ref()],
+        bump,
+        space = AllowlistRegistry::LEN,
+        constraint = args.entries.len() <= ALLOWLIST_REGISTRY_MAX_LEN @ MMMErrorCode::InvalidAllowLists,
+        constraint = args.entries.iter().all(|entry| entry.valid_registry_entry()) @ MMMErrorCode::InvalidAllowLists,
+    )]
+    pub registry: Box<Account<'info, AllowlistRegistry>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateAllowlistRegistry>,
+    args: CreateAllowlistRegistryArgs,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    registry.curator = ctx.accounts.curator.key();
+    registry.uuid = args.uuid;
+    registry.revision = 0;
+    registry.version = ALLOWLIST_REGISTRY_VERSION;
+    registry.entries = args.entries;
+
+    Ok(())
+}
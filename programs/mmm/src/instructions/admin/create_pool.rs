@@ -3,7 +3,9 @@ use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 use crate::{
     constants::*,
     errors::MMMErrorCode,
-    state::{Allowlist, Pool},
+    state::{
+        Allowlist, DutchAuction, GlobalConfig, Pool, PriceLadderStep, CURVE_KIND_CONSTANT_PRODUCT,
+    },
     util::*,
 };
 
@@ -13,13 +15,22 @@ pub struct CreatePoolArgs {
     pub spot_price: u64,
     pub curve_type: u8,
     pub curve_delta: u64,
+    // the delta of the sell side if it's different from curve_delta
+    pub sell_curve_delta: Option<u64>,
     pub reinvest_fulfill_buy: bool,
     pub reinvest_fulfill_sell: bool,
     pub expiry: i64,
+    pub start_time: i64,
+    pub buyside_expiry: i64,
+    pub sellside_expiry: i64,
     pub lp_fee_bp: u16,
     pub referral: Pubkey,
     pub cosigner_annotation: [u8; 32],
     pub buyside_creator_royalty_bp: u16,
+    // the steps of the price ladder curve, all empty for the other curves
+    pub price_ladder: [PriceLadderStep; PRICE_LADDER_MAX_LEN],
+    // the decay of the dutch auction curve, required by that curve only
+    pub dutch_auction: Option<DutchAuction>,
 
     // immutable
     pub uuid: Pubkey, // randomly generated keypair
@@ -40,13 +51,18 @@ pub struct CreatePool<'info> {
         seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), args.uuid.as_ref()],
         bump,
         space = Pool::LEN,
-        constraint = args.lp_fee_bp <= MAX_LP_FEE_BP @ MMMErrorCode::InvalidBP,
         constraint = args.buyside_creator_royalty_bp <= 10000 @ MMMErrorCode::InvalidBP,
-        constraint = args.spot_price > 0 @ MMMErrorCode::InvalidSpotPrice,
-        constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint, // remove this when we have spl token support
+        // the constant product spot_price is derived from the reserves, see refresh_constant_product_spot_price
+        constraint = args.spot_price > 0 || args.curve_type == CURVE_KIND_CONSTANT_PRODUCT @ MMMErrorCode::InvalidSpotPrice,
         constraint = args.referral.ne(owner.key) @ MMMErrorCode::InvalidReferral,
     )]
     pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = args.lp_fee_bp <= global_config.max_lp_fee_bp @ MMMErrorCode::InvalidBP,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
     pub system_program: Program<'info, System>,
 }
 
@@ -65,10 +81,17 @@ pub fn handler(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Result<()> {
     pool.reinvest_fulfill_buy = args.reinvest_fulfill_buy;
     pool.reinvest_fulfill_sell = args.reinvest_fulfill_sell;
     pool.expiry = args.expiry;
+    pool.start_time = args.start_time;
+    pool.buyside_expiry = args.buyside_expiry;
+    pool.sellside_expiry = args.sellside_expiry;
     pool.lp_fee_bp = args.lp_fee_bp;
     pool.referral = args.referral;
     pool.cosigner_annotation = args.cosigner_annotation;
     pool.buyside_creator_royalty_bp = args.buyside_creator_royalty_bp;
+    set_price_ladder(pool, Some(args.price_ladder))?;
+    if let Some(dutch_auction) = args.dutch_auction {
+        pool.dutch_auction = dutch_auction;
+    }
 
     // state variables
     pool.sellside_asset_amount = 0; // always equal to the number of NFTs in the pool
@@ -81,6 +104,10 @@ pub fn handler(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Result<()> {
     pool.uuid = args.uuid;
     pool.payment_mint = args.payment_mint;
     pool.allowlists = args.allowlists;
+    pool.version = POOL_VERSION;
+    set_sell_curve_delta(pool, args.sell_curve_delta)?;
+    reset_dutch_auction(pool)?;
+    refresh_constant_product_spot_price(pool)?;
 
     log_pool("post_create_pool", pool)?;
 
@@ -0,0 +1,51 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, program::Mmm, state::GlobalConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitGlobalConfigArgs {
+    pub protocol_fee_bp: u16,
+}
+
+// InitGlobalConfig can only be called once by the upgrade authority of the program,
+// who becomes the admin of the global config.
+#[derive(Accounts)]
+#[instruction(args:InitGlobalConfigArgs)]
+pub struct InitGlobalConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        space = GlobalConfig::LEN,
+        constraint = args.protocol_fee_bp <= MAX_PROTOCOL_FEE_BP @ MMMErrorCode::InvalidBP,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: the treasury only receives the protocol fees, and it needs to be rent exempt
+    /// so that the fees below the rent exempt minimum can still be transferred to it
+    #[account(
+        constraint = treasury.key().ne(&Pubkey::default()) @ MMMErrorCode::InvalidTreasury,
+        constraint = Rent::get()?.is_exempt(treasury.lamports(), treasury.data_len()) @ MMMErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ MMMErrorCode::InvalidAdmin)]
+    pub program: Program<'info, Mmm>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ MMMErrorCode::InvalidAdmin)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitGlobalConfig>, args: InitGlobalConfigArgs) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    global_config.admin = ctx.accounts.admin.key();
+    global_config.paused = false;
+    global_config.protocol_fee_bp = args.protocol_fee_bp;
+    global_config.treasury = ctx.accounts.treasury.key();
+    global_config.max_lp_fee_bp = MAX_LP_FEE_BP;
+    global_config.max_referral_fee_bp = MAX_REFERRAL_FEE_BP;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::Pool,
+    util::{log_pool, realloc_account},
+};
+
+// MigratePool moves the pool to the current layout, and it reallocs the pool if
+// the layout grows. It's permissionless because it never changes the meaning of
+// the pool, and the payer only pays for the extra rent.
+#[derive(Accounts)]
+pub struct MigratePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: checked by the owner and the discriminator in the handler, because the
+    /// pool might be shorter than Pool::LEN before the realloc
+    #[account(mut, owner = crate::ID)]
+    pub pool: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePool>) -> Result<()> {
+    let pool_info = ctx.accounts.pool.to_account_info();
+    if pool_info.data_len() < 8 || pool_info.try_borrow_data()?[..8] != Pool::DISCRIMINATOR {
+        return Err(MMMErrorCode::InvalidAccountState.into());
+    }
+
+    realloc_account(
+        &pool_info,
+        Pool::LEN,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut pool = Pool::try_deserialize(&mut &pool_info.try_borrow_data()?[..])?;
+    // v0 -> v1 -> v2: the fields added since v0 were all carved out of the zeroed
+    // padding, and their zero values keep the old behavior, e.g. a zero multiplier
+    // doesn't scale the price and the sell side moves by curve_delta unless
+    // asymmetric_curve_delta is set, so only the version is set.
+    if pool.version < POOL_VERSION {
+        pool.version = POOL_VERSION;
+    }
+    pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+    log_pool("post_migrate_pool", &pool)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{constants::*, errors::MMMErrorCode, state::SellState, util::realloc_account};
+
+// MigrateSellState is the same as MigratePool, but for the sell state.
+#[derive(Accounts)]
+pub struct MigrateSellState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: checked by the owner and the discriminator in the handler, because the
+    /// sell state might be shorter than SellState::LEN before the realloc
+    #[account(mut, owner = crate::ID)]
+    pub sell_state: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateSellState>) -> Result<()> {
+    let sell_state_info = ctx.accounts.sell_state.to_account_info();
+    if sell_state_info.data_len() < 8
+        || sell_state_info.try_borrow_data()?[..8] != SellState::DISCRIMINATOR
+    {
+        return Err(MMMErrorCode::InvalidAccountState.into());
+    }
+
+    realloc_account(
+        &sell_state_info,
+        SellState::LEN,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut sell_state = SellState::try_deserialize(&mut &sell_state_info.try_borrow_data()?[..])?;
+    // v0 -> v1: only the version is added, carved out of the zeroed padding.
+    if sell_state.version < SELL_STATE_VERSION {
+        sell_state.version = SELL_STATE_VERSION;
+    }
+    sell_state.try_serialize(&mut &mut sell_state_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -1,9 +1,43 @@
 #![allow(missing_docs)]
 
+pub mod accept_pool_owner;
+pub mod create_allowlist_registry;
 pub mod create_pool;
+pub mod init_global_config;
+pub mod migrate_pool;
+pub mod migrate_sell_state;
+pub mod operator_update_pool;
+pub mod propose_pool_owner;
+pub mod set_global_pause;
+pub mod set_pool_pause;
 pub mod sol_close_pool;
+pub mod spl_close_pool;
+pub mod sync_sell_state_owner;
+pub mod update_allowlist_registry;
+pub mod update_allowlists;
+pub mod update_global_config;
+pub mod update_overflow_allowlist;
 pub mod update_pool;
+pub mod update_pool_cosigner;
+pub mod update_pool_operator;
 
+pub use accept_pool_owner::*;
+pub use create_allowlist_registry::*;
 pub use create_pool::*;
+pub use init_global_config::*;
+pub use migrate_pool::*;
+pub use migrate_sell_state::*;
+pub use operator_update_pool::*;
+pub use propose_pool_owner::*;
+pub use set_global_pause::*;
+pub use set_pool_pause::*;
 pub use sol_close_pool::*;
+pub use spl_close_pool::*;
+pub use sync_sell_state_owner::*;
+pub use update_allowlist_registry::*;
+pub use update_allowlists::*;
+pub use update_global_config::*;
+pub use update_overflow_allowlist::*;
 pub use update_pool::*;
+pub use update_pool_cosigner::*;
+pub use update_pool_operator::*;
@@ -0,0 +1,73 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{GlobalConfig, Pool},
+    util::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OperatorUpdatePoolArgs {
+    pub spot_price: u64,
+    pub curve_delta: u64,
+    // the delta of the sell side if it's different from curve_delta
+    pub sell_curve_delta: Option<u64>,
+    pub lp_fee_bp: u16,
+    pub expiry: i64,
+    pub start_time: i64,
+    pub buyside_expiry: i64,
+    pub sellside_expiry: i64,
+    pub cosigner_annotation: [u8; 32],
+}
+
+// OperatorUpdatePool is the restricted update_pool for the pool operator, e.g. a
+// repricing bot. The operator can only update the pricing related fields, and
+// there are no other instructions that the operator can sign for, so it can never
+// withdraw the pool assets and payments, or change the owner.
+#[derive(Accounts)]
+#[instruction(args:OperatorUpdatePoolArgs)]
+pub struct OperatorUpdatePool<'info> {
+    pub operator: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = operator @ MMMErrorCode::InvalidOperator,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.operator.ne(&Pubkey::default()) @ MMMErrorCode::InvalidOperator,
+        constraint = args.spot_price > 0 @ MMMErrorCode::InvalidSpotPrice,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = args.lp_fee_bp <= global_config.max_lp_fee_bp @ MMMErrorCode::InvalidBP,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+}
+
+pub fn handler(ctx: Context<OperatorUpdatePool>, args: OperatorUpdatePoolArgs) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    check_curve(pool.curve_type, args.curve_delta)?;
+
+    pool.spot_price = args.spot_price;
+    pool.curve_delta = args.curve_delta;
+    pool.lp_fee_bp = args.lp_fee_bp;
+    pool.expiry = args.expiry;
+    pool.start_time = args.start_time;
+    pool.buyside_expiry = args.buyside_expiry;
+    pool.sellside_expiry = args.sellside_expiry;
+    pool.cosigner_annotation = args.cosigner_annotation;
+    // the price ladder stays as is, only the cached spot_price is restored
+    set_price_ladder(pool, None)?;
+    set_sell_curve_delta(pool, args.sell_curve_delta)?;
+    reset_dutch_auction(pool)?;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_operator_update_pool", pool)?;
+
+    Ok(())
+}
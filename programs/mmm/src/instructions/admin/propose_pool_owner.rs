@@ -0,0 +1,36 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, state::Pool, util::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposePoolOwnerArgs {
+    // Pubkey::default() cancels the pending ownership transfer
+    pub new_owner: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args:ProposePoolOwnerArgs)]
+pub struct ProposePoolOwner<'info> {
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        constraint = args.new_owner.ne(owner.key) @ MMMErrorCode::InvalidPendingOwner,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler(ctx: Context<ProposePoolOwner>, args: ProposePoolOwnerArgs) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    pool.pending_owner = args.new_owner;
+    log_pool("post_propose_pool_owner", pool)?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, state::GlobalConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetGlobalPauseArgs {
+    pub paused: bool,
+}
+
+// SetGlobalPause is the emergency switch of the admin, which pauses all the
+// fulfill instructions of every pool.
+#[derive(Accounts)]
+#[instruction(args:SetGlobalPauseArgs)]
+pub struct SetGlobalPause<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        has_one = admin @ MMMErrorCode::InvalidAdmin,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+}
+
+pub fn handler(ctx: Context<SetGlobalPause>, args: SetGlobalPauseArgs) -> Result<()> {
+    ctx.accounts.global_config.paused = args.paused;
+    msg!("{{\"global_paused\":{}}}", args.paused);
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, state::Pool, util::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetPoolPauseArgs {
+    pub buyside_paused: bool,
+    pub sellside_paused: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SetPoolPauseArgs)]
+pub struct SetPoolPause<'info> {
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler(ctx: Context<SetPoolPause>, args: SetPoolPauseArgs) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    pool.buyside_paused = args.buyside_paused;
+    pool.sellside_paused = args.sellside_paused;
+    log_pool("post_set_pool_pause", pool)?;
+
+    Ok(())
+}
@@ -6,15 +6,17 @@ use crate::{constants::*, errors::MMMErrorCode, state::Pool};
 pub struct SolClosePool<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
         constraint = pool.sellside_asset_amount == 0 @ MMMErrorCode::NotEmptySellsideAssetAmount,
         bump,
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         close = owner
     )]
     pub pool: Box<Account<'info, Pool>>,
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{Token, TokenAccount},
+};
+
+use crate::{constants::*, errors::MMMErrorCode, state::Pool};
+
+#[derive(Accounts)]
+pub struct SplClosePool<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        constraint = pool.payment_mint.ne(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.sellside_asset_amount == 0 @ MMMErrorCode::NotEmptySellsideAssetAmount,
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        close = owner
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: it's the ata of the pool for the payment_mint, and might have been closed already
+    #[account(
+        mut,
+        address = get_associated_token_address(&pool.key(), &pool.payment_mint) @ MMMErrorCode::PubkeyMismatch,
+    )]
+    pub buyside_payment_escrow_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<SplClosePool>) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let pool = &ctx.accounts.pool;
+    let token_program = &ctx.accounts.token_program;
+    let buyside_payment_escrow_account = &ctx.accounts.buyside_payment_escrow_account;
+
+    if buyside_payment_escrow_account.data_is_empty() {
+        return Ok(());
+    }
+
+    let escrow = TokenAccount::try_deserialize(
+        &mut buyside_payment_escrow_account.data.borrow().as_ref(),
+    )?;
+    if escrow.amount != 0 {
+        return Err(MMMErrorCode::NotEmptyEscrowAccount.into());
+    }
+
+    anchor_spl::token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        anchor_spl::token::CloseAccount {
+            account: buyside_payment_escrow_account.to_account_info(),
+            destination: owner.to_account_info(),
+            authority: pool.to_account_info(),
+        },
+        // seeds should be the PDA of 'pool'
+        &[&[
+            POOL_PREFIX.as_bytes(),
+            pool.seed_owner().as_ref(),
+            pool.uuid.as_ref(),
+            &[*ctx.bumps.get("pool").unwrap()],
+        ]],
+    ))?;
+
+    Ok(())
+}
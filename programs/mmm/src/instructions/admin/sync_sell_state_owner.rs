@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Pool, SellState},
+};
+
+// SyncSellStateOwner updates the pool_owner of the sell states, which are passed
+// in as remaining accounts, to the current pool owner after an ownership transfer.
+// It's permissionless because it only copies the owner from the pool.
+#[derive(Accounts)]
+pub struct SyncSellStateOwner<'info> {
+    #[account(
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SyncSellStateOwner<'info>>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    for sell_state_info in ctx.remaining_accounts.iter() {
+        let mut sell_state = Account::<SellState>::try_from(sell_state_info)?;
+        if sell_state.pool != pool.key() {
+            return Err(MMMErrorCode::InvalidAccountState.into());
+        }
+        sell_state.pool_owner = pool.owner;
+        sell_state.exit(ctx.program_id)?;
+    }
+
+    Ok(())
+}
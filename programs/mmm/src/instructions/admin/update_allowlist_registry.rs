@@ -0,0 +1,50 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Allowlist, AllowlistRegistry},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAllowlistRegistryArgs {
+    pub new_curator: Pubkey,
+    pub entries: Vec<Allowlist>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:UpdateAllowlistRegistryArgs)]
+pub struct UpdateAllowlistRegistry<'info> {
+    pub curator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [ALLOWLIST_REGISTRY_PREFIX.as_bytes(), registry.uuid.as_ref()],
+        bump,
+        has_one = curator @ MMMErrorCode::InvalidCurator,
+        constraint = args.new_curator.ne(&Pubkey::default()) @ MMMErrorCode::InvalidCurator,
+        constraint = args.entries.len() <= ALLOWLIST_REGISTRY_MAX_LEN @ MMMErrorCode::InvalidAllowLists,
+        constraint = args.entries.iter().all(|entry| entry.valid_registry_entry()) @ MMMErrorCode::InvalidAllowLists,
+    )]
+    pub registry: Box<Account<'info, AllowlistRegistry>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateAllowlistRegistry>,
+    args: UpdateAllowlistRegistryArgs,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+
+    registry.curator = args.new_curator;
+    registry.entries = args.entries;
+    registry.revision = registry
+        .revision
+        .checked_add(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    msg!(
+        "{{\"registry\":\"{}\",\"revision\":{}}}",
+        registry.key(),
+        registry.revision
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Allowlist, Pool},
+    util::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAllowlistsArgs {
+    pub allowlists: [Allowlist; ALLOWLIST_MAX_LEN],
+    // the price multiplier of each entry in allowlists, 0 means no scaling, and the
+    // others are at most MAX_ALLOWLIST_PRICE_MULTIPLIER_BP
+    pub allowlist_price_multipliers_bp: [u16; ALLOWLIST_MAX_LEN],
+}
+
+#[derive(Accounts)]
+#[instruction(args:UpdateAllowlistsArgs)]
+pub struct UpdateAllowlists<'info> {
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        constraint = args.allowlist_price_multipliers_bp.iter().all(|bp| *bp <= MAX_ALLOWLIST_PRICE_MULTIPLIER_BP) @ MMMErrorCode::InvalidPriceMultiplier,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler(ctx: Context<UpdateAllowlists>, args: UpdateAllowlistsArgs) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    check_allowlists(&args.allowlists)?;
+
+    // the assets that are already in the pool are not affected, they can still be
+    // withdrawn or bought even if they no longer match the allowlists.
+    pool.allowlists = args.allowlists;
+    pool.allowlist_price_multipliers_bp = args.allowlist_price_multipliers_bp;
+    log_pool("post_update_allowlists", pool)?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, state::GlobalConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGlobalConfigArgs {
+    pub new_admin: Pubkey,
+    pub protocol_fee_bp: u16,
+    pub max_lp_fee_bp: u16,
+    pub max_referral_fee_bp: i16,
+}
+
+#[derive(Accounts)]
+#[instruction(args:UpdateGlobalConfigArgs)]
+pub struct UpdateGlobalConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        has_one = admin @ MMMErrorCode::InvalidAdmin,
+        constraint = args.new_admin.ne(&Pubkey::default()) @ MMMErrorCode::InvalidAdmin,
+        constraint = args.protocol_fee_bp <= MAX_PROTOCOL_FEE_BP @ MMMErrorCode::InvalidBP,
+        constraint = args.max_lp_fee_bp <= 10000 @ MMMErrorCode::InvalidBP,
+        constraint = (0..=10000).contains(&args.max_referral_fee_bp) @ MMMErrorCode::InvalidBP,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: the same rent exempt treasury as in init_global_config
+    #[account(
+        constraint = treasury.key().ne(&Pubkey::default()) @ MMMErrorCode::InvalidTreasury,
+        constraint = Rent::get()?.is_exempt(treasury.lamports(), treasury.data_len()) @ MMMErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateGlobalConfig>, args: UpdateGlobalConfigArgs) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    global_config.admin = args.new_admin;
+    global_config.protocol_fee_bp = args.protocol_fee_bp;
+    global_config.treasury = ctx.accounts.treasury.key();
+    global_config.max_lp_fee_bp = args.max_lp_fee_bp;
+    global_config.max_referral_fee_bp = args.max_referral_fee_bp;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Allowlist, OverflowAllowlist, Pool, ALLOWLIST_KIND_REGISTRY},
+    util::*,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateOverflowAllowlistArgs {
+    // an empty allowlists closes the overflow allowlist and refunds the rent
+    pub allowlists: Vec<Allowlist>,
+}
+
+#[derive(Accounts)]
+#[instruction(args:UpdateOverflowAllowlistArgs)]
+pub struct UpdateOverflowAllowlist<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [OVERFLOW_ALLOWLIST_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+        space = OverflowAllowlist::LEN,
+        constraint = args.allowlists.len() <= OVERFLOW_ALLOWLIST_MAX_LEN @ MMMErrorCode::InvalidAllowLists,
+        constraint = args.allowlists.iter().all(|allowlist| allowlist.valid_overflow_entry()) @ MMMErrorCode::InvalidAllowLists,
+    )]
+    pub overflow_allowlist: Box<Account<'info, OverflowAllowlist>>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateOverflowAllowlist>,
+    args: UpdateOverflowAllowlistArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let pool = &ctx.accounts.pool;
+    let overflow_allowlist = &mut ctx.accounts.overflow_allowlist;
+
+    if args.allowlists.is_empty() {
+        return overflow_allowlist.close(owner.to_account_info());
+    }
+
+    // the pools with a registry resolve the aux entries from the registry only
+    if pool
+        .allowlists
+        .iter()
+        .any(|allowlist| allowlist.kind == ALLOWLIST_KIND_REGISTRY)
+    {
+        msg!("InvalidAllowLists: overflow allowlist of a pool with a registry");
+        return Err(MMMErrorCode::InvalidAllowLists.into());
+    }
+    check_allowlists(&args.allowlists)?;
+    overflow_allowlist.pool = pool.key();
+    overflow_allowlist.allowlists = args.allowlists;
+
+    Ok(())
+}
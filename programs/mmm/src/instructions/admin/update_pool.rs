@@ -1,6 +1,11 @@
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 
-use crate::{constants::*, errors::MMMErrorCode, state::Pool, util::*};
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::{DutchAuction, GlobalConfig, Pool, PriceLadderStep},
+    util::*,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdatePoolArgs {
@@ -8,13 +13,23 @@ pub struct UpdatePoolArgs {
     pub spot_price: u64,
     pub curve_type: u8,
     pub curve_delta: u64,
+    // the delta of the sell side if it's different from curve_delta
+    pub sell_curve_delta: Option<u64>,
     pub reinvest_fulfill_buy: bool,
     pub reinvest_fulfill_sell: bool,
     pub expiry: i64,
+    pub start_time: i64,
+    pub buyside_expiry: i64,
+    pub sellside_expiry: i64,
     pub lp_fee_bp: u16,
     pub referral: Pubkey,
     pub cosigner_annotation: [u8; 32],
     pub buyside_creator_royalty_bp: u16,
+    // replaces the price ladder and resets its position if set, the length is
+    // PRICE_LADDER_MAX_LEN, which the anchor idl can't resolve inside an Option
+    pub price_ladder: Option<[PriceLadderStep; 6]>,
+    // replaces the decay of the dutch auction if set
+    pub dutch_auction: Option<DutchAuction>,
 }
 
 #[derive(Accounts)]
@@ -22,19 +37,26 @@ pub struct UpdatePoolArgs {
 pub struct UpdatePool<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         bump,
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
-        constraint = args.lp_fee_bp <= MAX_LP_FEE_BP @ MMMErrorCode::InvalidBP,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         constraint = args.buyside_creator_royalty_bp <= 10000 @ MMMErrorCode::InvalidBP,
         constraint = args.spot_price > 0 @ MMMErrorCode::InvalidSpotPrice,
         constraint = args.referral.ne(owner.key) @ MMMErrorCode::InvalidReferral,
     )]
     pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = args.lp_fee_bp <= global_config.max_lp_fee_bp @ MMMErrorCode::InvalidBP,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
 }
 
 pub fn handler(ctx: Context<UpdatePool>, args: UpdatePoolArgs) -> Result<()> {
@@ -48,10 +70,20 @@ pub fn handler(ctx: Context<UpdatePool>, args: UpdatePoolArgs) -> Result<()> {
     pool.reinvest_fulfill_buy = args.reinvest_fulfill_buy;
     pool.reinvest_fulfill_sell = args.reinvest_fulfill_sell;
     pool.expiry = args.expiry;
+    pool.start_time = args.start_time;
+    pool.buyside_expiry = args.buyside_expiry;
+    pool.sellside_expiry = args.sellside_expiry;
     pool.lp_fee_bp = args.lp_fee_bp;
     pool.referral = args.referral;
     pool.cosigner_annotation = args.cosigner_annotation;
     pool.buyside_creator_royalty_bp = args.buyside_creator_royalty_bp;
+    set_price_ladder(pool, args.price_ladder)?;
+    if let Some(dutch_auction) = args.dutch_auction {
+        pool.dutch_auction = dutch_auction;
+    }
+    set_sell_curve_delta(pool, args.sell_curve_delta)?;
+    reset_dutch_auction(pool)?;
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_update_pool", pool)?;
 
     Ok(())
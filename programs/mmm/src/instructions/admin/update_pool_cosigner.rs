@@ -0,0 +1,67 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, state::Pool, util::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePoolCosignerArgs {
+    pub new_cosigner: Pubkey,
+    pub no_cosigner: bool,
+}
+
+// UpdatePoolCosigner rotates the cosigner of the pool, and sets the no_cosigner mode.
+//   - signed by the owner and the current cosigner: applied right away
+//   - signed by the owner alone: the first call requests the rotation, and the same
+//     call after COSIGNER_ROTATION_TIMELOCK applies it. This is the way out when the
+//     current cosigner key is lost or compromised.
+#[derive(Accounts)]
+#[instruction(args:UpdatePoolCosignerArgs)]
+pub struct UpdatePoolCosigner<'info> {
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, the rotation is applied right away if it signs
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = args.new_cosigner.ne(&Pubkey::default()) @ MMMErrorCode::InvalidCosigner,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler(ctx: Context<UpdatePoolCosigner>, args: UpdatePoolCosignerArgs) -> Result<()> {
+    let cosigner = &ctx.accounts.cosigner;
+    let pool = &mut ctx.accounts.pool;
+    let now = Clock::get()?.unix_timestamp;
+
+    let timelock_passed = pool.cosigner_rotation_requested_at != 0
+        && pool.pending_cosigner == args.new_cosigner
+        && pool.pending_no_cosigner == args.no_cosigner
+        && now
+            >= pool
+                .cosigner_rotation_requested_at
+                .checked_add(COSIGNER_ROTATION_TIMELOCK)
+                .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    if cosigner.is_signer || timelock_passed {
+        pool.cosigner = args.new_cosigner;
+        pool.no_cosigner = args.no_cosigner;
+        pool.pending_cosigner = Pubkey::default();
+        pool.pending_no_cosigner = false;
+        pool.cosigner_rotation_requested_at = 0;
+    } else if pool.cosigner_rotation_requested_at == 0
+        || pool.pending_cosigner != args.new_cosigner
+        || pool.pending_no_cosigner != args.no_cosigner
+    {
+        // a different request restarts the timelock
+        pool.pending_cosigner = args.new_cosigner;
+        pool.pending_no_cosigner = args.no_cosigner;
+        pool.cosigner_rotation_requested_at = now;
+    } else {
+        return Err(MMMErrorCode::CosignerRotationTimelocked.into());
+    }
+    log_pool("post_update_pool_cosigner", pool)?;
+
+    Ok(())
+}
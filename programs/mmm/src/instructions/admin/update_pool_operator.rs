@@ -0,0 +1,36 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{constants::*, errors::MMMErrorCode, state::Pool, util::*};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePoolOperatorArgs {
+    // Pubkey::default() removes the operator
+    pub operator: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args:UpdatePoolOperatorArgs)]
+pub struct UpdatePoolOperator<'info> {
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        bump,
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        constraint = args.operator.ne(owner.key) @ MMMErrorCode::InvalidOperator,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn handler(ctx: Context<UpdatePoolOperator>, args: UpdatePoolOperatorArgs) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    pool.operator = args.operator;
+    log_pool("post_update_pool_operator", pool)?;
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    bubblegum::{
+        get_asset_id, transfer, MetadataArgs, TransferArgs, BUBBLEGUM_PROGRAM_ID,
+        SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, SPL_NOOP_PROGRAM_ID,
+    },
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Pool, SellState},
+    util::{
+        check_allowlists_for_cnft, get_allowlists, log_pool, refresh_constant_product_spot_price,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CnftDepositSellArgs {
+    pub root: [u8; 32],
+    pub metadata_args: MetadataArgs,
+    pub nonce: u64,
+    pub index: u32,
+    pub allowlist_aux: Option<String>,
+}
+
+// The merkle proof of the leaf is passed in as remaining accounts
+#[derive(Accounts)]
+#[instruction(args:CnftDepositSellArgs)]
+pub struct CnftDepositSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: will be checked in cpi
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: checked by seeds and in cpi
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump,
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: will be checked in cpi
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            get_asset_id(&merkle_tree.key(), args.nonce).as_ref(),
+        ],
+        space = SellState::LEN,
+        bump
+    )]
+    pub sell_state: Box<Account<'info, SellState>>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CnftDepositSell<'info>>,
+    args: CnftDepositSellArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+
+    let asset_id = get_asset_id(&merkle_tree.key(), args.nonce);
+    check_allowlists_for_cnft(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        &asset_id,
+        &args.metadata_args.to_metadata(&asset_id),
+        args.allowlist_aux,
+    )?;
+
+    transfer(
+        ctx.accounts.tree_authority.to_account_info(),
+        owner.to_account_info(),
+        ctx.accounts.leaf_delegate.to_account_info(),
+        pool.to_account_info(),
+        merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+        ctx.remaining_accounts,
+        TransferArgs {
+            root: args.root,
+            data_hash: args.metadata_args.data_hash()?,
+            creator_hash: args.metadata_args.creator_hash(),
+            nonce: args.nonce,
+            index: args.index,
+        },
+        &[],
+    )?;
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_add(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    sell_state.pool = pool.key();
+    sell_state.pool_owner = owner.key();
+    sell_state.version = SELL_STATE_VERSION;
+    sell_state.asset_mint = asset_id;
+    sell_state.cosigner_annotation = pool.cosigner_annotation;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_add(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_cnft_deposit_sell", pool)?;
+
+    Ok(())
+}
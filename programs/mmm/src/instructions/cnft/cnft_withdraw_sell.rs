@@ -0,0 +1,134 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    bubblegum::{
+        get_asset_id, transfer, TransferArgs, BUBBLEGUM_PROGRAM_ID,
+        SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, SPL_NOOP_PROGRAM_ID,
+    },
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Pool, SellState},
+    util::{log_pool, refresh_constant_product_spot_price, try_close_pool, try_close_sell_state},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CnftWithdrawSellArgs {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+// The merkle proof of the leaf is passed in as remaining accounts
+#[derive(Accounts)]
+#[instruction(args:CnftWithdrawSellArgs)]
+pub struct CnftWithdrawSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: checked by seeds and in cpi
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump,
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: will be checked in cpi
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            get_asset_id(&merkle_tree.key(), args.nonce).as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Box<Account<'info, SellState>>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CnftWithdrawSell<'info>>,
+    args: CnftWithdrawSellArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+
+    // Note that the allowlist checks are skipped for withdraw, same as withdraw_sell
+    transfer(
+        ctx.accounts.tree_authority.to_account_info(),
+        pool.to_account_info(),
+        pool.to_account_info(),
+        owner.to_account_info(),
+        ctx.accounts.merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+        ctx.remaining_accounts,
+        TransferArgs {
+            root: args.root,
+            data_hash: args.data_hash,
+            creator_hash: args.creator_hash,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        // seeds should be the PDA of 'pool'
+        &[&[
+            POOL_PREFIX.as_bytes(),
+            pool.seed_owner().as_ref(),
+            pool.uuid.key().as_ref(),
+            &[*ctx.bumps.get("pool").unwrap()],
+        ]],
+    )?;
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    // spl payment pools keep track of buyside_payment_amount in the spl handlers
+    if pool.payment_mint.eq(&Pubkey::default()) {
+        pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    }
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_cnft_withdraw_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    Ok(())
+}
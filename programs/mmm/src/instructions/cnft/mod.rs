@@ -0,0 +1,9 @@
+pub mod cnft_deposit_sell;
+pub mod cnft_withdraw_sell;
+pub mod sol_cnft_fulfill_buy;
+pub mod sol_cnft_fulfill_sell;
+
+pub use cnft_deposit_sell::*;
+pub use cnft_withdraw_sell::*;
+pub use sol_cnft_fulfill_buy::*;
+pub use sol_cnft_fulfill_sell::*;
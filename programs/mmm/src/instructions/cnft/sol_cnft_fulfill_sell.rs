@@ -0,0 +1,319 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use std::convert::TryFrom;
+
+use crate::{
+    bubblegum::{
+        get_asset_id, transfer, MetadataArgs, TransferArgs, BUBBLEGUM_PROGRAM_ID,
+        SPL_ACCOUNT_COMPRESSION_PROGRAM_ID, SPL_NOOP_PROGRAM_ID,
+    },
+    constants::*,
+    errors::MMMErrorCode,
+    state::{GlobalConfig, Pool, SellState},
+    util::{
+        advance_curve, assert_valid_fees_bp, check_allowlists_for_cnft,
+        get_allowlist_multiplier_bp, get_allowlists, get_metadata_royalty_bp, get_protocol_fee,
+        get_sol_fee, get_sol_lp_fee, get_sol_total_price_and_next_price, log_pool,
+        pay_creator_fees_in_sol, refresh_constant_product_spot_price, try_close_pool,
+        try_close_sell_state,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SolCnftFulfillSellArgs {
+    pub root: [u8; 32],
+    pub metadata_args: MetadataArgs,
+    pub nonce: u64,
+    pub index: u32,
+    pub max_payment_amount: u64,
+    pub buyside_creator_royalty_bp: u16,
+    pub allowlist_aux: Option<String>,
+    pub maker_fee_bp: i16, // will be checked by cosigner
+    pub taker_fee_bp: i16, // will be checked by cosigner
+}
+
+// FulfillSell means a buyer wants to buy the compressed NFT from the pool
+// where the pool has some sellside asset liquidity. The remaining accounts
+// are the creator accounts (one per metadata creator) for the royalties,
+// followed by the merkle proof of the leaf.
+#[derive(Accounts)]
+#[instruction(args:SolCnftFulfillSellArgs)]
+pub struct SolCnftFulfillSell<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: we will check the owner field that matches the pool owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    pub cosigner: Signer<'info>,
+    /// CHECK: we will check that the referral matches the pool's referral
+    #[account(mut)]
+    pub referral: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = referral @ MMMErrorCode::InvalidReferral,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.expiry == 0 || pool.expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.sellside_expiry == 0 || pool.sellside_expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.start_time <= Clock::get().unwrap().unix_timestamp @ MMMErrorCode::PoolNotStarted,
+        constraint = !pool.sellside_paused @ MMMErrorCode::PoolPaused,
+        constraint = args.buyside_creator_royalty_bp <= 10000 @ MMMErrorCode::InvalidBP,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = !global_config.paused @ MMMErrorCode::ProgramPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: checked by the treasury of the global_config
+    #[account(mut, address = global_config.treasury @ MMMErrorCode::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        mut,
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: AccountInfo<'info>,
+    /// CHECK: checked by seeds and in cpi
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        seeds::program = bubblegum_program.key(),
+        bump,
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    /// CHECK: will be checked in cpi
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            get_asset_id(&merkle_tree.key(), args.nonce).as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Box<Account<'info, SellState>>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = SPL_NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = SPL_ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SolCnftFulfillSell<'info>>,
+    args: SolCnftFulfillSellArgs,
+) -> Result<()> {
+    let system_program = &ctx.accounts.system_program;
+    let owner = &ctx.accounts.owner;
+    let referral = &ctx.accounts.referral;
+    let global_config = &ctx.accounts.global_config;
+    let treasury = &ctx.accounts.treasury;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let payer = &ctx.accounts.payer;
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let seed_owner = pool.seed_owner();
+    let pool_uuid = pool.uuid;
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool_uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    let creators_len = args.metadata_args.creators.len();
+    if ctx.remaining_accounts.len() < creators_len {
+        return Err(MMMErrorCode::InvalidCreatorAddress.into());
+    }
+    let (creator_accounts, proof_accounts) = ctx.remaining_accounts.split_at(creators_len);
+
+    let asset_id = get_asset_id(&merkle_tree.key(), args.nonce);
+    let parsed_metadata = args.metadata_args.to_metadata(&asset_id);
+    let allowlist_match = check_allowlists_for_cnft(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        &asset_id,
+        &parsed_metadata,
+        args.allowlist_aux,
+    )?;
+
+    let allowlist_multiplier_bp =
+        get_allowlist_multiplier_bp(pool, allowlist_match, &ctx.accounts.allowlist_aux_account);
+    let (total_price, next_price) =
+        get_sol_total_price_and_next_price(pool, 1, false, allowlist_multiplier_bp)?;
+    let lp_fee = get_sol_lp_fee(pool, buyside_sol_escrow_account.lamports(), total_price)?;
+
+    assert_valid_fees_bp(
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        global_config.max_referral_fee_bp,
+    )?;
+    let maker_fee = get_sol_fee(total_price, args.maker_fee_bp)?;
+    let taker_fee = get_sol_fee(total_price, args.taker_fee_bp)?;
+    let protocol_fee = get_protocol_fee(total_price, global_config.protocol_fee_bp)?;
+    let referral_fee = u64::try_from(
+        maker_fee
+            .checked_add(taker_fee)
+            .ok_or(MMMErrorCode::NumericOverflow)?,
+    )
+    .map_err(|_| MMMErrorCode::NumericOverflow)?;
+
+    let transfer_sol_to = if pool.reinvest_fulfill_sell {
+        buyside_sol_escrow_account.to_account_info()
+    } else {
+        owner.to_account_info()
+    };
+
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            payer.key,
+            transfer_sol_to.key,
+            u64::try_from(
+                i64::try_from(total_price)
+                    .map_err(|_| MMMErrorCode::NumericOverflow)?
+                    .checked_sub(maker_fee)
+                    .ok_or(MMMErrorCode::NumericOverflow)?,
+            )
+            .map_err(|_| MMMErrorCode::NumericOverflow)?,
+        ),
+        &[
+            payer.to_account_info(),
+            transfer_sol_to,
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    // the transfer cpi verifies the metadata_args against the leaf
+    transfer(
+        ctx.accounts.tree_authority.to_account_info(),
+        pool.to_account_info(),
+        pool.to_account_info(),
+        payer.to_account_info(),
+        merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+        proof_accounts,
+        TransferArgs {
+            root: args.root,
+            data_hash: args.metadata_args.data_hash()?,
+            creator_hash: args.metadata_args.creator_hash(),
+            nonce: args.nonce,
+            index: args.index,
+        },
+        pool_seeds,
+    )?;
+
+    if lp_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key, owner.key, lp_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                owner.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    if referral_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                referral.key,
+                referral_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                referral.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                treasury.key,
+                protocol_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                treasury.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    pool.spot_price = next_price;
+    advance_curve(pool, 1, false)?;
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    pool.lp_fee_earned = pool
+        .lp_fee_earned
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    let royalty_bp = get_metadata_royalty_bp(total_price, &parsed_metadata, None);
+    let royalty_paid = pay_creator_fees_in_sol(
+        args.buyside_creator_royalty_bp,
+        total_price,
+        &parsed_metadata,
+        creator_accounts,
+        payer.to_account_info(),
+        royalty_bp,
+        &[&[&[]]],
+        system_program.to_account_info(),
+    )?;
+
+    // prevent frontrun by pool config changes
+    let payment_amount = total_price
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(taker_fee as u64)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(protocol_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(royalty_paid)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    if payment_amount > args.max_payment_amount {
+        return Err(MMMErrorCode::InvalidRequestedPrice.into());
+    }
+
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_sol_cnft_fulfill_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    msg!(
+        "{{\"lp_fee\":{},\"royalty_paid\":{},\"total_price\":{}}}",
+        lp_fee,
+        royalty_paid,
+        total_price,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    ata::init_if_needed_ext_ata,
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Pool, SellState},
+    token_ext::transfer_checked,
+    util::{
+        check_allowlists_for_ext_mint, get_allowlists, log_pool,
+        refresh_constant_product_spot_price,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExtDepositSellArgs {
+    pub asset_amount: u64,
+    pub allowlist_aux: Option<String>,
+}
+
+// ExtDepositSell deposits a token-2022 asset into the pool. The remaining accounts
+// are the transfer hook accounts if the mint has a transfer hook.
+#[derive(Accounts)]
+#[instruction(args:ExtDepositSellArgs)]
+pub struct ExtDepositSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        constraint = asset_mint.to_account_info().owner == token_program.key @ MMMErrorCode::InvalidTokenMint,
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = owner,
+    )]
+    pub asset_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: check in init_if_needed_ext_ata
+    #[account(mut)]
+    pub sellside_escrow_token_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        space = SellState::LEN,
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExtDepositSell<'info>>,
+    args: ExtDepositSellArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let asset_token_account = &ctx.accounts.asset_token_account;
+    let asset_mint = &ctx.accounts.asset_mint;
+    let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
+    let token_program = &ctx.accounts.token_program;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+
+    check_allowlists_for_ext_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        &asset_mint.to_account_info(),
+        args.allowlist_aux,
+    )?;
+
+    init_if_needed_ext_ata(
+        sellside_escrow_token_account.to_account_info(),
+        owner.to_account_info(),
+        pool.to_account_info(),
+        asset_mint.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+    )?;
+
+    transfer_checked(
+        token_program.to_account_info(),
+        asset_token_account.to_account_info(),
+        asset_mint.to_account_info(),
+        sellside_escrow_token_account.to_account_info(),
+        owner.to_account_info(),
+        ctx.remaining_accounts,
+        args.asset_amount,
+        asset_mint.decimals,
+        &[],
+    )?;
+
+    if asset_token_account.amount == args.asset_amount {
+        anchor_spl::token_2022::close_account(CpiContext::new(
+            token_program.to_account_info(),
+            anchor_spl::token_2022::CloseAccount {
+                account: asset_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: owner.to_account_info(),
+            },
+        ))?;
+    }
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_add(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    sell_state.pool = pool.key();
+    sell_state.pool_owner = owner.key();
+    sell_state.version = SELL_STATE_VERSION;
+    sell_state.asset_mint = asset_mint.key();
+    sell_state.cosigner_annotation = pool.cosigner_annotation;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_add(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_ext_deposit_sell", pool)?;
+
+    Ok(())
+}
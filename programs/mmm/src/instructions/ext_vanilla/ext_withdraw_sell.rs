@@ -0,0 +1,156 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    ata::init_if_needed_ext_ata,
+    constants::*,
+    errors::MMMErrorCode,
+    state::{Pool, SellState},
+    token_ext::transfer_checked,
+    util::{log_pool, refresh_constant_product_spot_price, try_close_pool, try_close_sell_state},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExtWithdrawSellArgs {
+    pub asset_amount: u64,
+    pub allowlist_aux: Option<String>,
+}
+
+// ExtWithdrawSell withdraws a token-2022 asset from the pool. The remaining accounts
+// are the transfer hook accounts if the mint has a transfer hook.
+#[derive(Accounts)]
+#[instruction(args:ExtWithdrawSellArgs)]
+pub struct ExtWithdrawSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        constraint = asset_mint.to_account_info().owner == token_program.key @ MMMErrorCode::InvalidTokenMint,
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: check in init_if_needed_ext_ata
+    #[account(mut)]
+    pub asset_token_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = pool,
+    )]
+    pub sellside_escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        mut,
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: UncheckedAccount<'info>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExtWithdrawSell<'info>>,
+    args: ExtWithdrawSellArgs,
+) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let asset_mint = &ctx.accounts.asset_mint;
+    let asset_token_account = &ctx.accounts.asset_token_account;
+    let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
+    let token_program = &ctx.accounts.token_program;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let seed_owner = pool.seed_owner();
+    let pool_uuid = pool.uuid;
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool_uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    // Same as withdraw_sell, check_allowlists_for_ext_mint is skipped so that
+    // assets moved out of the group can still be withdrawn by the pool owner.
+    init_if_needed_ext_ata(
+        asset_token_account.to_account_info(),
+        owner.to_account_info(),
+        owner.to_account_info(),
+        asset_mint.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        token_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+    )?;
+
+    transfer_checked(
+        token_program.to_account_info(),
+        sellside_escrow_token_account.to_account_info(),
+        asset_mint.to_account_info(),
+        asset_token_account.to_account_info(),
+        pool.to_account_info(),
+        ctx.remaining_accounts,
+        args.asset_amount,
+        asset_mint.decimals,
+        pool_seeds,
+    )?;
+    // we can close the sellside_escrow_token_account if no amount left
+    if sellside_escrow_token_account.amount == args.asset_amount {
+        anchor_spl::token_2022::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            anchor_spl::token_2022::CloseAccount {
+                account: sellside_escrow_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            pool_seeds,
+        ))?;
+    }
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    // spl payment pools keep track of buyside_payment_amount in the spl handlers
+    if pool.payment_mint.eq(&Pubkey::default()) {
+        pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    }
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_ext_withdraw_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+pub mod ext_deposit_sell;
+pub mod ext_withdraw_sell;
+pub mod sol_ext_fulfill_buy;
+pub mod sol_ext_fulfill_sell;
+
+pub use ext_deposit_sell::*;
+pub use ext_withdraw_sell::*;
+pub use sol_ext_fulfill_buy::*;
+pub use sol_ext_fulfill_sell::*;
@@ -0,0 +1,336 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+use std::convert::TryFrom;
+
+use crate::{
+    ata::init_if_needed_ext_ata,
+    constants::*,
+    errors::MMMErrorCode,
+    state::{GlobalConfig, Pool, SellState},
+    token_ext::transfer_checked,
+    util::{
+        advance_curve, assert_valid_fees_bp, check_allowlists_for_ext_mint,
+        get_allowlist_multiplier_bp, get_allowlists, get_buyside_seller_receives, get_lp_fee_bp,
+        get_protocol_fee, get_sol_fee, get_sol_lp_fee, get_sol_total_price_and_next_price,
+        log_pool, refresh_constant_product_spot_price, try_close_escrow, try_close_pool,
+        try_close_sell_state,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SolExtFulfillBuyArgs {
+    pub asset_amount: u64,
+    pub min_payment_amount: u64,
+    pub allowlist_aux: Option<String>,
+    pub maker_fee_bp: i16, // will be checked by cosigner
+    pub taker_fee_bp: i16, // will be checked by cosigner
+}
+
+// SolExtFulfillBuy is the token-2022 version of SolFulfillBuy. Token-2022 metadata
+// has no creators, so there are no royalties to pay, and the remaining accounts
+// are the transfer hook accounts if the mint has a transfer hook.
+#[derive(Accounts)]
+#[instruction(args:SolExtFulfillBuyArgs)]
+pub struct SolExtFulfillBuy<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: we will check the owner field that matches the pool owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    pub cosigner: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: we will check that the referral matches the pool's referral
+    pub referral: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = referral @ MMMErrorCode::InvalidReferral,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.expiry == 0 || pool.expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.buyside_expiry == 0 || pool.buyside_expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.start_time <= Clock::get().unwrap().unix_timestamp @ MMMErrorCode::PoolNotStarted,
+        constraint = !pool.buyside_paused @ MMMErrorCode::PoolPaused,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = !global_config.paused @ MMMErrorCode::ProgramPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: checked by the treasury of the global_config
+    #[account(mut, address = global_config.treasury @ MMMErrorCode::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        mut,
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: UncheckedAccount<'info>,
+    #[account(
+        constraint = asset_mint.to_account_info().owner == token_program.key @ MMMErrorCode::InvalidTokenMint,
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = payer,
+    )]
+    pub payer_asset_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: check in init_if_needed_ext_ata
+    #[account(mut)]
+    pub sellside_escrow_token_account: UncheckedAccount<'info>,
+    /// CHECK: check in init_if_needed_ext_ata
+    #[account(mut)]
+    pub owner_token_account: UncheckedAccount<'info>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        space = SellState::LEN,
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SolExtFulfillBuy<'info>>,
+    args: SolExtFulfillBuyArgs,
+) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let associated_token_program = &ctx.accounts.associated_token_program;
+    let rent = &ctx.accounts.rent;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let owner = &ctx.accounts.owner;
+    let referral = &ctx.accounts.referral;
+    let global_config = &ctx.accounts.global_config;
+    let treasury = &ctx.accounts.treasury;
+    let payer = &ctx.accounts.payer;
+    let payer_asset_account = &ctx.accounts.payer_asset_account;
+    let asset_mint = &ctx.accounts.asset_mint;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let pool_key = pool.key();
+    let buyside_sol_escrow_account_seeds: &[&[&[u8]]] = &[&[
+        BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(),
+        pool_key.as_ref(),
+        &[*ctx.bumps.get("buyside_sol_escrow_account").unwrap()],
+    ]];
+
+    let allowlist_match = check_allowlists_for_ext_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        &asset_mint.to_account_info(),
+        args.allowlist_aux,
+    )?;
+
+    let allowlist_multiplier_bp =
+        get_allowlist_multiplier_bp(pool, allowlist_match, &ctx.accounts.allowlist_aux_account);
+    let (total_price, next_price) =
+        get_sol_total_price_and_next_price(pool, args.asset_amount, true, allowlist_multiplier_bp)?;
+    let seller_receives = {
+        let lp_fee_bp = get_lp_fee_bp(pool, buyside_sol_escrow_account.lamports());
+        get_buyside_seller_receives(total_price, lp_fee_bp, 0, pool.buyside_creator_royalty_bp)
+    }?;
+    let lp_fee = get_sol_lp_fee(pool, buyside_sol_escrow_account.lamports(), seller_receives)?;
+
+    assert_valid_fees_bp(
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        global_config.max_referral_fee_bp,
+    )?;
+    let maker_fee = get_sol_fee(seller_receives, args.maker_fee_bp)?;
+    let taker_fee = get_sol_fee(seller_receives, args.taker_fee_bp)?;
+    let protocol_fee = get_protocol_fee(seller_receives, global_config.protocol_fee_bp)?;
+    let referral_fee = u64::try_from(
+        maker_fee
+            .checked_add(taker_fee)
+            .ok_or(MMMErrorCode::NumericOverflow)?,
+    )
+    .map_err(|_| MMMErrorCode::NumericOverflow)?;
+
+    let transfer_asset_to = if pool.reinvest_fulfill_buy {
+        let sellside_escrow_token_account =
+            ctx.accounts.sellside_escrow_token_account.to_account_info();
+        init_if_needed_ext_ata(
+            sellside_escrow_token_account.to_account_info(),
+            payer.to_account_info(),
+            pool.to_account_info(),
+            asset_mint.to_account_info(),
+            associated_token_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+        )?;
+        pool.sellside_asset_amount = pool
+            .sellside_asset_amount
+            .checked_add(args.asset_amount)
+            .ok_or(MMMErrorCode::NumericOverflow)?;
+        sell_state.pool = pool.key();
+        sell_state.pool_owner = owner.key();
+        sell_state.version = SELL_STATE_VERSION;
+        sell_state.asset_mint = asset_mint.key();
+        sell_state.cosigner_annotation = pool.cosigner_annotation;
+        sell_state.asset_amount = sell_state
+            .asset_amount
+            .checked_add(args.asset_amount)
+            .ok_or(MMMErrorCode::NumericOverflow)?;
+        sellside_escrow_token_account
+    } else {
+        let owner_token_account = ctx.accounts.owner_token_account.to_account_info();
+        init_if_needed_ext_ata(
+            owner_token_account.to_account_info(),
+            payer.to_account_info(),
+            owner.to_account_info(),
+            asset_mint.to_account_info(),
+            associated_token_program.to_account_info(),
+            token_program.to_account_info(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+        )?;
+        owner_token_account
+    };
+
+    transfer_checked(
+        token_program.to_account_info(),
+        payer_asset_account.to_account_info(),
+        asset_mint.to_account_info(),
+        transfer_asset_to,
+        payer.to_account_info(),
+        ctx.remaining_accounts,
+        args.asset_amount,
+        asset_mint.decimals,
+        &[],
+    )?;
+
+    // we can close the payer_asset_account if no amount left
+    if payer_asset_account.amount == args.asset_amount {
+        anchor_spl::token_2022::close_account(CpiContext::new(
+            token_program.to_account_info(),
+            anchor_spl::token_2022::CloseAccount {
+                account: payer_asset_account.to_account_info(),
+                destination: payer.to_account_info(),
+                authority: payer.to_account_info(),
+            },
+        ))?;
+    }
+
+    // prevent frontrun by pool config changes
+    let payment_amount = total_price
+        .checked_sub(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_sub(taker_fee as u64)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_sub(protocol_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    if payment_amount < args.min_payment_amount {
+        return Err(MMMErrorCode::InvalidRequestedPrice.into());
+    }
+
+    anchor_lang::solana_program::program::invoke_signed(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            buyside_sol_escrow_account.key,
+            payer.key,
+            payment_amount,
+        ),
+        &[
+            buyside_sol_escrow_account.to_account_info(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+        ],
+        buyside_sol_escrow_account_seeds,
+    )?;
+
+    if lp_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                buyside_sol_escrow_account.key,
+                owner.key,
+                lp_fee,
+            ),
+            &[
+                buyside_sol_escrow_account.to_account_info(),
+                owner.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            buyside_sol_escrow_account_seeds,
+        )?;
+    }
+    if referral_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                buyside_sol_escrow_account.key,
+                referral.key,
+                referral_fee,
+            ),
+            &[
+                buyside_sol_escrow_account.to_account_info(),
+                referral.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            buyside_sol_escrow_account_seeds,
+        )?;
+    }
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                buyside_sol_escrow_account.key,
+                treasury.key,
+                protocol_fee,
+            ),
+            &[
+                buyside_sol_escrow_account.to_account_info(),
+                treasury.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            buyside_sol_escrow_account_seeds,
+        )?;
+    }
+
+    pool.lp_fee_earned = pool
+        .lp_fee_earned
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    pool.spot_price = next_price;
+    advance_curve(pool, args.asset_amount, true)?;
+
+    try_close_escrow(
+        &buyside_sol_escrow_account.to_account_info(),
+        pool,
+        system_program,
+        buyside_sol_escrow_account_seeds,
+    )?;
+    try_close_sell_state(sell_state, payer.to_account_info())?;
+
+    pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_sol_ext_fulfill_buy", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    msg!(
+        "{{\"lp_fee\":{},\"royalty_paid\":{},\"total_price\":{}}}",
+        lp_fee,
+        0,
+        total_price,
+    );
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+use std::convert::TryFrom;
+
+use crate::{
+    ata::init_if_needed_ext_ata,
+    constants::*,
+    errors::MMMErrorCode,
+    state::{GlobalConfig, Pool, SellState},
+    token_ext::transfer_checked,
+    util::{
+        advance_curve, assert_valid_fees_bp, check_allowlists_for_ext_mint,
+        get_allowlist_multiplier_bp, get_allowlists, get_protocol_fee, get_sol_fee, get_sol_lp_fee,
+        get_sol_total_price_and_next_price, log_pool, refresh_constant_product_spot_price,
+        try_close_pool, try_close_sell_state,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SolExtFulfillSellArgs {
+    pub asset_amount: u64,
+    pub max_payment_amount: u64,
+    pub allowlist_aux: Option<String>,
+    pub maker_fee_bp: i16, // will be checked by cosigner
+    pub taker_fee_bp: i16, // will be checked by cosigner
+}
+
+// SolExtFulfillSell is the token-2022 version of SolFulfillSell. Token-2022 metadata
+// has no creators, so there are no royalties to pay, and the remaining accounts
+// are the transfer hook accounts if the mint has a transfer hook.
+#[derive(Accounts)]
+#[instruction(args:SolExtFulfillSellArgs)]
+pub struct SolExtFulfillSell<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: we will check the owner field that matches the pool owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    pub cosigner: Signer<'info>,
+    /// CHECK: we will check that the referral matches the pool's referral
+    #[account(mut)]
+    pub referral: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = referral @ MMMErrorCode::InvalidReferral,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.expiry == 0 || pool.expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.sellside_expiry == 0 || pool.sellside_expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.start_time <= Clock::get().unwrap().unix_timestamp @ MMMErrorCode::PoolNotStarted,
+        constraint = !pool.sellside_paused @ MMMErrorCode::PoolPaused,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = !global_config.paused @ MMMErrorCode::ProgramPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: checked by the treasury of the global_config
+    #[account(mut, address = global_config.treasury @ MMMErrorCode::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        mut,
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: AccountInfo<'info>,
+    #[account(
+        constraint = asset_mint.to_account_info().owner == token_program.key @ MMMErrorCode::InvalidTokenMint,
+    )]
+    pub asset_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = pool,
+    )]
+    pub sellside_escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: check in init_if_needed_ext_ata
+    #[account(mut)]
+    pub payer_asset_account: UncheckedAccount<'info>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SolExtFulfillSell<'info>>,
+    args: SolExtFulfillSellArgs,
+) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let owner = &ctx.accounts.owner;
+    let referral = &ctx.accounts.referral;
+    let global_config = &ctx.accounts.global_config;
+    let treasury = &ctx.accounts.treasury;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+
+    let payer = &ctx.accounts.payer;
+    let payer_asset_account = &ctx.accounts.payer_asset_account;
+    let asset_mint = &ctx.accounts.asset_mint;
+
+    let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let seed_owner = pool.seed_owner();
+    let pool_uuid = pool.uuid;
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool_uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    let allowlist_match = check_allowlists_for_ext_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        &asset_mint.to_account_info(),
+        args.allowlist_aux,
+    )?;
+
+    let allowlist_multiplier_bp =
+        get_allowlist_multiplier_bp(pool, allowlist_match, &ctx.accounts.allowlist_aux_account);
+    let (total_price, next_price) = get_sol_total_price_and_next_price(
+        pool,
+        args.asset_amount,
+        false,
+        allowlist_multiplier_bp,
+    )?;
+    let lp_fee = get_sol_lp_fee(pool, buyside_sol_escrow_account.lamports(), total_price)?;
+
+    assert_valid_fees_bp(
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        global_config.max_referral_fee_bp,
+    )?;
+    let maker_fee = get_sol_fee(total_price, args.maker_fee_bp)?;
+    let taker_fee = get_sol_fee(total_price, args.taker_fee_bp)?;
+    let protocol_fee = get_protocol_fee(total_price, global_config.protocol_fee_bp)?;
+    let referral_fee = u64::try_from(
+        maker_fee
+            .checked_add(taker_fee)
+            .ok_or(MMMErrorCode::NumericOverflow)?,
+    )
+    .map_err(|_| MMMErrorCode::NumericOverflow)?;
+
+    // prevent frontrun by pool config changes
+    let payment_amount = total_price
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(taker_fee as u64)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(protocol_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    if payment_amount > args.max_payment_amount {
+        return Err(MMMErrorCode::InvalidRequestedPrice.into());
+    }
+
+    let transfer_sol_to = if pool.reinvest_fulfill_sell {
+        buyside_sol_escrow_account.to_account_info()
+    } else {
+        owner.to_account_info()
+    };
+
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(
+            payer.key,
+            transfer_sol_to.key,
+            u64::try_from(
+                i64::try_from(total_price)
+                    .map_err(|_| MMMErrorCode::NumericOverflow)?
+                    .checked_sub(maker_fee)
+                    .ok_or(MMMErrorCode::NumericOverflow)?,
+            )
+            .map_err(|_| MMMErrorCode::NumericOverflow)?,
+        ),
+        &[
+            payer.to_account_info(),
+            transfer_sol_to,
+            system_program.to_account_info(),
+        ],
+    )?;
+
+    init_if_needed_ext_ata(
+        payer_asset_account.to_account_info(),
+        payer.to_account_info(),
+        payer.to_account_info(),
+        asset_mint.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        token_program.to_account_info(),
+        system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+    )?;
+    transfer_checked(
+        token_program.to_account_info(),
+        sellside_escrow_token_account.to_account_info(),
+        asset_mint.to_account_info(),
+        payer_asset_account.to_account_info(),
+        pool.to_account_info(),
+        ctx.remaining_accounts,
+        args.asset_amount,
+        asset_mint.decimals,
+        pool_seeds,
+    )?;
+    // we can close the sellside_escrow_token_account if no amount left
+    if sellside_escrow_token_account.amount == args.asset_amount {
+        anchor_spl::token_2022::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            anchor_spl::token_2022::CloseAccount {
+                account: sellside_escrow_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            pool_seeds,
+        ))?;
+    }
+
+    if lp_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key, owner.key, lp_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                owner.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    if referral_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                referral.key,
+                referral_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                referral.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                treasury.key,
+                protocol_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                treasury.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    pool.spot_price = next_price;
+    advance_curve(pool, args.asset_amount, false)?;
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    pool.lp_fee_earned = pool
+        .lp_fee_earned
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_sol_ext_fulfill_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    msg!(
+        "{{\"lp_fee\":{},\"royalty_paid\":{},\"total_price\":{}}}",
+        lp_fee,
+        0,
+        total_price,
+    );
+
+    Ok(())
+}
@@ -18,7 +18,10 @@ use crate::{
     errors::MMMErrorCode,
     instructions::vanilla::DepositSellArgs,
     state::{Pool, SellState},
-    util::{assert_is_programmable, check_allowlists_for_mint, log_pool},
+    util::{
+        assert_is_programmable, check_allowlists_for_mint, get_allowlists, log_pool,
+        refresh_constant_product_spot_price,
+    },
 };
 
 #[derive(Accounts)]
@@ -26,12 +29,14 @@ use crate::{
 pub struct Mip1DepositSell<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         bump
     )]
     pub pool: Box<Account<'info, Pool>>,
@@ -116,12 +121,14 @@ pub fn handler(ctx: Context<Mip1DepositSell>, args: DepositSellArgs) -> Result<(
     let authorization_rules = &ctx.accounts.authorization_rules;
     let authorization_rules_program = &ctx.accounts.authorization_rules_program;
 
-    let parsed_metadata = check_allowlists_for_mint(
-        &pool.allowlists,
+    let (parsed_metadata, _) = check_allowlists_for_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
         asset_mint,
         asset_metadata,
         Some(asset_master_edition),
         args.allowlist_aux,
+        ctx.remaining_accounts,
+        0,
     )?;
     assert_is_programmable(&parsed_metadata)?;
 
@@ -130,7 +137,7 @@ pub fn handler(ctx: Context<Mip1DepositSell>, args: DepositSellArgs) -> Result<(
         PayloadType::Seeds(SeedsVec {
             seeds: vec![
                 POOL_PREFIX.as_bytes().to_vec(),
-                owner.key().to_bytes().to_vec(),
+                pool.seed_owner().to_bytes().to_vec(),
                 pool.uuid.to_bytes().to_vec(),
             ],
         }),
@@ -199,12 +206,14 @@ pub fn handler(ctx: Context<Mip1DepositSell>, args: DepositSellArgs) -> Result<(
 
     sell_state.pool = pool.key();
     sell_state.pool_owner = owner.key();
+    sell_state.version = SELL_STATE_VERSION;
     sell_state.asset_mint = asset_mint.key();
     sell_state.cosigner_annotation = pool.cosigner_annotation;
     sell_state.asset_amount = sell_state
         .asset_amount
         .checked_add(args.asset_amount)
         .ok_or(MMMErrorCode::NumericOverflow)?;
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_mip1_deposit_sell", pool)?;
 
     Ok(())
@@ -19,7 +19,10 @@ use crate::{
     errors::MMMErrorCode,
     instructions::vanilla::WithdrawSellArgs,
     state::{Pool, SellState},
-    util::{assert_is_programmable, log_pool, try_close_pool, try_close_sell_state},
+    util::{
+        assert_is_programmable, log_pool, refresh_constant_product_spot_price, try_close_pool,
+        try_close_sell_state,
+    },
 };
 
 #[derive(Accounts)]
@@ -27,12 +30,14 @@ use crate::{
 pub struct Mip1WithdrawSell<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         bump
     )]
     pub pool: Box<Account<'info, Pool>>,
@@ -122,9 +127,10 @@ pub fn handler(ctx: Context<Mip1WithdrawSell>, args: WithdrawSellArgs) -> Result
     let authorization_rules = &ctx.accounts.authorization_rules;
     let authorization_rules_program = &ctx.accounts.authorization_rules_program;
 
+    let seed_owner = pool.seed_owner();
     let pool_seeds: &[&[&[u8]]] = &[&[
         POOL_PREFIX.as_bytes(),
-        pool.owner.as_ref(),
+        seed_owner.as_ref(),
         pool.uuid.as_ref(),
         &[*ctx.bumps.get("pool").unwrap()],
     ]];
@@ -206,7 +212,11 @@ pub fn handler(ctx: Context<Mip1WithdrawSell>, args: WithdrawSellArgs) -> Result
         .ok_or(MMMErrorCode::NumericOverflow)?;
     try_close_sell_state(sell_state, owner.to_account_info())?;
 
-    pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    // spl payment pools keep track of buyside_payment_amount in the spl handlers
+    if pool.payment_mint.eq(&Pubkey::default()) {
+        pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    }
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_mip1_withdraw_sell", pool)?;
     try_close_pool(pool, owner.to_account_info())?;
 
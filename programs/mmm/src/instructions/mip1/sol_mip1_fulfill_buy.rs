@@ -22,12 +22,14 @@ use crate::{
     constants::*,
     errors::MMMErrorCode,
     instructions::sol_fulfill_buy::SolFulfillBuyArgs,
-    state::{Pool, SellState},
+    state::{GlobalConfig, Pool, SellState},
     util::{
-        assert_is_programmable, assert_valid_fees_bp, check_allowlists_for_mint,
-        get_buyside_seller_receives, get_lp_fee_bp, get_metadata_royalty_bp, get_sol_fee,
-        get_sol_lp_fee, get_sol_total_price_and_next_price, log_pool, pay_creator_fees_in_sol,
-        try_close_escrow, try_close_pool, try_close_sell_state,
+        advance_curve, assert_is_programmable, assert_valid_fees_bp, check_allowlists_for_mint,
+        get_allowlist_multiplier_bp, get_allowlists, get_buyside_seller_receives, get_lp_fee_bp,
+        get_metadata_royalty_bp, get_protocol_fee, get_sol_fee, get_sol_lp_fee,
+        get_sol_total_price_and_next_price, log_pool, pay_creator_fees_in_sol,
+        refresh_constant_product_spot_price, try_close_escrow, try_close_pool,
+        try_close_sell_state,
     },
 };
 
@@ -49,15 +51,27 @@ pub struct SolMip1FulfillBuy<'info> {
     pub referral: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = referral @ MMMErrorCode::InvalidReferral,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
         constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
         constraint = pool.expiry == 0 || pool.expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.buyside_expiry == 0 || pool.buyside_expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.start_time <= Clock::get().unwrap().unix_timestamp @ MMMErrorCode::PoolNotStarted,
+        constraint = !pool.buyside_paused @ MMMErrorCode::PoolPaused,
         bump
     )]
     pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = !global_config.paused @ MMMErrorCode::ProgramPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: checked by the treasury of the global_config
+    #[account(mut, address = global_config.treasury @ MMMErrorCode::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
     /// CHECK: it's a pda, and the private key is owned by the seeds
     #[account(
         mut,
@@ -146,6 +160,8 @@ pub fn handler<'info>(
     let sell_state = &mut ctx.accounts.sell_state;
     let owner = &ctx.accounts.owner;
     let referral = &ctx.accounts.referral;
+    let global_config = &ctx.accounts.global_config;
+    let treasury = &ctx.accounts.treasury;
     let payer = &ctx.accounts.payer;
     let payer_asset_account = &ctx.accounts.payer_asset_account;
     let asset_mint = &ctx.accounts.asset_mint;
@@ -168,34 +184,44 @@ pub fn handler<'info>(
         pool_key.as_ref(),
         &[*ctx.bumps.get("buyside_sol_escrow_account").unwrap()],
     ]];
+    let seed_owner = pool.seed_owner();
     let pool_seeds: &[&[&[u8]]] = &[&[
         POOL_PREFIX.as_bytes(),
-        pool.owner.as_ref(),
+        seed_owner.as_ref(),
         pool.uuid.as_ref(),
         &[*ctx.bumps.get("pool").unwrap()],
     ]];
 
-    let parsed_metadata = check_allowlists_for_mint(
-        &pool.allowlists,
+    let (parsed_metadata, allowlist_match) = check_allowlists_for_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
         asset_mint,
         asset_metadata,
         Some(asset_master_edition),
         args.allowlist_aux,
+        ctx.remaining_accounts,
+        1,
     )?;
     assert_is_programmable(&parsed_metadata)?;
 
+    let allowlist_multiplier_bp =
+        get_allowlist_multiplier_bp(pool, allowlist_match, &ctx.accounts.allowlist_aux_account);
     let (total_price, next_price) =
-        get_sol_total_price_and_next_price(pool, args.asset_amount, true)?;
-    let metadata_royalty_bp = get_metadata_royalty_bp(total_price, &parsed_metadata);
+        get_sol_total_price_and_next_price(pool, args.asset_amount, true, allowlist_multiplier_bp)?;
+    let metadata_royalty_bp = get_metadata_royalty_bp(total_price, &parsed_metadata, None);
     let seller_receives = {
         let lp_fee_bp = get_lp_fee_bp(pool, buyside_sol_escrow_account.lamports());
         get_buyside_seller_receives(total_price, lp_fee_bp, metadata_royalty_bp, 10000)
     }?;
     let lp_fee = get_sol_lp_fee(pool, buyside_sol_escrow_account.lamports(), seller_receives)?;
 
-    assert_valid_fees_bp(args.maker_fee_bp, args.taker_fee_bp)?;
+    assert_valid_fees_bp(
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        global_config.max_referral_fee_bp,
+    )?;
     let maker_fee = get_sol_fee(seller_receives, args.maker_fee_bp)?;
     let taker_fee = get_sol_fee(seller_receives, args.taker_fee_bp)?;
+    let protocol_fee = get_protocol_fee(seller_receives, global_config.protocol_fee_bp)?;
     let referral_fee = u64::try_from(
         maker_fee
             .checked_add(taker_fee)
@@ -264,6 +290,7 @@ pub fn handler<'info>(
             .ok_or(MMMErrorCode::NumericOverflow)?;
         sell_state.pool = pool.key();
         sell_state.pool_owner = owner.key();
+        sell_state.version = SELL_STATE_VERSION;
         sell_state.asset_mint = asset_mint.key();
         sell_state.cosigner_annotation = pool.cosigner_annotation;
         sell_state.asset_amount = sell_state
@@ -379,6 +406,8 @@ pub fn handler<'info>(
         .ok_or(MMMErrorCode::NumericOverflow)?
         .checked_sub(taker_fee as u64)
         .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_sub(protocol_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
         .checked_sub(royalty_paid)
         .ok_or(MMMErrorCode::NumericOverflow)?;
     if payment_amount < args.min_payment_amount {
@@ -430,12 +459,28 @@ pub fn handler<'info>(
             buyside_sol_escrow_account_seeds,
         )?;
     }
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                buyside_sol_escrow_account.key,
+                treasury.key,
+                protocol_fee,
+            ),
+            &[
+                buyside_sol_escrow_account.to_account_info(),
+                treasury.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            buyside_sol_escrow_account_seeds,
+        )?;
+    }
 
     pool.lp_fee_earned = pool
         .lp_fee_earned
         .checked_add(lp_fee)
         .ok_or(MMMErrorCode::NumericOverflow)?;
     pool.spot_price = next_price;
+    advance_curve(pool, args.asset_amount, true)?;
 
     try_close_escrow(
         &buyside_sol_escrow_account.to_account_info(),
@@ -446,6 +491,7 @@ pub fn handler<'info>(
     try_close_sell_state(sell_state, payer.to_account_info())?;
 
     pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_sol_mip1_fulfill_buy", pool)?;
     try_close_pool(pool, owner.to_account_info())?;
 
@@ -17,11 +17,13 @@ use std::convert::TryFrom;
 use crate::{
     constants::*,
     errors::MMMErrorCode,
-    state::{Pool, SellState},
+    state::{GlobalConfig, Pool, SellState},
     util::{
-        assert_is_programmable, assert_valid_fees_bp, check_allowlists_for_mint,
-        get_metadata_royalty_bp, get_sol_fee, get_sol_lp_fee, get_sol_total_price_and_next_price,
-        log_pool, pay_creator_fees_in_sol, try_close_pool, try_close_sell_state,
+        advance_curve, assert_is_programmable, assert_valid_fees_bp, check_allowlists_for_mint,
+        get_allowlist_multiplier_bp, get_allowlists, get_metadata_royalty_bp, get_protocol_fee,
+        get_sol_fee, get_sol_lp_fee, get_sol_total_price_and_next_price, log_pool,
+        pay_creator_fees_in_sol, refresh_constant_product_spot_price, try_close_pool,
+        try_close_sell_state,
     },
 };
 
@@ -52,15 +54,27 @@ pub struct SolMip1FulfillSell<'info> {
     pub referral: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = referral @ MMMErrorCode::InvalidReferral,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
         constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
         constraint = pool.expiry == 0 || pool.expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.sellside_expiry == 0 || pool.sellside_expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.start_time <= Clock::get().unwrap().unix_timestamp @ MMMErrorCode::PoolNotStarted,
+        constraint = !pool.sellside_paused @ MMMErrorCode::PoolPaused,
         bump
     )]
     pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = !global_config.paused @ MMMErrorCode::ProgramPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: checked by the treasury of the global_config
+    #[account(mut, address = global_config.treasury @ MMMErrorCode::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
     /// CHECK: it's a pda, and the private key is owned by the seeds
     #[account(
         mut,
@@ -136,6 +150,8 @@ pub fn handler<'info>(
     let system_program = &ctx.accounts.system_program;
     let owner = &ctx.accounts.owner;
     let referral = &ctx.accounts.referral;
+    let global_config = &ctx.accounts.global_config;
+    let treasury = &ctx.accounts.treasury;
     let pool = &mut ctx.accounts.pool;
     let sell_state = &mut ctx.accounts.sell_state;
 
@@ -153,29 +169,43 @@ pub fn handler<'info>(
 
     let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
     let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let seed_owner = pool.seed_owner();
     let pool_seeds: &[&[&[u8]]] = &[&[
         POOL_PREFIX.as_bytes(),
-        pool.owner.as_ref(),
+        seed_owner.as_ref(),
         pool.uuid.as_ref(),
         &[*ctx.bumps.get("pool").unwrap()],
     ]];
 
-    let parsed_metadata = check_allowlists_for_mint(
-        &pool.allowlists,
+    let (parsed_metadata, allowlist_match) = check_allowlists_for_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
         asset_mint,
         asset_metadata,
         None,
         args.allowlist_aux,
+        ctx.remaining_accounts,
+        1,
     )?;
     assert_is_programmable(&parsed_metadata)?;
 
-    let (total_price, next_price) =
-        get_sol_total_price_and_next_price(pool, args.asset_amount, false)?;
+    let allowlist_multiplier_bp =
+        get_allowlist_multiplier_bp(pool, allowlist_match, &ctx.accounts.allowlist_aux_account);
+    let (total_price, next_price) = get_sol_total_price_and_next_price(
+        pool,
+        args.asset_amount,
+        false,
+        allowlist_multiplier_bp,
+    )?;
     let lp_fee = get_sol_lp_fee(pool, buyside_sol_escrow_account.lamports(), total_price)?;
 
-    assert_valid_fees_bp(args.maker_fee_bp, args.taker_fee_bp)?;
+    assert_valid_fees_bp(
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        global_config.max_referral_fee_bp,
+    )?;
     let maker_fee = get_sol_fee(total_price, args.maker_fee_bp)?;
     let taker_fee = get_sol_fee(total_price, args.taker_fee_bp)?;
+    let protocol_fee = get_protocol_fee(total_price, global_config.protocol_fee_bp)?;
     let referral_fee = u64::try_from(
         maker_fee
             .checked_add(taker_fee)
@@ -300,8 +330,23 @@ pub fn handler<'info>(
             ],
         )?;
     }
+    if protocol_fee > 0 {
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                treasury.key,
+                protocol_fee,
+            ),
+            &[
+                payer.to_account_info(),
+                treasury.to_account_info(),
+                system_program.to_account_info(),
+            ],
+        )?;
+    }
 
     pool.spot_price = next_price;
+    advance_curve(pool, args.asset_amount, false)?;
     pool.sellside_asset_amount = pool
         .sellside_asset_amount
         .checked_sub(args.asset_amount)
@@ -311,7 +356,7 @@ pub fn handler<'info>(
         .checked_add(lp_fee)
         .ok_or(MMMErrorCode::NumericOverflow)?;
 
-    let metadata_royalty_bp = get_metadata_royalty_bp(total_price, &parsed_metadata);
+    let metadata_royalty_bp = get_metadata_royalty_bp(total_price, &parsed_metadata, None);
     let royalty_paid = pay_creator_fees_in_sol(
         10000,
         total_price,
@@ -329,6 +374,8 @@ pub fn handler<'info>(
         .ok_or(MMMErrorCode::NumericOverflow)?
         .checked_add(taker_fee as u64)
         .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(protocol_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
         .checked_add(royalty_paid)
         .ok_or(MMMErrorCode::NumericOverflow)?;
     if payment_amount > args.max_payment_amount {
@@ -342,6 +389,7 @@ pub fn handler<'info>(
     try_close_sell_state(sell_state, owner.to_account_info())?;
 
     pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_sol_mip1_fulfill_sell", pool)?;
     try_close_pool(pool, owner.to_account_info())?;
 
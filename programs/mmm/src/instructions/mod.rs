@@ -1,9 +1,17 @@
 #![allow(missing_docs)]
 
 pub mod admin;
+pub mod cnft;
+pub mod ext_vanilla;
 pub mod mip1;
+pub mod mpl_core_asset;
+pub mod ocp;
 pub mod vanilla;
 
 pub use admin::*;
+pub use cnft::*;
+pub use ext_vanilla::*;
 pub use mip1::*;
+pub use mpl_core_asset::*;
+pub use ocp::*;
 pub use vanilla::*;
@@ -0,0 +1,9 @@
+pub mod mpl_core_deposit_sell;
+pub mod mpl_core_withdraw_sell;
+pub mod sol_mpl_core_fulfill_buy;
+pub mod sol_mpl_core_fulfill_sell;
+
+pub use mpl_core_deposit_sell::*;
+pub use mpl_core_withdraw_sell::*;
+pub use sol_mpl_core_fulfill_buy::*;
+pub use sol_mpl_core_fulfill_sell::*;
@@ -0,0 +1,106 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    mpl_core::{parse_asset, transfer, MPL_CORE_PROGRAM_ID},
+    state::{Pool, SellState},
+    util::{
+        check_allowlists_for_mpl_core_asset, get_allowlists, log_pool,
+        refresh_constant_product_spot_price,
+    },
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MplCoreDepositSellArgs {
+    pub allowlist_aux: Option<String>,
+}
+
+// MplCoreDepositSell escrows the core asset under the pool, i.e. the pool
+// becomes the owner of the asset.
+#[derive(Accounts)]
+#[instruction(args:MplCoreDepositSellArgs)]
+pub struct MplCoreDepositSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: checked in parse_asset
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+    /// CHECK: checked in parse_asset, the core program id if the asset has no collection
+    pub collection: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset.key().as_ref(),
+        ],
+        space = SellState::LEN,
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = MPL_CORE_PROGRAM_ID)]
+    pub asset_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MplCoreDepositSell>, args: MplCoreDepositSellArgs) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let asset = &ctx.accounts.asset;
+    let collection = &ctx.accounts.collection;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+
+    let parsed_asset = parse_asset(asset, collection)?;
+    check_allowlists_for_mpl_core_asset(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        asset.key,
+        &parsed_asset,
+        args.allowlist_aux,
+    )?;
+
+    transfer(
+        asset.to_account_info(),
+        collection.to_account_info(),
+        owner.to_account_info(),
+        owner.to_account_info(),
+        pool.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.asset_program.to_account_info(),
+        &[],
+    )?;
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_add(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    sell_state.pool = pool.key();
+    sell_state.pool_owner = owner.key();
+    sell_state.version = SELL_STATE_VERSION;
+    sell_state.asset_mint = asset.key();
+    sell_state.cosigner_annotation = pool.cosigner_annotation;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_add(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_mpl_core_deposit_sell", pool)?;
+
+    Ok(())
+}
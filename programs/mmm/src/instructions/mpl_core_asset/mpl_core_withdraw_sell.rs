@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    mpl_core::{transfer, MPL_CORE_PROGRAM_ID},
+    state::{Pool, SellState},
+    util::{log_pool, refresh_constant_product_spot_price, try_close_pool, try_close_sell_state},
+};
+
+#[derive(Accounts)]
+pub struct MplCoreWithdrawSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: checked in cpi
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+    /// CHECK: checked in cpi, the core program id if the asset has no collection
+    pub collection: UncheckedAccount<'info>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        mut,
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset.key().as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    pub system_program: Program<'info, System>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = MPL_CORE_PROGRAM_ID)]
+    pub asset_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MplCoreWithdrawSell>) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let asset = &ctx.accounts.asset;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let seed_owner = pool.seed_owner();
+    let pool_uuid = pool.uuid;
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool_uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    // Same as withdraw_sell, the allowlist check is skipped so that assets
+    // moved out of the collection can still be withdrawn by the pool owner.
+    transfer(
+        asset.to_account_info(),
+        ctx.accounts.collection.to_account_info(),
+        owner.to_account_info(),
+        pool.to_account_info(),
+        owner.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.asset_program.to_account_info(),
+        pool_seeds,
+    )?;
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(1)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    // spl payment pools keep track of buyside_payment_amount in the spl handlers
+    if pool.payment_mint.eq(&Pubkey::default()) {
+        pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    }
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_mpl_core_withdraw_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    Ok(())
+}
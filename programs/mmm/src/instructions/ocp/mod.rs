@@ -0,0 +1,9 @@
+pub mod ocp_deposit_sell;
+pub mod ocp_withdraw_sell;
+pub mod sol_ocp_fulfill_buy;
+pub mod sol_ocp_fulfill_sell;
+
+pub use ocp_deposit_sell::*;
+pub use ocp_withdraw_sell::*;
+pub use sol_ocp_fulfill_buy::*;
+pub use sol_ocp_fulfill_sell::*;
@@ -0,0 +1,167 @@
+use anchor_lang::{prelude::*, solana_program::sysvar, AnchorDeserialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    instructions::vanilla::DepositSellArgs,
+    ocp::{MintState, OcpAccounts, Policy, OCP_PROGRAM_ID},
+    state::{Pool, SellState},
+    util::{
+        check_allowlists_for_mint, get_allowlists, log_pool, refresh_constant_product_spot_price,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(args:DepositSellArgs)]
+pub struct OcpDepositSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: we will check the metadata in check_allowlists_for_mint()
+    pub asset_metadata: UncheckedAccount<'info>,
+    #[account(
+        constraint = asset_mint.supply == 1 && asset_mint.decimals == 0 @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub asset_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = owner,
+        constraint = asset_token_account.amount == 1 @ MMMErrorCode::InvalidOcpAssetParams,
+        constraint = args.asset_amount == 1 @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub asset_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: will be initialized by ocp if needed, and checked in cpi
+    #[account(
+        mut,
+        address = anchor_spl::associated_token::get_associated_token_address(&pool.key(), &asset_mint.key()),
+    )]
+    pub sellside_escrow_token_account: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        space = SellState::LEN,
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = ocp_mint_state.mint == asset_mint.key() @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub ocp_mint_state: Box<Account<'info, MintState>>,
+    #[account(
+        constraint = ocp_policy.key() == ocp_mint_state.policy @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub ocp_policy: Box<Account<'info, Policy>>,
+    /// CHECK: will be checked in cpi
+    pub ocp_freeze_authority: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = OCP_PROGRAM_ID)]
+    pub ocp_program: UncheckedAccount<'info>,
+    /// CHECK: will be checked in cpi
+    pub cmt_program: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<OcpDepositSell>, args: DepositSellArgs) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let asset_token_account = &ctx.accounts.asset_token_account;
+    let asset_mint = &ctx.accounts.asset_mint;
+    let asset_metadata = &ctx.accounts.asset_metadata;
+    let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let ocp = OcpAccounts {
+        ocp_program: ctx.accounts.ocp_program.to_account_info(),
+        policy: ctx.accounts.ocp_policy.to_account_info(),
+        freeze_authority: ctx.accounts.ocp_freeze_authority.to_account_info(),
+        mint: asset_mint.to_account_info(),
+        metadata: asset_metadata.to_account_info(),
+        mint_state: ctx.accounts.ocp_mint_state.to_account_info(),
+        cmt_program: ctx.accounts.cmt_program.to_account_info(),
+        instructions: ctx.accounts.instructions.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    check_allowlists_for_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        asset_mint,
+        asset_metadata,
+        None,
+        args.allowlist_aux,
+        ctx.remaining_accounts,
+        0,
+    )?;
+
+    // the ocp token accounts are frozen, so the escrow has to be created by ocp
+    if sellside_escrow_token_account.data_is_empty() {
+        ocp.init_account(
+            pool.to_account_info(),
+            sellside_escrow_token_account.to_account_info(),
+            owner.to_account_info(),
+            ctx.accounts.associated_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &[],
+        )?;
+    }
+    ocp.transfer(
+        owner.to_account_info(),
+        asset_token_account.to_account_info(),
+        pool.to_account_info(),
+        sellside_escrow_token_account.to_account_info(),
+        &[],
+    )?;
+    ocp.close(
+        owner.to_account_info(),
+        asset_token_account.to_account_info(),
+        owner.to_account_info(),
+        &[],
+    )?;
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_add(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    sell_state.pool = pool.key();
+    sell_state.pool_owner = owner.key();
+    sell_state.version = SELL_STATE_VERSION;
+    sell_state.asset_mint = asset_mint.key();
+    sell_state.cosigner_annotation = pool.cosigner_annotation;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_add(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_ocp_deposit_sell", pool)?;
+
+    Ok(())
+}
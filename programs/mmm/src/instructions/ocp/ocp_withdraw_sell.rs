@@ -0,0 +1,170 @@
+use anchor_lang::{prelude::*, solana_program::sysvar, AnchorDeserialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    instructions::vanilla::WithdrawSellArgs,
+    ocp::{MintState, OcpAccounts, Policy, OCP_PROGRAM_ID},
+    state::{Pool, SellState},
+    util::{log_pool, refresh_constant_product_spot_price, try_close_pool, try_close_sell_state},
+};
+
+#[derive(Accounts)]
+#[instruction(args:WithdrawSellArgs)]
+pub struct OcpWithdrawSell<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    /// CHECK: will be checked in cpi
+    pub asset_metadata: UncheckedAccount<'info>,
+    #[account(
+        constraint = asset_mint.supply == 1 && asset_mint.decimals == 0 @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub asset_mint: Account<'info, Mint>,
+    /// CHECK: will be initialized by ocp if needed, and checked in cpi
+    #[account(
+        mut,
+        address = anchor_spl::associated_token::get_associated_token_address(&owner.key(), &asset_mint.key()),
+    )]
+    pub asset_token_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = pool,
+        constraint = sellside_escrow_token_account.amount == 1 @ MMMErrorCode::InvalidOcpAssetParams,
+        constraint = args.asset_amount == 1 @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub sellside_escrow_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: it's a pda, and the private key is owned by the seeds
+    #[account(
+        mut,
+        seeds = [BUYSIDE_SOL_ESCROW_ACCOUNT_PREFIX.as_bytes(), pool.key().as_ref()],
+        bump,
+    )]
+    pub buyside_sol_escrow_account: UncheckedAccount<'info>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Account<'info, SellState>,
+
+    #[account(
+        mut,
+        constraint = ocp_mint_state.mint == asset_mint.key() @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub ocp_mint_state: Box<Account<'info, MintState>>,
+    #[account(
+        constraint = ocp_policy.key() == ocp_mint_state.policy @ MMMErrorCode::InvalidOcpAssetParams,
+    )]
+    pub ocp_policy: Box<Account<'info, Policy>>,
+    /// CHECK: will be checked in cpi
+    pub ocp_freeze_authority: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = OCP_PROGRAM_ID)]
+    pub ocp_program: UncheckedAccount<'info>,
+    /// CHECK: will be checked in cpi
+    pub cmt_program: UncheckedAccount<'info>,
+    /// CHECK: checked by address and in cpi
+    #[account(address = sysvar::instructions::id())]
+    pub instructions: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<OcpWithdrawSell>, args: WithdrawSellArgs) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let asset_token_account = &ctx.accounts.asset_token_account;
+    let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
+    let buyside_sol_escrow_account = &ctx.accounts.buyside_sol_escrow_account;
+    let pool = &mut ctx.accounts.pool;
+    let sell_state = &mut ctx.accounts.sell_state;
+    let ocp = OcpAccounts {
+        ocp_program: ctx.accounts.ocp_program.to_account_info(),
+        policy: ctx.accounts.ocp_policy.to_account_info(),
+        freeze_authority: ctx.accounts.ocp_freeze_authority.to_account_info(),
+        mint: ctx.accounts.asset_mint.to_account_info(),
+        metadata: ctx.accounts.asset_metadata.to_account_info(),
+        mint_state: ctx.accounts.ocp_mint_state.to_account_info(),
+        cmt_program: ctx.accounts.cmt_program.to_account_info(),
+        instructions: ctx.accounts.instructions.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let seed_owner = pool.seed_owner();
+    let pool_uuid = pool.uuid;
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool_uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    // Note that check_allowlists_for_mint is optional for withdraw_sell, the same
+    // as the vanilla withdraw_sell.
+    if asset_token_account.data_is_empty() {
+        ocp.init_account(
+            owner.to_account_info(),
+            asset_token_account.to_account_info(),
+            owner.to_account_info(),
+            ctx.accounts.associated_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            &[],
+        )?;
+    }
+    ocp.transfer(
+        pool.to_account_info(),
+        sellside_escrow_token_account.to_account_info(),
+        owner.to_account_info(),
+        asset_token_account.to_account_info(),
+        pool_seeds,
+    )?;
+    ocp.close(
+        pool.to_account_info(),
+        sellside_escrow_token_account.to_account_info(),
+        owner.to_account_info(),
+        pool_seeds,
+    )?;
+
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    // spl payment pools keep track of buyside_payment_amount in the spl handlers
+    if pool.payment_mint.eq(&Pubkey::default()) {
+        pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    }
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_ocp_withdraw_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    Ok(())
+}
@@ -8,7 +8,9 @@ use crate::{
     constants::*,
     errors::MMMErrorCode,
     state::{Pool, SellState},
-    util::{check_allowlists_for_mint, log_pool},
+    util::{
+        check_allowlists_for_mint, get_allowlists, log_pool, refresh_constant_product_spot_price,
+    },
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -22,12 +24,14 @@ pub struct DepositSellArgs {
 pub struct DepositSell<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         bump
     )]
     pub pool: Box<Account<'info, Pool>>,
@@ -81,11 +85,13 @@ pub fn handler(ctx: Context<DepositSell>, args: DepositSellArgs) -> Result<()> {
     let sell_state = &mut ctx.accounts.sell_state;
 
     check_allowlists_for_mint(
-        &pool.allowlists,
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
         asset_mint,
         asset_metadata,
         Some(asset_master_edition),
         args.allowlist_aux,
+        ctx.remaining_accounts,
+        0,
     )?;
 
     anchor_spl::token::transfer(
@@ -118,12 +124,14 @@ pub fn handler(ctx: Context<DepositSell>, args: DepositSellArgs) -> Result<()> {
 
     sell_state.pool = pool.key();
     sell_state.pool_owner = owner.key();
+    sell_state.version = SELL_STATE_VERSION;
     sell_state.asset_mint = asset_mint.key();
     sell_state.cosigner_annotation = pool.cosigner_annotation;
     sell_state.asset_amount = sell_state
         .asset_amount
         .checked_add(args.asset_amount)
         .ok_or(MMMErrorCode::NumericOverflow)?;
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_deposit_sell", pool)?;
 
     Ok(())
@@ -5,6 +5,10 @@ pub mod sol_deposit_buy;
 pub mod sol_fulfill_buy;
 pub mod sol_fulfill_sell;
 pub mod sol_withdraw_buy;
+pub mod spl_deposit_buy;
+pub mod spl_fulfill_buy;
+pub mod spl_fulfill_sell;
+pub mod spl_withdraw_buy;
 pub mod withdraw_sell;
 
 pub use deposit_sell::*;
@@ -12,4 +16,8 @@ pub use sol_deposit_buy::*;
 pub use sol_fulfill_buy::*;
 pub use sol_fulfill_sell::*;
 pub use sol_withdraw_buy::*;
+pub use spl_deposit_buy::*;
+pub use spl_fulfill_buy::*;
+pub use spl_fulfill_sell::*;
+pub use spl_withdraw_buy::*;
 pub use withdraw_sell::*;
@@ -2,7 +2,7 @@ use crate::{
     constants::*,
     errors::MMMErrorCode,
     state::Pool,
-    util::{log_pool, try_close_escrow, try_close_pool},
+    util::{log_pool, refresh_constant_product_spot_price, try_close_escrow, try_close_pool},
 };
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 
@@ -16,12 +16,14 @@ pub struct SolWithdrawBuyArgs {
 pub struct SolWithdrawBuy<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         constraint = pool.payment_mint.eq(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
         bump
     )]
@@ -74,6 +76,7 @@ pub fn handler(ctx: Context<SolWithdrawBuy>, args: SolWithdrawBuyArgs) -> Result
     )?;
 
     pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_sol_withdraw_buy", pool)?;
     try_close_pool(pool, owner.to_account_info())?;
     Ok(())
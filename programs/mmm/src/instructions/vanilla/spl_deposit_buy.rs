@@ -0,0 +1,82 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::Pool,
+    util::{log_pool, refresh_constant_product_spot_price},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SplDepositBuyArgs {
+    payment_amount: u64,
+}
+
+// This is targeting the deposit of spl payment_mint, e.g. USDC
+#[derive(Accounts)]
+#[instruction(args:SplDepositBuyArgs)]
+pub struct SplDepositBuy<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        has_one = payment_mint @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.payment_mint.ne(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    pub payment_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = owner,
+    )]
+    pub owner_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = payment_mint,
+        associated_token::authority = pool,
+    )]
+    pub buyside_payment_escrow_account: Box<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<SplDepositBuy>, args: SplDepositBuyArgs) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let owner_payment_token_account = &ctx.accounts.owner_payment_token_account;
+    let buyside_payment_escrow_account = &mut ctx.accounts.buyside_payment_escrow_account;
+    let token_program = &ctx.accounts.token_program;
+    let pool = &mut ctx.accounts.pool;
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: owner_payment_token_account.to_account_info(),
+                to: buyside_payment_escrow_account.to_account_info(),
+                authority: owner.to_account_info(),
+            },
+        ),
+        args.payment_amount,
+    )?;
+
+    buyside_payment_escrow_account.reload()?;
+    pool.buyside_payment_amount = buyside_payment_escrow_account.amount;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_spl_deposit_buy", pool)?;
+    Ok(())
+}
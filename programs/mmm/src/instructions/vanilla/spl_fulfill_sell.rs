@@ -0,0 +1,356 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use std::convert::TryFrom;
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    instructions::sol_fulfill_sell::SolFulfillSellArgs,
+    state::{GlobalConfig, Pool, SellState},
+    util::{
+        advance_curve, assert_valid_fees_bp, check_allowlists_for_mint,
+        get_allowlist_multiplier_bp, get_allowlists, get_metadata_royalty_bp, get_protocol_fee,
+        get_sol_fee, get_sol_lp_fee, get_sol_total_price_and_next_price, log_pool,
+        pay_creator_fees_in_spl, refresh_constant_product_spot_price, try_close_pool,
+        try_close_sell_state,
+    },
+};
+
+// SplFulfillSell is the same as SolFulfillSell, but the buyer pays in the
+// payment_mint (e.g. USDC) of the pool. The lp fee, referral fee and creator
+// royalties are all paid in the payment_mint.
+#[derive(Accounts)]
+#[instruction(args:SolFulfillSellArgs)]
+pub struct SplFulfillSell<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: we will check the owner field that matches the pool owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    pub cosigner: Signer<'info>,
+    /// CHECK: we will check that the referral matches the pool's referral
+    #[account(mut)]
+    pub referral: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = referral @ MMMErrorCode::InvalidReferral,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        has_one = payment_mint @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.payment_mint.ne(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.expiry == 0 || pool.expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.sellside_expiry == 0 || pool.sellside_expiry > Clock::get().unwrap().unix_timestamp @ MMMErrorCode::Expired,
+        constraint = pool.start_time <= Clock::get().unwrap().unix_timestamp @ MMMErrorCode::PoolNotStarted,
+        constraint = !pool.sellside_paused @ MMMErrorCode::PoolPaused,
+        constraint = args.buyside_creator_royalty_bp <= 10000 @ MMMErrorCode::InvalidBP,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(
+        seeds = [GLOBAL_CONFIG_PREFIX.as_bytes()],
+        bump,
+        constraint = !global_config.paused @ MMMErrorCode::ProgramPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+    /// CHECK: checked by the treasury of the global_config
+    #[account(mut, address = global_config.treasury @ MMMErrorCode::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub payment_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = pool,
+    )]
+    pub buyside_payment_escrow_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = payer,
+    )]
+    pub payer_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = referral,
+    )]
+    pub referral_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+    )]
+    pub treasury_payment_token_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: we will check the metadata in check_allowlists_for_mint()
+    pub asset_metadata: UncheckedAccount<'info>,
+    /// CHECK: we will check the master_edtion in check_allowlists_for_mint()
+    pub asset_master_edition: UncheckedAccount<'info>,
+    /// CHECK: check_allowlists_for_mint
+    pub asset_mint: Box<Account<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = pool,
+    )]
+    pub sellside_escrow_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = asset_mint,
+        associated_token::authority = payer,
+    )]
+    pub payer_asset_account: Box<Account<'info, TokenAccount>>,
+    /// CHECK: will be used for allowlist checks
+    pub allowlist_aux_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [
+            SELL_STATE_PREFIX.as_bytes(),
+            pool.key().as_ref(),
+            asset_mint.key().as_ref(),
+        ],
+        bump
+    )]
+    pub sell_state: Box<Account<'info, SellState>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SplFulfillSell<'info>>,
+    args: SolFulfillSellArgs,
+) -> Result<()> {
+    let token_program = &ctx.accounts.token_program;
+    let system_program = &ctx.accounts.system_program;
+    let associated_token_program = &ctx.accounts.associated_token_program;
+    let rent = &ctx.accounts.rent;
+    let owner = &ctx.accounts.owner;
+    let pool = &mut ctx.accounts.pool;
+    let global_config = &ctx.accounts.global_config;
+    let sell_state = &mut ctx.accounts.sell_state;
+
+    let payer = &ctx.accounts.payer;
+    let payer_asset_account = &ctx.accounts.payer_asset_account;
+    let asset_mint = &ctx.accounts.asset_mint;
+    let payer_asset_metadata = &ctx.accounts.asset_metadata;
+    let asset_master_edition = &ctx.accounts.asset_master_edition;
+
+    let sellside_escrow_token_account = &ctx.accounts.sellside_escrow_token_account;
+    let payment_mint = &ctx.accounts.payment_mint;
+    let buyside_payment_escrow_account = &mut ctx.accounts.buyside_payment_escrow_account;
+    let payer_payment_token_account = &ctx.accounts.payer_payment_token_account;
+    let owner_payment_token_account = &ctx.accounts.owner_payment_token_account;
+    let referral_payment_token_account = &ctx.accounts.referral_payment_token_account;
+    let treasury_payment_token_account = &ctx.accounts.treasury_payment_token_account;
+    let seed_owner = pool.seed_owner();
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool.uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    let (parsed_metadata, allowlist_match) = check_allowlists_for_mint(
+        &get_allowlists(pool, &ctx.accounts.allowlist_aux_account)?,
+        asset_mint,
+        payer_asset_metadata,
+        Some(asset_master_edition),
+        args.allowlist_aux,
+        ctx.remaining_accounts,
+        2,
+    )?;
+
+    let allowlist_multiplier_bp =
+        get_allowlist_multiplier_bp(pool, allowlist_match, &ctx.accounts.allowlist_aux_account);
+    let (total_price, next_price) = get_sol_total_price_and_next_price(
+        pool,
+        args.asset_amount,
+        false,
+        allowlist_multiplier_bp,
+    )?;
+    let lp_fee = get_sol_lp_fee(pool, buyside_payment_escrow_account.amount, total_price)?;
+
+    assert_valid_fees_bp(
+        args.maker_fee_bp,
+        args.taker_fee_bp,
+        global_config.max_referral_fee_bp,
+    )?;
+    let maker_fee = get_sol_fee(total_price, args.maker_fee_bp)?;
+    let taker_fee = get_sol_fee(total_price, args.taker_fee_bp)?;
+    let protocol_fee = get_protocol_fee(total_price, global_config.protocol_fee_bp)?;
+    let referral_fee = u64::try_from(
+        maker_fee
+            .checked_add(taker_fee)
+            .ok_or(MMMErrorCode::NumericOverflow)?,
+    )
+    .map_err(|_| MMMErrorCode::NumericOverflow)?;
+
+    let transfer_payment_to = if pool.reinvest_fulfill_sell {
+        buyside_payment_escrow_account.to_account_info()
+    } else {
+        owner_payment_token_account.to_account_info()
+    };
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: payer_payment_token_account.to_account_info(),
+                to: transfer_payment_to,
+                authority: payer.to_account_info(),
+            },
+        ),
+        u64::try_from(
+            i64::try_from(total_price)
+                .map_err(|_| MMMErrorCode::NumericOverflow)?
+                .checked_sub(maker_fee)
+                .ok_or(MMMErrorCode::NumericOverflow)?,
+        )
+        .map_err(|_| MMMErrorCode::NumericOverflow)?,
+    )?;
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: sellside_escrow_token_account.to_account_info(),
+                to: payer_asset_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            pool_seeds,
+        ),
+        args.asset_amount,
+    )?;
+    // we can close the sellside_escrow_token_account if no amount left
+    if sellside_escrow_token_account.amount == args.asset_amount {
+        anchor_spl::token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            anchor_spl::token::CloseAccount {
+                account: sellside_escrow_token_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            pool_seeds,
+        ))?;
+    }
+
+    if lp_fee > 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: payer_payment_token_account.to_account_info(),
+                    to: owner_payment_token_account.to_account_info(),
+                    authority: payer.to_account_info(),
+                },
+            ),
+            lp_fee,
+        )?;
+    }
+
+    if referral_fee > 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: payer_payment_token_account.to_account_info(),
+                    to: referral_payment_token_account.to_account_info(),
+                    authority: payer.to_account_info(),
+                },
+            ),
+            referral_fee,
+        )?;
+    }
+    if protocol_fee > 0 {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                token_program.to_account_info(),
+                anchor_spl::token::Transfer {
+                    from: payer_payment_token_account.to_account_info(),
+                    to: treasury_payment_token_account.to_account_info(),
+                    authority: payer.to_account_info(),
+                },
+            ),
+            protocol_fee,
+        )?;
+    }
+
+    pool.spot_price = next_price;
+    advance_curve(pool, args.asset_amount, false)?;
+    pool.sellside_asset_amount = pool
+        .sellside_asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    pool.lp_fee_earned = pool
+        .lp_fee_earned
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+
+    let royalty_bp = get_metadata_royalty_bp(total_price, &parsed_metadata, None);
+    let royalty_paid = pay_creator_fees_in_spl(
+        args.buyside_creator_royalty_bp,
+        total_price,
+        &parsed_metadata,
+        ctx.remaining_accounts,
+        payer.to_account_info(),
+        payer_payment_token_account.to_account_info(),
+        royalty_bp,
+        &[&[&[]]],
+        payment_mint.to_account_info(),
+        payer.to_account_info(),
+        associated_token_program.to_account_info(),
+        token_program.to_account_info(),
+        system_program.to_account_info(),
+        rent.to_account_info(),
+    )?;
+
+    // prevent frontrun by pool config changes
+    let payment_amount = total_price
+        .checked_add(lp_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(taker_fee as u64)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(protocol_fee)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(royalty_paid)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    if payment_amount > args.max_payment_amount {
+        return Err(MMMErrorCode::InvalidRequestedPrice.into());
+    }
+
+    sell_state.asset_amount = sell_state
+        .asset_amount
+        .checked_sub(args.asset_amount)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    try_close_sell_state(sell_state, owner.to_account_info())?;
+
+    buyside_payment_escrow_account.reload()?;
+    pool.buyside_payment_amount = buyside_payment_escrow_account.amount;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_spl_fulfill_sell", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+
+    msg!(
+        "{{\"lp_fee\":{},\"royalty_paid\":{},\"total_price\":{}}}",
+        lp_fee,
+        royalty_paid,
+        total_price,
+    );
+
+    Ok(())
+}
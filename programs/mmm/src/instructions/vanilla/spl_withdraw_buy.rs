@@ -0,0 +1,110 @@
+use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::{
+    constants::*,
+    errors::MMMErrorCode,
+    state::Pool,
+    util::{log_pool, refresh_constant_product_spot_price, try_close_pool},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SplWithdrawBuyArgs {
+    payment_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args:SplWithdrawBuyArgs)]
+pub struct SplWithdrawBuy<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
+        has_one = owner @ MMMErrorCode::InvalidOwner,
+        has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
+        has_one = payment_mint @ MMMErrorCode::InvalidPaymentMint,
+        constraint = pool.payment_mint.ne(&Pubkey::default()) @ MMMErrorCode::InvalidPaymentMint,
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    pub payment_mint: Box<Account<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = payment_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_payment_token_account: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = pool,
+    )]
+    pub buyside_payment_escrow_account: Box<Account<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<SplWithdrawBuy>, args: SplWithdrawBuyArgs) -> Result<()> {
+    let owner = &ctx.accounts.owner;
+    let owner_payment_token_account = &ctx.accounts.owner_payment_token_account;
+    let buyside_payment_escrow_account = &ctx.accounts.buyside_payment_escrow_account;
+    let token_program = &ctx.accounts.token_program;
+    let pool = &mut ctx.accounts.pool;
+    let seed_owner = pool.seed_owner();
+    let pool_seeds: &[&[&[u8]]] = &[&[
+        POOL_PREFIX.as_bytes(),
+        seed_owner.as_ref(),
+        pool.uuid.as_ref(),
+        &[*ctx.bumps.get("pool").unwrap()],
+    ]];
+
+    let amount_to_withdraw =
+        std::cmp::min(args.payment_amount, buyside_payment_escrow_account.amount);
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: buyside_payment_escrow_account.to_account_info(),
+                to: owner_payment_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            // seeds should be the PDA of 'pool'
+            pool_seeds,
+        ),
+        amount_to_withdraw,
+    )?;
+
+    // we can close the buyside_payment_escrow_account if no amount left
+    let remaining_amount = buyside_payment_escrow_account
+        .amount
+        .checked_sub(amount_to_withdraw)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    if remaining_amount == 0 {
+        anchor_spl::token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            anchor_spl::token::CloseAccount {
+                account: buyside_payment_escrow_account.to_account_info(),
+                destination: owner.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            pool_seeds,
+        ))?;
+    }
+
+    pool.buyside_payment_amount = remaining_amount;
+    refresh_constant_product_spot_price(pool)?;
+    log_pool("post_spl_withdraw_buy", pool)?;
+    try_close_pool(pool, owner.to_account_info())?;
+    Ok(())
+}
@@ -8,7 +8,7 @@ use crate::{
     constants::*,
     errors::MMMErrorCode,
     state::{Pool, SellState},
-    util::{log_pool, try_close_pool, try_close_sell_state},
+    util::{log_pool, refresh_constant_product_spot_price, try_close_pool, try_close_sell_state},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -22,12 +22,14 @@ pub struct WithdrawSellArgs {
 pub struct WithdrawSell<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
-    pub cosigner: Signer<'info>,
+    /// CHECK: checked by has_one, and it only needs to sign if the pool has a cosigner
+    pub cosigner: UncheckedAccount<'info>,
     #[account(
         mut,
-        seeds = [POOL_PREFIX.as_bytes(), owner.key().as_ref(), pool.uuid.as_ref()],
+        seeds = [POOL_PREFIX.as_bytes(), pool.seed_owner().as_ref(), pool.uuid.as_ref()],
         has_one = owner @ MMMErrorCode::InvalidOwner,
         has_one = cosigner @ MMMErrorCode::InvalidCosigner,
+        constraint = pool.no_cosigner || cosigner.is_signer @ MMMErrorCode::InvalidCosigner,
         bump
     )]
     pub pool: Box<Account<'info, Pool>>,
@@ -95,7 +97,7 @@ pub fn handler(ctx: Context<WithdrawSell>, args: WithdrawSellArgs) -> Result<()>
             // seeds should be the PDA of 'pool'
             &[&[
                 POOL_PREFIX.as_bytes(),
-                owner.key().as_ref(),
+                pool.seed_owner().as_ref(),
                 pool.uuid.key().as_ref(),
                 &[*ctx.bumps.get("pool").unwrap()],
             ]],
@@ -114,7 +116,7 @@ pub fn handler(ctx: Context<WithdrawSell>, args: WithdrawSellArgs) -> Result<()>
             // seeds should be the PDA of 'pool'
             &[&[
                 POOL_PREFIX.as_bytes(),
-                owner.key().as_ref(),
+                pool.seed_owner().as_ref(),
                 pool.uuid.key().as_ref(),
                 &[*ctx.bumps.get("pool").unwrap()],
             ]],
@@ -131,7 +133,11 @@ pub fn handler(ctx: Context<WithdrawSell>, args: WithdrawSellArgs) -> Result<()>
         .ok_or(MMMErrorCode::NumericOverflow)?;
     try_close_sell_state(sell_state, owner.to_account_info())?;
 
-    pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    // spl payment pools keep track of buyside_payment_amount in the spl handlers
+    if pool.payment_mint.eq(&Pubkey::default()) {
+        pool.buyside_payment_amount = buyside_sol_escrow_account.lamports();
+    }
+    refresh_constant_product_spot_price(pool)?;
     log_pool("post_withdraw_sell", pool)?;
     try_close_pool(pool, owner.to_account_info())?;
 
@@ -5,10 +5,14 @@ use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
 declare_id!("cLawU4m5wgQ7nkwdkX6yRdkZ5NR6yN8dNuCmEiqTjk6");
 
 mod ata;
+pub mod bubblegum;
 mod constants;
 mod errors;
 pub mod instructions;
+pub mod mpl_core;
+pub mod ocp;
 pub mod state;
+pub mod token_ext;
 pub mod util;
 
 use instructions::*;
@@ -25,10 +29,106 @@ pub mod mmm {
         instructions::update_pool::handler(ctx, args)
     }
 
+    pub fn update_allowlists(
+        ctx: Context<UpdateAllowlists>,
+        args: UpdateAllowlistsArgs,
+    ) -> Result<()> {
+        instructions::update_allowlists::handler(ctx, args)
+    }
+
+    pub fn update_overflow_allowlist(
+        ctx: Context<UpdateOverflowAllowlist>,
+        args: UpdateOverflowAllowlistArgs,
+    ) -> Result<()> {
+        instructions::update_overflow_allowlist::handler(ctx, args)
+    }
+
+    pub fn create_allowlist_registry(
+        ctx: Context<CreateAllowlistRegistry>,
+        args: CreateAllowlistRegistryArgs,
+    ) -> Result<()> {
+        instructions::create_allowlist_registry::handler(ctx, args)
+    }
+
+    pub fn update_allowlist_registry(
+        ctx: Context<UpdateAllowlistRegistry>,
+        args: UpdateAllowlistRegistryArgs,
+    ) -> Result<()> {
+        instructions::update_allowlist_registry::handler(ctx, args)
+    }
+
+    pub fn update_pool_cosigner(
+        ctx: Context<UpdatePoolCosigner>,
+        args: UpdatePoolCosignerArgs,
+    ) -> Result<()> {
+        instructions::update_pool_cosigner::handler(ctx, args)
+    }
+
+    pub fn update_pool_operator(
+        ctx: Context<UpdatePoolOperator>,
+        args: UpdatePoolOperatorArgs,
+    ) -> Result<()> {
+        instructions::update_pool_operator::handler(ctx, args)
+    }
+
+    pub fn operator_update_pool(
+        ctx: Context<OperatorUpdatePool>,
+        args: OperatorUpdatePoolArgs,
+    ) -> Result<()> {
+        instructions::operator_update_pool::handler(ctx, args)
+    }
+
+    pub fn set_pool_pause(ctx: Context<SetPoolPause>, args: SetPoolPauseArgs) -> Result<()> {
+        instructions::set_pool_pause::handler(ctx, args)
+    }
+
+    pub fn init_global_config(
+        ctx: Context<InitGlobalConfig>,
+        args: InitGlobalConfigArgs,
+    ) -> Result<()> {
+        instructions::init_global_config::handler(ctx, args)
+    }
+
+    pub fn update_global_config(
+        ctx: Context<UpdateGlobalConfig>,
+        args: UpdateGlobalConfigArgs,
+    ) -> Result<()> {
+        instructions::update_global_config::handler(ctx, args)
+    }
+
+    pub fn set_global_pause(ctx: Context<SetGlobalPause>, args: SetGlobalPauseArgs) -> Result<()> {
+        instructions::set_global_pause::handler(ctx, args)
+    }
+
     pub fn sol_close_pool(ctx: Context<SolClosePool>) -> Result<()> {
         instructions::sol_close_pool::handler(ctx)
     }
 
+    pub fn propose_pool_owner(
+        ctx: Context<ProposePoolOwner>,
+        args: ProposePoolOwnerArgs,
+    ) -> Result<()> {
+        instructions::propose_pool_owner::handler(ctx, args)
+    }
+
+    pub fn accept_pool_owner(ctx: Context<AcceptPoolOwner>) -> Result<()> {
+        instructions::accept_pool_owner::handler(ctx)
+    }
+
+    pub fn sync_sell_state_owner<'info>(
+        ctx: Context<'_, '_, '_, 'info, SyncSellStateOwner<'info>>,
+    ) -> Result<()> {
+        instructions::sync_sell_state_owner::handler(ctx)
+    }
+
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        instructions::migrate_pool::handler(ctx)
+    }
+
+    pub fn migrate_sell_state(ctx: Context<MigrateSellState>) -> Result<()> {
+        instructions::migrate_sell_state::handler(ctx)
+    }
+
     pub fn sol_deposit_buy(ctx: Context<SolDepositBuy>, args: SolDepositBuyArgs) -> Result<()> {
         instructions::sol_deposit_buy::handler(ctx, args)
     }
@@ -59,6 +159,32 @@ pub mod mmm {
         instructions::deposit_sell::handler(ctx, args)
     }
 
+    pub fn spl_close_pool(ctx: Context<SplClosePool>) -> Result<()> {
+        instructions::spl_close_pool::handler(ctx)
+    }
+
+    pub fn spl_deposit_buy(ctx: Context<SplDepositBuy>, args: SplDepositBuyArgs) -> Result<()> {
+        instructions::spl_deposit_buy::handler(ctx, args)
+    }
+
+    pub fn spl_withdraw_buy(ctx: Context<SplWithdrawBuy>, args: SplWithdrawBuyArgs) -> Result<()> {
+        instructions::spl_withdraw_buy::handler(ctx, args)
+    }
+
+    pub fn spl_fulfill_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SplFulfillBuy<'info>>,
+        args: SolFulfillBuyArgs,
+    ) -> Result<()> {
+        instructions::spl_fulfill_buy::handler(ctx, args)
+    }
+
+    pub fn spl_fulfill_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, SplFulfillSell<'info>>,
+        args: SolFulfillSellArgs,
+    ) -> Result<()> {
+        instructions::spl_fulfill_sell::handler(ctx, args)
+    }
+
     pub fn mip1_deposit_sell(ctx: Context<Mip1DepositSell>, args: DepositSellArgs) -> Result<()> {
         instructions::mip1_deposit_sell::handler(ctx, args)
     }
@@ -83,4 +209,107 @@ pub mod mmm {
     ) -> Result<()> {
         instructions::sol_mip1_fulfill_buy::handler(ctx, args)
     }
+
+    pub fn cnft_deposit_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, CnftDepositSell<'info>>,
+        args: CnftDepositSellArgs,
+    ) -> Result<()> {
+        instructions::cnft_deposit_sell::handler(ctx, args)
+    }
+
+    pub fn cnft_withdraw_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, CnftWithdrawSell<'info>>,
+        args: CnftWithdrawSellArgs,
+    ) -> Result<()> {
+        instructions::cnft_withdraw_sell::handler(ctx, args)
+    }
+
+    pub fn sol_cnft_fulfill_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolCnftFulfillBuy<'info>>,
+        args: SolCnftFulfillBuyArgs,
+    ) -> Result<()> {
+        instructions::sol_cnft_fulfill_buy::handler(ctx, args)
+    }
+
+    pub fn sol_cnft_fulfill_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolCnftFulfillSell<'info>>,
+        args: SolCnftFulfillSellArgs,
+    ) -> Result<()> {
+        instructions::sol_cnft_fulfill_sell::handler(ctx, args)
+    }
+
+    pub fn ext_deposit_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtDepositSell<'info>>,
+        args: ExtDepositSellArgs,
+    ) -> Result<()> {
+        instructions::ext_deposit_sell::handler(ctx, args)
+    }
+
+    pub fn ext_withdraw_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtWithdrawSell<'info>>,
+        args: ExtWithdrawSellArgs,
+    ) -> Result<()> {
+        instructions::ext_withdraw_sell::handler(ctx, args)
+    }
+
+    pub fn sol_ext_fulfill_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolExtFulfillBuy<'info>>,
+        args: SolExtFulfillBuyArgs,
+    ) -> Result<()> {
+        instructions::sol_ext_fulfill_buy::handler(ctx, args)
+    }
+
+    pub fn sol_ext_fulfill_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolExtFulfillSell<'info>>,
+        args: SolExtFulfillSellArgs,
+    ) -> Result<()> {
+        instructions::sol_ext_fulfill_sell::handler(ctx, args)
+    }
+
+    pub fn mpl_core_deposit_sell(
+        ctx: Context<MplCoreDepositSell>,
+        args: MplCoreDepositSellArgs,
+    ) -> Result<()> {
+        instructions::mpl_core_deposit_sell::handler(ctx, args)
+    }
+
+    pub fn mpl_core_withdraw_sell(ctx: Context<MplCoreWithdrawSell>) -> Result<()> {
+        instructions::mpl_core_withdraw_sell::handler(ctx)
+    }
+
+    pub fn sol_mpl_core_fulfill_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolMplCoreFulfillBuy<'info>>,
+        args: SolMplCoreFulfillBuyArgs,
+    ) -> Result<()> {
+        instructions::sol_mpl_core_fulfill_buy::handler(ctx, args)
+    }
+
+    pub fn sol_mpl_core_fulfill_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolMplCoreFulfillSell<'info>>,
+        args: SolMplCoreFulfillSellArgs,
+    ) -> Result<()> {
+        instructions::sol_mpl_core_fulfill_sell::handler(ctx, args)
+    }
+
+    pub fn ocp_deposit_sell(ctx: Context<OcpDepositSell>, args: DepositSellArgs) -> Result<()> {
+        instructions::ocp_deposit_sell::handler(ctx, args)
+    }
+
+    pub fn ocp_withdraw_sell(ctx: Context<OcpWithdrawSell>, args: WithdrawSellArgs) -> Result<()> {
+        instructions::ocp_withdraw_sell::handler(ctx, args)
+    }
+
+    pub fn sol_ocp_fulfill_buy<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolOcpFulfillBuy<'info>>,
+        args: SolFulfillBuyArgs,
+    ) -> Result<()> {
+        instructions::sol_ocp_fulfill_buy::handler(ctx, args)
+    }
+
+    pub fn sol_ocp_fulfill_sell<'info>(
+        ctx: Context<'_, '_, '_, 'info, SolOcpFulfillSell<'info>>,
+        args: SolOcpFulfillSellArgs,
+    ) -> Result<()> {
+        instructions::sol_ocp_fulfill_sell::handler(ctx, args)
+    }
 }
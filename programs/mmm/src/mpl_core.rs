@@ -0,0 +1,261 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed, pubkey},
+};
+use mpl_token_metadata::state::{Creator as MplCreator, Data};
+
+use crate::errors::MMMErrorCode;
+
+pub const MPL_CORE_PROGRAM_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+// The following account layouts are copied from mpl-core, and they are only
+// partially deserialized, i.e. only the fields that the pool needs are parsed.
+const KEY_ASSET_V1: u8 = 1;
+const KEY_PLUGIN_HEADER_V1: u8 = 3;
+const KEY_PLUGIN_REGISTRY_V1: u8 = 4;
+const KEY_COLLECTION_V1: u8 = 5;
+const PLUGIN_TYPE_ROYALTIES: u8 = 0;
+// MplAssetInstruction::TransferV1
+const TRANSFER_V1_DISCRIMINATOR: u8 = 14;
+
+#[derive(AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum UpdateAuthority {
+    None,
+    Address(Pubkey),
+    Collection(Pubkey),
+}
+
+#[derive(AnchorDeserialize, Clone)]
+pub struct BaseAssetV1 {
+    pub key: u8,
+    pub owner: Pubkey,
+    pub update_authority: UpdateAuthority,
+    pub name: String,
+    pub uri: String,
+    pub seq: Option<u64>,
+}
+
+#[derive(AnchorDeserialize, Clone)]
+pub struct BaseCollectionV1 {
+    pub key: u8,
+    pub update_authority: Pubkey,
+    pub name: String,
+    pub uri: String,
+    pub num_minted: u32,
+    pub current_size: u32,
+}
+
+#[derive(AnchorDeserialize, Clone)]
+struct PluginHeaderV1 {
+    key: u8,
+    plugin_registry_offset: u64,
+}
+
+// Authority is only deserialized to skip over it in the registry records
+#[allow(dead_code, clippy::enum_variant_names)]
+#[derive(AnchorDeserialize, Clone)]
+enum Authority {
+    None,
+    Owner,
+    UpdateAuthority,
+    Address { address: Pubkey },
+}
+
+#[derive(AnchorDeserialize, Clone)]
+struct RegistryRecord {
+    plugin_type: u8,
+    #[allow(dead_code)]
+    authority: Authority,
+    offset: u64,
+}
+
+#[derive(AnchorDeserialize, Clone)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub percentage: u8,
+}
+
+// Royalties is the royalties plugin without the trailing rule_set,
+// the rule_set is enforced by mpl-core itself in the transfer cpi.
+#[derive(AnchorDeserialize, Clone)]
+pub struct Royalties {
+    pub basis_points: u16,
+    pub creators: Vec<Creator>,
+}
+
+// deserialize_prefix deserializes T from the beginning of the data and returns
+// the serialized length of T, which is where the plugin header starts.
+fn deserialize_prefix<T: AnchorDeserialize>(data: &[u8]) -> Result<(T, usize)> {
+    let mut cursor = data;
+    let value = T::deserialize(&mut cursor).map_err(|_| MMMErrorCode::InvalidMplCoreAsset)?;
+    Ok((value, data.len() - cursor.len()))
+}
+
+// get_royalties finds the royalties plugin through the plugin header and registry
+// that follow the base account at base_len.
+fn get_royalties(data: &[u8], base_len: usize) -> Result<Option<Royalties>> {
+    if data.len() <= base_len {
+        return Ok(None);
+    }
+    let (header, _) = deserialize_prefix::<PluginHeaderV1>(&data[base_len..])?;
+    if header.key != KEY_PLUGIN_HEADER_V1 {
+        return Err(MMMErrorCode::InvalidMplCoreAsset.into());
+    }
+    let registry_data = data
+        .get(header.plugin_registry_offset as usize..)
+        .ok_or(MMMErrorCode::InvalidMplCoreAsset)?;
+    let (registry, _) = deserialize_prefix::<(u8, Vec<RegistryRecord>)>(registry_data)?;
+    if registry.0 != KEY_PLUGIN_REGISTRY_V1 {
+        return Err(MMMErrorCode::InvalidMplCoreAsset.into());
+    }
+
+    let record = match registry
+        .1
+        .iter()
+        .find(|r| r.plugin_type == PLUGIN_TYPE_ROYALTIES)
+    {
+        Some(record) => record,
+        None => return Ok(None),
+    };
+    let plugin_data = data
+        .get(record.offset as usize..)
+        .ok_or(MMMErrorCode::InvalidMplCoreAsset)?;
+    // the plugin is a borsh enum, and Royalties is the first variant
+    if plugin_data.first() != Some(&PLUGIN_TYPE_ROYALTIES) {
+        return Err(MMMErrorCode::InvalidMplCoreAsset.into());
+    }
+    let (royalties, _) = deserialize_prefix::<Royalties>(&plugin_data[1..])?;
+    Ok(Some(royalties))
+}
+
+pub struct MplCoreAsset {
+    pub base: BaseAssetV1,
+    pub royalties: Option<Royalties>,
+}
+
+impl MplCoreAsset {
+    pub fn collection(&self) -> Option<Pubkey> {
+        match self.base.update_authority {
+            UpdateAuthority::Collection(collection) => Some(collection),
+            _ => None,
+        }
+    }
+
+    // update_authority is only the address update authority, the collection update
+    // authority lives in the collection account.
+    pub fn update_authority(&self) -> Option<Pubkey> {
+        match self.base.update_authority {
+            UpdateAuthority::Address(address) => Some(address),
+            _ => None,
+        }
+    }
+
+    // royalty_data converts the royalties into the token metadata format, so that the
+    // royalties can be paid by pay_creator_fees_in_sol_for_data.
+    pub fn royalty_data(&self) -> Data {
+        Data {
+            name: self.base.name.clone(),
+            symbol: String::new(),
+            uri: self.base.uri.clone(),
+            seller_fee_basis_points: self.royalties.as_ref().map_or(0, |r| r.basis_points),
+            creators: self.royalties.as_ref().map(|r| {
+                r.creators
+                    .iter()
+                    .map(|c| MplCreator {
+                        address: c.address,
+                        verified: true,
+                        share: c.percentage,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+// parse_asset parses the core asset, and checks the collection account against
+// the update authority of the asset. The collection account should be the core
+// program id if the asset doesn't belong to a collection. The royalties of the
+// asset take precedence over the royalties of the collection.
+pub fn parse_asset(asset: &AccountInfo, collection: &AccountInfo) -> Result<MplCoreAsset> {
+    if *asset.owner != MPL_CORE_PROGRAM_ID {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+    let asset_data = asset.data.borrow();
+    let (base, base_len) = deserialize_prefix::<BaseAssetV1>(&asset_data)?;
+    if base.key != KEY_ASSET_V1 {
+        return Err(MMMErrorCode::InvalidMplCoreAsset.into());
+    }
+    let mut royalties = get_royalties(&asset_data, base_len)?;
+
+    match base.update_authority {
+        UpdateAuthority::Collection(collection_key) => {
+            if *collection.key != collection_key {
+                return Err(MMMErrorCode::InvalidMplCoreCollection.into());
+            }
+            if *collection.owner != MPL_CORE_PROGRAM_ID {
+                return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+            }
+            if royalties.is_none() {
+                let collection_data = collection.data.borrow();
+                let (collection_base, collection_base_len) =
+                    deserialize_prefix::<BaseCollectionV1>(&collection_data)?;
+                if collection_base.key != KEY_COLLECTION_V1 {
+                    return Err(MMMErrorCode::InvalidMplCoreCollection.into());
+                }
+                royalties = get_royalties(&collection_data, collection_base_len)?;
+            }
+        }
+        _ => {
+            if *collection.key != MPL_CORE_PROGRAM_ID {
+                return Err(MMMErrorCode::InvalidMplCoreCollection.into());
+            }
+        }
+    }
+
+    Ok(MplCoreAsset { base, royalties })
+}
+
+// transfer the core asset to the new_owner, the optional accounts that are
+// not used are replaced by the core program id, as mpl-core expects.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer<'info>(
+    asset: AccountInfo<'info>,
+    collection: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    new_owner: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    asset_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let accounts = vec![
+        AccountMeta::new(*asset.key, false),
+        AccountMeta::new_readonly(*collection.key, false),
+        AccountMeta::new(*payer.key, true),
+        AccountMeta::new_readonly(*authority.key, true),
+        AccountMeta::new_readonly(*new_owner.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+        AccountMeta::new_readonly(MPL_CORE_PROGRAM_ID, false),
+    ];
+    // TransferV1Args { compression_proof: None }
+    let data = vec![TRANSFER_V1_DISCRIMINATOR, 0];
+
+    invoke_signed(
+        &Instruction {
+            program_id: MPL_CORE_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &[
+            asset,
+            collection,
+            payer,
+            authority,
+            new_owner,
+            system_program,
+            asset_program,
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,311 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke_signed, pubkey},
+};
+use std::io::Write;
+
+pub const OCP_PROGRAM_ID: Pubkey = pubkey!("ocp4vWUzA2z2XMYJ3QhM9vWdyoyoQwAFJhRdVTbvo9E");
+
+// sha256("account:<name>")[..8] and sha256("global:<name>")[..8] of the ocp program
+const POLICY_DISCRIMINATOR: [u8; 8] = [222, 135, 7, 163, 235, 177, 33, 68];
+const MINT_STATE_DISCRIMINATOR: [u8; 8] = [81, 17, 143, 120, 23, 57, 22, 117];
+const INIT_ACCOUNT_DISCRIMINATOR: [u8; 8] = [169, 188, 158, 199, 9, 151, 101, 125];
+const TRANSFER_DISCRIMINATOR: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+const CLOSE_DISCRIMINATOR: [u8; 8] = [98, 165, 201, 177, 108, 65, 206, 96];
+
+// The following account layouts are copied from open_creator_protocol::state,
+// and they are only partially deserialized, i.e. the trailing fields that the
+// pool doesn't need (e.g. the json_rule of the policy) are skipped.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum DynamicRoyaltyKind {
+    PriceLinear,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DynamicRoyaltyPriceLinear {
+    pub price_mint: Option<Pubkey>,
+    pub start_price: u64,
+    pub end_price: u64,
+    pub start_multiplier_bp: u16,
+    pub end_multiplier_bp: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DynamicRoyalty {
+    pub version: u8,
+    pub kind: DynamicRoyaltyKind,
+    pub override_royalty_bp: Option<u16>,
+    pub price_linear: Option<DynamicRoyaltyPriceLinear>,
+}
+
+impl DynamicRoyalty {
+    // get_royalty_bp follows the ocp dynamic royalty, where the royalty multiplier
+    // moves linearly from start_multiplier_bp at start_price to end_multiplier_bp
+    // at end_price, and it's capped at both ends.
+    pub fn get_royalty_bp(&self, total_price: u64, seller_fee_basis_points: u16) -> u16 {
+        let royalty_bp = self.override_royalty_bp.unwrap_or(seller_fee_basis_points) as u128;
+        let multiplier_bp = match self.kind {
+            DynamicRoyaltyKind::PriceLinear => match &self.price_linear {
+                Some(pl) => pl.get_multiplier_bp(total_price),
+                None => 10000,
+            },
+        };
+        (royalty_bp * multiplier_bp / 10000) as u16
+    }
+}
+
+impl DynamicRoyaltyPriceLinear {
+    fn get_multiplier_bp(&self, total_price: u64) -> u128 {
+        if total_price <= self.start_price || self.end_price <= self.start_price {
+            return self.start_multiplier_bp as u128;
+        }
+        if total_price >= self.end_price {
+            return self.end_multiplier_bp as u128;
+        }
+
+        let start = self.start_multiplier_bp as i128;
+        let end = self.end_multiplier_bp as i128;
+        let multiplier_bp = start
+            + (end - start) * (total_price - self.start_price) as i128
+                / (self.end_price - self.start_price) as i128;
+        multiplier_bp as u128
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Policy {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub uuid: Pubkey,
+    pub authority: Pubkey,
+    pub dynamic_royalty: Option<DynamicRoyalty>,
+}
+
+impl Policy {
+    pub fn get_royalty_bp(&self, total_price: u64, seller_fee_basis_points: u16) -> u16 {
+        match &self.dynamic_royalty {
+            Some(dynamic_royalty) => {
+                dynamic_royalty.get_royalty_bp(total_price, seller_fee_basis_points)
+            }
+            None => seller_fee_basis_points,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintState {
+    pub version: u8,
+    pub bump: [u8; 1],
+    pub mint: Pubkey,
+    pub policy: Pubkey,
+}
+
+// The ocp accounts are read only for the pool, so they only need to be deserialized
+// with the discriminator checks, and they are never written back by anchor because
+// the owner is the ocp program.
+macro_rules! impl_ocp_account {
+    ($name:ident, $discriminator:expr) => {
+        impl Owner for $name {
+            fn owner() -> Pubkey {
+                OCP_PROGRAM_ID
+            }
+        }
+
+        impl AccountSerialize for $name {
+            fn try_serialize<W: Write>(&self, _writer: &mut W) -> Result<()> {
+                Err(ErrorCode::AccountDidNotSerialize.into())
+            }
+        }
+
+        impl AccountDeserialize for $name {
+            fn try_deserialize(buf: &mut &[u8]) -> Result<Self> {
+                if buf.len() < 8 || buf[..8] != $discriminator {
+                    return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+                }
+                Self::try_deserialize_unchecked(buf)
+            }
+
+            fn try_deserialize_unchecked(buf: &mut &[u8]) -> Result<Self> {
+                let mut data: &[u8] = &buf[8..];
+                AnchorDeserialize::deserialize(&mut data)
+                    .map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+            }
+        }
+    };
+}
+
+impl_ocp_account!(Policy, POLICY_DISCRIMINATOR);
+impl_ocp_account!(MintState, MINT_STATE_DISCRIMINATOR);
+
+// OcpAccounts are the accounts that every ocp instruction needs besides the
+// token accounts involved.
+pub struct OcpAccounts<'info> {
+    pub ocp_program: AccountInfo<'info>,
+    pub policy: AccountInfo<'info>,
+    pub freeze_authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub mint_state: AccountInfo<'info>,
+    pub cmt_program: AccountInfo<'info>,
+    pub instructions: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+impl<'info> OcpAccounts<'info> {
+    fn invoke(
+        &self,
+        data: [u8; 8],
+        accounts: Vec<AccountMeta>,
+        account_infos: &[AccountInfo<'info>],
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        invoke_signed(
+            &Instruction {
+                program_id: OCP_PROGRAM_ID,
+                accounts,
+                data: data.to_vec(),
+            },
+            account_infos,
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+
+    // init_account creates the associated token account of the owner, which is
+    // frozen by the ocp freeze authority right after the creation.
+    pub fn init_account(
+        &self,
+        owner: AccountInfo<'info>,
+        token_account: AccountInfo<'info>,
+        payer: AccountInfo<'info>,
+        associated_token_program: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let accounts = vec![
+            AccountMeta::new_readonly(*self.policy.key, false),
+            AccountMeta::new_readonly(*self.freeze_authority.key, false),
+            AccountMeta::new_readonly(*self.mint.key, false),
+            AccountMeta::new_readonly(*self.metadata.key, false),
+            AccountMeta::new_readonly(*self.mint_state.key, false),
+            AccountMeta::new_readonly(*owner.key, false),
+            AccountMeta::new(*token_account.key, false),
+            AccountMeta::new_readonly(*self.cmt_program.key, false),
+            AccountMeta::new_readonly(*self.instructions.key, false),
+            AccountMeta::new(*payer.key, true),
+            AccountMeta::new_readonly(*associated_token_program.key, false),
+            AccountMeta::new_readonly(*self.token_program.key, false),
+            AccountMeta::new_readonly(*system_program.key, false),
+        ];
+        self.invoke(
+            INIT_ACCOUNT_DISCRIMINATOR,
+            accounts,
+            &[
+                self.ocp_program.clone(),
+                self.policy.clone(),
+                self.freeze_authority.clone(),
+                self.mint.clone(),
+                self.metadata.clone(),
+                self.mint_state.clone(),
+                owner,
+                token_account,
+                self.cmt_program.clone(),
+                self.instructions.clone(),
+                payer,
+                associated_token_program,
+                self.token_program.clone(),
+                system_program,
+            ],
+            signer_seeds,
+        )
+    }
+
+    // transfer moves the asset from the token account of from to the token account
+    // of to, and ocp enforces the policy checks in the cpi.
+    pub fn transfer(
+        &self,
+        from: AccountInfo<'info>,
+        from_account: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        to_account: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let accounts = vec![
+            AccountMeta::new_readonly(*self.policy.key, false),
+            AccountMeta::new_readonly(*self.freeze_authority.key, false),
+            AccountMeta::new_readonly(*self.mint.key, false),
+            AccountMeta::new_readonly(*self.metadata.key, false),
+            AccountMeta::new(*self.mint_state.key, false),
+            AccountMeta::new_readonly(*from.key, true),
+            AccountMeta::new(*from_account.key, false),
+            AccountMeta::new_readonly(*self.cmt_program.key, false),
+            AccountMeta::new_readonly(*self.instructions.key, false),
+            AccountMeta::new_readonly(*self.token_program.key, false),
+            AccountMeta::new_readonly(*to.key, false),
+            AccountMeta::new(*to_account.key, false),
+        ];
+        self.invoke(
+            TRANSFER_DISCRIMINATOR,
+            accounts,
+            &[
+                self.ocp_program.clone(),
+                self.policy.clone(),
+                self.freeze_authority.clone(),
+                self.mint.clone(),
+                self.metadata.clone(),
+                self.mint_state.clone(),
+                from,
+                from_account,
+                self.cmt_program.clone(),
+                self.instructions.clone(),
+                self.token_program.clone(),
+                to,
+                to_account,
+            ],
+            signer_seeds,
+        )
+    }
+
+    // close thaws and closes the empty token account of from, and the rent goes
+    // to the destination.
+    pub fn close(
+        &self,
+        from: AccountInfo<'info>,
+        from_account: AccountInfo<'info>,
+        destination: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let accounts = vec![
+            AccountMeta::new_readonly(*self.policy.key, false),
+            AccountMeta::new_readonly(*self.freeze_authority.key, false),
+            AccountMeta::new_readonly(*self.mint.key, false),
+            AccountMeta::new_readonly(*self.metadata.key, false),
+            AccountMeta::new(*self.mint_state.key, false),
+            AccountMeta::new_readonly(*from.key, true),
+            AccountMeta::new(*from_account.key, false),
+            AccountMeta::new(*destination.key, false),
+            AccountMeta::new_readonly(*self.token_program.key, false),
+            AccountMeta::new_readonly(*self.cmt_program.key, false),
+            AccountMeta::new_readonly(*self.instructions.key, false),
+        ];
+        self.invoke(
+            CLOSE_DISCRIMINATOR,
+            accounts,
+            &[
+                self.ocp_program.clone(),
+                self.policy.clone(),
+                self.freeze_authority.clone(),
+                self.mint.clone(),
+                self.metadata.clone(),
+                self.mint_state.clone(),
+                from,
+                from_account,
+                destination,
+                self.token_program.clone(),
+                self.cmt_program.clone(),
+                self.instructions.clone(),
+            ],
+            signer_seeds,
+        )
+    }
+}
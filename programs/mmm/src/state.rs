@@ -4,12 +4,30 @@ use crate::constants::*;
 
 pub const CURVE_KIND_LINEAR: u8 = 0;
 pub const CURVE_KIND_EXP: u8 = 1;
+pub const CURVE_KIND_REVERSE_LINEAR: u8 = 2;
+pub const CURVE_KIND_REVERSE_EXP: u8 = 3;
+pub const CURVE_KIND_CONSTANT_PRODUCT: u8 = 4;
+pub const CURVE_KIND_PRICE_LADDER: u8 = 5;
+pub const CURVE_KIND_DUTCH_AUCTION: u8 = 6;
 
 pub const ALLOWLIST_KIND_EMPTY: u8 = 0;
 pub const ALLOWLIST_KIND_FVCA: u8 = 1;
 pub const ALLOWLIST_KIND_MINT: u8 = 2;
 pub const ALLOWLIST_KIND_MCC: u8 = 3;
 pub const ALLOWLIST_KIND_METADATA: u8 = 4;
+pub const ALLOWLIST_KIND_MERKLE: u8 = 5;
+pub const ALLOWLIST_KIND_GROUP: u8 = 6;
+pub const ALLOWLIST_KIND_REGISTRY: u8 = 7;
+pub const ALLOWLIST_KIND_URI_PREFIX_HASH: u8 = 8;
+pub const ALLOWLIST_KIND_NAME_PREFIX_HASH: u8 = 9;
+pub const ALLOWLIST_KIND_SYMBOL_HASH: u8 = 10;
+pub const ALLOWLIST_KIND_UPDATE_AUTHORITY: u8 = 11;
+pub const ALLOWLIST_KIND_EDITION: u8 = 12;
+
+// the high bits of the kind are flags that change how the entry is composed
+pub const ALLOWLIST_FLAG_DENY: u8 = 0x80;
+pub const ALLOWLIST_FLAG_REQUIRED: u8 = 0x40;
+pub const ALLOWLIST_FLAGS: u8 = ALLOWLIST_FLAG_DENY | ALLOWLIST_FLAG_REQUIRED;
 
 #[derive(Default, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct Allowlist {
@@ -22,25 +40,131 @@ impl Allowlist {
     // kind == 1: first verified creator address (FVCA)
     // kind == 2: single mint, useful for SFT
     // kind == 3: verified MCC
-    // kind == 4,5,6,... will be supported in the future
+    // kind == 4: metadata uri prefix, passed in as allowlist_aux (deprecated, the pool
+    //            doesn't commit to the prefix, use kind == 8 instead)
+    // kind == 5: merkle root of the mint addresses, proof passed in as remaining accounts
+    // kind == 6: token-2022 group address, checked against the mint's group member extension
+    // kind == 7: allowlist registry address, the registry passed in as allowlist_aux_account
+    // kind == 8: keccak of the metadata uri prefix, the prefix passed in as allowlist_aux
+    // kind == 9: keccak of the metadata name prefix, the prefix passed in as allowlist_aux
+    // kind == 10: keccak of the metadata symbol
+    // kind == 11: metadata update authority
+    // kind == 12: master edition address or its mint, matches the print editions of it
+    // kind == 13,14,... will be supported in the future
+    //
+    // kind | 0x80: deny, the asset is rejected if the entry matches. Only the kinds that
+    //              the taker can't dodge by omitting allowlist_aux can be denied, and the
+    //              merkle root is the root of the ranges between the denied mints
+    // kind | 0x40: required, the entry has to match on top of the other entries
     pub fn valid(&self) -> bool {
-        if self.kind > ALLOWLIST_KIND_METADATA {
+        let kind = self.base_kind();
+        if kind > ALLOWLIST_KIND_EDITION {
+            return false;
+        }
+        if self.is_deny() && self.is_required() {
+            return false;
+        }
+        if self.is_deny()
+            && !matches!(
+                kind,
+                ALLOWLIST_KIND_FVCA
+                    | ALLOWLIST_KIND_MINT
+                    | ALLOWLIST_KIND_MCC
+                    | ALLOWLIST_KIND_MERKLE
+                    | ALLOWLIST_KIND_GROUP
+                    | ALLOWLIST_KIND_SYMBOL_HASH
+                    | ALLOWLIST_KIND_UPDATE_AUTHORITY
+                    | ALLOWLIST_KIND_EDITION
+            )
+        {
+            return false;
+        }
+        if self.is_required()
+            && matches!(
+                kind,
+                ALLOWLIST_KIND_EMPTY | ALLOWLIST_KIND_METADATA | ALLOWLIST_KIND_REGISTRY
+            )
+        {
             return false;
         }
-        if self.kind != 0 {
+        if kind != 0 {
             return self.value.ne(&Pubkey::default());
         }
         true
     }
 
+    pub fn base_kind(&self) -> u8 {
+        self.kind & !ALLOWLIST_FLAGS
+    }
+
+    pub fn is_deny(&self) -> bool {
+        self.kind & ALLOWLIST_FLAG_DENY != 0
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.kind & ALLOWLIST_FLAG_REQUIRED != 0
+    }
+
     pub fn is_empty(&self) -> bool {
         self.kind == ALLOWLIST_KIND_EMPTY
     }
+
+    pub fn is_metadata_commitment(&self) -> bool {
+        matches!(
+            self.base_kind(),
+            ALLOWLIST_KIND_URI_PREFIX_HASH
+                | ALLOWLIST_KIND_NAME_PREFIX_HASH
+                | ALLOWLIST_KIND_SYMBOL_HASH
+                | ALLOWLIST_KIND_UPDATE_AUTHORITY
+        )
+    }
+
+    // the registry entries can't be empty, and they can't be metadata entries which
+    // need the allowlist_aux of the taker, or another registry. The registry entries
+    // are optional for the taker, so they can't be deny or required entries either
+    pub fn valid_registry_entry(&self) -> bool {
+        self.valid()
+            && self.kind & ALLOWLIST_FLAGS == 0
+            && !matches!(
+                self.kind,
+                ALLOWLIST_KIND_EMPTY | ALLOWLIST_KIND_METADATA | ALLOWLIST_KIND_REGISTRY
+            )
+    }
+
+    // the overflow entries are optional for the taker too, so they can't be deny or
+    // required entries, and they can't be registry entries because the registry would
+    // have to be passed in as the allowlist_aux_account in place of the overflow allowlist
+    pub fn valid_overflow_entry(&self) -> bool {
+        self.valid() && self.kind & ALLOWLIST_FLAGS == 0 && self.kind != ALLOWLIST_KIND_REGISTRY
+    }
+}
+
+// a step of the price ladder, the units are priced at the price of the step for
+// amount units. A step with a zero price is empty and ends the ladder, and the last
+// non-empty step lasts forever regardless of its amount.
+#[derive(Default, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PriceLadderStep {
+    pub price: u64,
+    pub amount: u64,
+}
+
+impl PriceLadderStep {
+    pub fn is_empty(&self) -> bool {
+        self.price == 0
+    }
+}
+
+// the ask of the dutch auction curve decays from the spot_price by decay_per_second
+// lamports every second since the auction started, and it never goes below the floor
+#[derive(Default, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct DutchAuction {
+    pub floor: u64,
+    pub decay_per_second: u64,
 }
 
 // seeds = [
 //    POOL_PREFIX.as_bytes(),
-//    owner.key().as_ref(),
+//    pool.seed_owner().as_ref(),
 //    pool.uuid.as_ref(),
 // ]
 #[account]
@@ -68,25 +192,101 @@ pub struct Pool {
     pub lp_fee_earned: u64,
 
     // immutable
-    pub owner: Pubkey,
+    pub owner: Pubkey, // only changed by the two-step ownership transfer
     pub cosigner: Pubkey,
     pub uuid: Pubkey, // randomly generated keypair
     pub payment_mint: Pubkey,
-    pub allowlists: [Allowlist; ALLOWLIST_MAX_LEN],
+    pub allowlists: [Allowlist; ALLOWLIST_MAX_LEN], // only changed by update_allowlists
     pub buyside_payment_amount: u64,
+
+    // ownership transfer: the owner can propose a pending_owner, and the owner
+    // only changes after the pending_owner accepts it. original_owner is the owner
+    // that the pool was created with, and it's set on the first transfer so that
+    // the pool pda, and the escrow accounts derived from it, stay the same.
+    pub original_owner: Pubkey,
+    pub pending_owner: Pubkey,
+
+    // cosigner rotation: the owner and the cosigner can rotate the cosigner right away,
+    // otherwise the owner can only apply the pending rotation after the timelock.
+    pub pending_cosigner: Pubkey,
+    pub pending_no_cosigner: bool,
+    pub cosigner_rotation_requested_at: i64,
+    // no_cosigner: the owner-only instructions (e.g. deposit and withdraw) don't
+    // need the cosigner to sign, the fulfill instructions still do.
+    pub no_cosigner: bool,
+
+    // operator: optional delegate set by the owner, which can only update the
+    // pricing related fields via operator_update_pool, and never moves funds.
+    pub operator: Pubkey,
+
+    // pause switches: the paused side rejects all the fulfill instructions, while
+    // deposit and withdraw keep working.
+    pub buyside_paused: bool,
+    pub sellside_paused: bool,
+
+    // time window: the pool can't be fulfilled before start_time, and each side
+    // expires at its own expiry on top of the pool expiry. 0 means not set.
+    pub start_time: i64,
+    pub buyside_expiry: i64,
+    pub sellside_expiry: i64,
+
+    // version: the layout version of the pool, 0 is the layout before the version
+    // was added. migrate_pool moves the pool to POOL_VERSION.
+    pub version: u8,
+
+    // allowlist price multipliers: the price of the asset is scaled by the multiplier
+    // of the allowlist entry that it matches, 0 means no scaling (i.e. 10000), and
+    // it's at most MAX_ALLOWLIST_PRICE_MULTIPLIER_BP. only changed by update_allowlists
+    pub allowlist_price_multipliers_bp: [u16; ALLOWLIST_MAX_LEN],
+
+    // price ladder: the steps that the price ladder curve walks, and the position is
+    // the net amount of units that the pool has bought since the ladder was set.
+    // set by create_pool and update_pool, and the position by the fulfill instructions
+    pub price_ladder: [PriceLadderStep; PRICE_LADDER_MAX_LEN],
+    pub price_ladder_position: u64,
+
+    // dutch auction: the decay of the sellside ask of the dutch auction curve, and the
+    // auction restarts from the spot_price whenever the spot_price is set.
+    // only changed by update_pool, and started_at whenever the auction restarts
+    pub dutch_auction: DutchAuction,
+    pub dutch_auction_started_at: i64,
+
+    // asymmetric deltas: when set, the curve moves by curve_delta on the buy side of
+    // curve_anchor_price and by sell_curve_delta on the sell side of it. The anchor is
+    // the spot_price whenever the spot_price is set by update_pool or operator_update_pool
+    pub sell_curve_delta: u64,
+    pub curve_anchor_price: u64,
+    pub asymmetric_curve_delta: bool,
 }
 
 impl Pool {
     pub const LEN: usize = 8 +
         8 * 5 + // u64
-        8 + // i64
-        1 +  // u8
+        8 * 5 + // i64
+        2 +  // u8
         2 * 2 +  // u16
-        32 * 5 + // Pubkey
-        2 + // bool
+        32 * 9 + // Pubkey
+        6 + // bool
         32 + // [u8; 32]
         4 + (1 + 32) * ALLOWLIST_MAX_LEN + // Allowlist
-        392; // padding
+        2 * ALLOWLIST_MAX_LEN + // [u16; ALLOWLIST_MAX_LEN]
+        (8 + 8) * PRICE_LADDER_MAX_LEN + // PriceLadderStep
+        8 + // u64
+        8 * 2 + // DutchAuction
+        8 + // i64
+        8 * 2 + // u64
+        1 + // bool
+        70; // padding
+
+    // seed_owner is the owner that the pool pda is derived with, the pools that
+    // have never been transferred have an empty original_owner.
+    pub fn seed_owner(&self) -> Pubkey {
+        if self.original_owner == Pubkey::default() {
+            self.owner
+        } else {
+            self.original_owner
+        }
+    }
 }
 
 // seeds = [
@@ -108,6 +308,10 @@ pub struct SellState {
     pub asset_mint: Pubkey,
     pub asset_amount: u64,
     pub cosigner_annotation: [u8; 32],
+
+    // version: the layout version of the sell state, 0 is the layout before the
+    // version was added. migrate_sell_state moves the sell state to SELL_STATE_VERSION.
+    pub version: u8,
 }
 
 impl SellState {
@@ -115,5 +319,84 @@ impl SellState {
         8 + // u64
         32 * 3 + // Pubkey
         32 + // [u8; 32]
+        1 + // u8
+        199; // padding
+}
+
+// seeds = [
+//     OVERFLOW_ALLOWLIST_PREFIX.as_bytes(),
+//     pool.key().as_ref(),
+// ]
+#[account]
+#[derive(Default)]
+pub struct OverflowAllowlist {
+    // overflow allowlist holds the extra allowlist entries of the pool beyond
+    // ALLOWLIST_MAX_LEN, which are unioned together with the pool allowlists. It's
+    // passed in as the allowlist_aux_account of the instructions that check the
+    // allowlists, and it's only needed when the asset matches one of its entries.
+    pub pool: Pubkey,
+    pub allowlists: Vec<Allowlist>,
+}
+
+impl OverflowAllowlist {
+    pub const LEN: usize = 8 +
+        32 + // Pubkey
+        4 + (1 + 32) * OVERFLOW_ALLOWLIST_MAX_LEN; // Allowlist
+}
+
+// seeds = [
+//     ALLOWLIST_REGISTRY_PREFIX.as_bytes(),
+//     registry.uuid.as_ref(),
+// ]
+#[account]
+#[derive(Default)]
+pub struct AllowlistRegistry {
+    // allowlist registry is a shared collection definition maintained by the curator,
+    // and the pools reference it with an ALLOWLIST_KIND_REGISTRY allowlist entry.
+    // The entries are unioned together with the pool allowlists, so the updates of the
+    // curator apply to every pool that references the registry right away.
+    pub curator: Pubkey,
+    pub uuid: Pubkey, // randomly generated keypair
+    // revision is bumped on every update of the entries, e.g. for the indexers and
+    // the cosigners to tell which definition a fulfill was checked against
+    pub revision: u64,
+    pub version: u8,
+    pub entries: Vec<Allowlist>,
+}
+
+impl AllowlistRegistry {
+    pub const LEN: usize = 8 +
+        32 * 2 + // Pubkey
+        8 + // u64
+        1 + // u8
+        4 + (1 + 32) * ALLOWLIST_REGISTRY_MAX_LEN + // Allowlist
+        64; // padding
+}
+
+// seeds = [
+//     GLOBAL_CONFIG_PREFIX.as_bytes(),
+// ]
+#[account]
+#[derive(Default)]
+pub struct GlobalConfig {
+    // admin is initialized to the upgrade authority of the program
+    pub admin: Pubkey,
+    // paused: the emergency switch that rejects all the fulfill instructions
+    pub paused: bool,
+    // protocol fee: charged to the taker in every fulfill, on the same price as
+    // the taker fee, and paid to the treasury
+    pub protocol_fee_bp: u16,
+    pub treasury: Pubkey,
+    // bounds: they override MAX_LP_FEE_BP and MAX_REFERRAL_FEE_BP in constants.rs,
+    // which are only the initial values
+    pub max_lp_fee_bp: u16,
+    pub max_referral_fee_bp: i16,
+}
+
+impl GlobalConfig {
+    pub const LEN: usize = 8 +
+        32 * 2 + // Pubkey
+        1 + // bool
+        2 * 3 + // u16 and i16
         200; // padding
 }
@@ -0,0 +1,175 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, program_pack::Pack},
+};
+use anchor_spl::token_2022::spl_token_2022;
+use std::convert::TryInto;
+
+use crate::errors::MMMErrorCode;
+
+// The token-2022 crate that we depend on predates the transfer hook, metadata
+// and group extensions, so the mint TLV data is parsed by hand here.
+// The extension type values follow spl_token_2022::extension::ExtensionType.
+const EXTENSION_TYPE_UNINITIALIZED: u16 = 0;
+const EXTENSION_TYPE_TRANSFER_HOOK: u16 = 14;
+const EXTENSION_TYPE_METADATA_POINTER: u16 = 18;
+const EXTENSION_TYPE_TOKEN_METADATA: u16 = 19;
+const EXTENSION_TYPE_GROUP_MEMBER_POINTER: u16 = 22;
+const EXTENSION_TYPE_TOKEN_GROUP_MEMBER: u16 = 23;
+
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const EXTRA_ACCOUNT_METAS_PREFIX: &str = "extra-account-metas";
+
+// TokenMetadata is the embedded metadata of the token metadata extension,
+// which is borsh encoded in the mint TLV data.
+#[derive(AnchorDeserialize, Clone)]
+pub struct TokenMetadata {
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+// get_mint_extension returns the value of the extension_type TLV entry of the mint.
+// The TLV entries start right after the account type byte, which is stored at
+// the end of a padded token account, i.e. spl_token_2022::state::Account::LEN.
+fn get_mint_extension(data: &[u8], extension_type: u16) -> Option<&[u8]> {
+    let account_type_offset = spl_token_2022::state::Account::LEN;
+    if data.len() <= account_type_offset || data[account_type_offset] != ACCOUNT_TYPE_MINT {
+        return None;
+    }
+
+    let mut cursor = account_type_offset + 1;
+    while cursor + 4 <= data.len() {
+        let entry_type = u16::from_le_bytes([data[cursor], data[cursor + 1]]);
+        let entry_len = u16::from_le_bytes([data[cursor + 2], data[cursor + 3]]) as usize;
+        if entry_type == EXTENSION_TYPE_UNINITIALIZED {
+            return None;
+        }
+        let start = cursor + 4;
+        let end = start.checked_add(entry_len)?;
+        if end > data.len() {
+            return None;
+        }
+        if entry_type == extension_type {
+            return Some(&data[start..end]);
+        }
+        cursor = end;
+    }
+    None
+}
+
+// get_pointer_address parses the (authority, address) pair of the pointer extensions,
+// where an all-zero address means that the pointer is not set.
+fn get_pointer_address(data: &[u8], extension_type: u16) -> Option<Pubkey> {
+    let value = get_mint_extension(data, extension_type)?;
+    if value.len() != 64 {
+        return None;
+    }
+    let address = Pubkey::new_from_array(value[32..64].try_into().ok()?);
+    if address == Pubkey::default() {
+        return None;
+    }
+    Some(address)
+}
+
+pub fn get_transfer_hook_program_id(asset_mint: &AccountInfo) -> Option<Pubkey> {
+    get_pointer_address(&asset_mint.data.borrow(), EXTENSION_TYPE_TRANSFER_HOOK)
+}
+
+// get_token_metadata returns the metadata embedded in the mint. Only the metadata
+// that the metadata pointer points back to the mint itself is trusted.
+pub fn get_token_metadata(asset_mint: &AccountInfo) -> Result<Option<TokenMetadata>> {
+    let data = asset_mint.data.borrow();
+    if get_pointer_address(&data, EXTENSION_TYPE_METADATA_POINTER) != Some(*asset_mint.key) {
+        return Ok(None);
+    }
+    let value = match get_mint_extension(&data, EXTENSION_TYPE_TOKEN_METADATA) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    let metadata = TokenMetadata::deserialize(&mut &value[..])
+        .map_err(|_| MMMErrorCode::InvalidTokenExtension)?;
+    if metadata.mint != *asset_mint.key {
+        return Err(MMMErrorCode::InvalidTokenExtension.into());
+    }
+    Ok(Some(metadata))
+}
+
+// get_token_group returns the group address of the group member extension embedded
+// in the mint. Only the member that the group member pointer points back to the
+// mint itself is trusted.
+pub fn get_token_group(asset_mint: &AccountInfo) -> Result<Option<Pubkey>> {
+    let data = asset_mint.data.borrow();
+    if get_pointer_address(&data, EXTENSION_TYPE_GROUP_MEMBER_POINTER) != Some(*asset_mint.key)
+    {
+        return Ok(None);
+    }
+    let value = match get_mint_extension(&data, EXTENSION_TYPE_TOKEN_GROUP_MEMBER) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    // mint (32) || group (32) || member_number (8)
+    if value.len() != 72 || value[0..32] != asset_mint.key.to_bytes() {
+        return Err(MMMErrorCode::InvalidTokenExtension.into());
+    }
+    Ok(Some(Pubkey::new_from_array(
+        value[32..64]
+            .try_into()
+            .map_err(|_| MMMErrorCode::InvalidTokenExtension)?,
+    )))
+}
+
+// transfer_checked transfers the token-2022 asset. If the mint has a transfer hook,
+// the hook_accounts need to include the extra account metas that the hook requires,
+// the hook program and its extra account metas validation account, so that the
+// token program can invoke the hook.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    asset_mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if let Some(hook_program_id) = get_transfer_hook_program_id(&asset_mint) {
+        let (validation_address, _) = Pubkey::find_program_address(
+            &[EXTRA_ACCOUNT_METAS_PREFIX.as_bytes(), asset_mint.key.as_ref()],
+            &hook_program_id,
+        );
+        if !hook_accounts.iter().any(|a| *a.key == hook_program_id)
+            || !hook_accounts.iter().any(|a| *a.key == validation_address)
+        {
+            return Err(MMMErrorCode::InvalidTransferHookAccounts.into());
+        }
+    }
+
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        from.key,
+        asset_mint.key,
+        to.key,
+        authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    ix.accounts.extend(hook_accounts.iter().map(|a| {
+        if a.is_writable {
+            AccountMeta::new(*a.key, false)
+        } else {
+            AccountMeta::new_readonly(*a.key, false)
+        }
+    }));
+
+    let mut account_infos = vec![from, asset_mint, to, authority];
+    account_infos.extend_from_slice(hook_accounts);
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+    Ok(())
+}
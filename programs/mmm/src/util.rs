@@ -1,14 +1,22 @@
 use crate::{
-    constants::{MAX_METADATA_CREATOR_ROYALTY_BP, MAX_REFERRAL_FEE_BP, MAX_TOTAL_PRICE},
+    ata::init_if_needed_ata,
+    constants::{MAX_METADATA_CREATOR_ROYALTY_BP, MAX_TOTAL_PRICE, PRICE_LADDER_MAX_LEN},
     errors::MMMErrorCode,
+    mpl_core::MplCoreAsset,
+    ocp::Policy,
     state::*,
+    token_ext::{get_token_group, get_token_metadata},
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{keccak, log::sol_log_data},
+    Discriminator,
 };
-use anchor_lang::{prelude::*, solana_program::log::sol_log_data};
 use anchor_spl::token::Mint;
 use mpl_token_metadata::{
     id as token_metadata_program_key,
     pda::{find_master_edition_account, find_metadata_account},
-    state::{Metadata, TokenMetadataAccount, TokenStandard},
+    state::{Data, Edition, Metadata, TokenMetadataAccount, TokenStandard},
 };
 use std::convert::TryFrom;
 
@@ -18,6 +26,11 @@ fn check_master_edition(master_edition_account_info: &AccountInfo) -> bool {
     version == 2 || version == 6
 }
 
+// the edition allowlist value is either the master edition, or the mint of it
+fn check_edition_parent(allowlist: &Allowlist, parent: &Pubkey) -> bool {
+    allowlist.value == *parent || find_master_edition_account(&allowlist.value).0 == *parent
+}
+
 pub fn check_allowlists(allowlists: &[Allowlist]) -> Result<()> {
     for allowlist in allowlists.iter() {
         if !allowlist.valid() {
@@ -26,22 +39,237 @@ pub fn check_allowlists(allowlists: &[Allowlist]) -> Result<()> {
         }
     }
 
+    // the merkle entries share the proof in the remaining accounts, so a deny or
+    // required merkle entry has to be the only merkle entry
+    let mut merkle_entries = allowlists
+        .iter()
+        .filter(|allowlist| allowlist.base_kind() == ALLOWLIST_KIND_MERKLE);
+    if merkle_entries.clone().count() > 1
+        && merkle_entries.any(|allowlist| allowlist.is_deny() || allowlist.is_required())
+    {
+        msg!("InvalidAllowLists: merkle entries can't be composed");
+        return Err(MMMErrorCode::InvalidAllowLists.into());
+    }
+
+    // the registry entries are priced by the multiplier of the registry, so a pool
+    // references at most one registry, otherwise the taker could pick the price tier
+    // of an asset that two registries list by the registry that it passes in
+    if allowlists
+        .iter()
+        .filter(|allowlist| allowlist.kind == ALLOWLIST_KIND_REGISTRY)
+        .count()
+        > 1
+    {
+        msg!("InvalidAllowLists: more than one registry");
+        return Err(MMMErrorCode::InvalidAllowLists.into());
+    }
+
     Ok(())
 }
 
+// check_allowlist_matches composes the per entry matches of the allowlists
+//   1. skip if the allowlist.is_empty()
+//   2. the asset is rejected if any deny entry matches
+//   3. every required entry has to match, i.e. the required entries are intersected
+//   4. one of the other entries has to match, i.e. they are unioned together, unless
+//      the allowlists only have required entries besides the deny entries
+// the first matched entry of the union is returned, which prices the asset
+fn check_allowlist_matches<F>(allowlists: &[Allowlist], matches: F) -> Result<Option<Allowlist>>
+where
+    F: Fn(&Allowlist) -> Result<bool>,
+{
+    let mut has_required = false;
+    let mut has_union = false;
+    let mut union_matched = None;
+    for allowlist in allowlists.iter().filter(|allowlist| !allowlist.is_empty()) {
+        let matched = matches(allowlist)?;
+        if allowlist.is_deny() {
+            if matched {
+                msg!(
+                    "InvalidAllowLists: denied by kind {}",
+                    allowlist.base_kind()
+                );
+                return Err(MMMErrorCode::InvalidAllowLists.into());
+            }
+        } else if allowlist.is_required() {
+            if !matched {
+                msg!("InvalidAllowLists: required kind {}", allowlist.base_kind());
+                return Err(MMMErrorCode::InvalidAllowLists.into());
+            }
+            has_required = true;
+        } else {
+            has_union = true;
+            if matched && union_matched.is_none() {
+                union_matched = Some(*allowlist);
+            }
+        }
+    }
+
+    if union_matched.is_some() || (has_required && !has_union) {
+        return Ok(union_matched);
+    }
+    // at the end, we didn't find a match, thus return err
+    Err(MMMErrorCode::InvalidAllowLists.into())
+}
+
+// get_allowlists returns the allowlists of the pool, extended with the entries of
+// the allowlist_aux_account if it's one of the following, and any other
+// allowlist_aux_account is ignored here.
+//   - the overflow allowlist of the pool, unless the pool references a registry
+//   - the allowlist registry that the pool references with the registry kind
+// So the aux entries of a pool only come from one account, and the taker can't pick
+// the price tier of an asset by the allowlist_aux_account that it passes in.
+pub fn get_allowlists(
+    pool: &Account<Pool>,
+    allowlist_aux_account: &AccountInfo,
+) -> Result<Vec<Allowlist>> {
+    let mut allowlists = pool.allowlists.to_vec();
+    if *allowlist_aux_account.owner != crate::ID || allowlist_aux_account.data_len() < 8 {
+        return Ok(allowlists);
+    }
+
+    let data = allowlist_aux_account.try_borrow_data()?;
+    if data[..8] == OverflowAllowlist::DISCRIMINATOR {
+        let overflow_allowlist = OverflowAllowlist::try_deserialize(&mut &data[..])?;
+        if overflow_allowlist.pool != pool.key() {
+            msg!("InvalidAllowLists: overflow allowlist of another pool");
+            return Err(MMMErrorCode::InvalidAllowLists.into());
+        }
+        if allowlists
+            .iter()
+            .any(|allowlist| allowlist.kind == ALLOWLIST_KIND_REGISTRY)
+        {
+            msg!("InvalidAllowLists: overflow allowlist of a pool with a registry");
+            return Err(MMMErrorCode::InvalidAllowLists.into());
+        }
+        allowlists.extend(overflow_allowlist.allowlists);
+    } else if data[..8] == AllowlistRegistry::DISCRIMINATOR
+        && allowlists.iter().any(|allowlist| {
+            allowlist.kind == ALLOWLIST_KIND_REGISTRY
+                && allowlist.value == *allowlist_aux_account.key
+        })
+    {
+        let registry = AllowlistRegistry::try_deserialize(&mut &data[..])?;
+        allowlists.extend(registry.entries);
+    }
+    Ok(allowlists)
+}
+
+// MetadataFields are the metadata fields of the asset that the committed metadata
+// allowlists match, the update_authority is None if it can't be verified.
+pub struct MetadataFields<'a> {
+    pub name: &'a str,
+    pub symbol: &'a str,
+    pub uri: &'a str,
+    pub update_authority: Option<Pubkey>,
+}
+
+// check_metadata_commitment matches the committed metadata allowlist against the
+// asset. The prefixes are not stored in the pool, so the taker passes the prefix
+// in as allowlist_aux, and it has to hash to the committed value.
+fn check_metadata_commitment(
+    allowlist: &Allowlist,
+    fields: &MetadataFields,
+    allowlist_aux: &Option<String>,
+) -> bool {
+    let committed = |preimage: &str| keccak::hash(preimage.as_bytes()).to_bytes();
+    let value = allowlist.value.to_bytes();
+    match allowlist.base_kind() {
+        ALLOWLIST_KIND_URI_PREFIX_HASH => allowlist_aux.as_ref().is_some_and(|prefix| {
+            committed(prefix) == value && fields.uri.trim().starts_with(prefix.as_str())
+        }),
+        ALLOWLIST_KIND_NAME_PREFIX_HASH => allowlist_aux.as_ref().is_some_and(|prefix| {
+            committed(prefix) == value && fields.name.starts_with(prefix.as_str())
+        }),
+        ALLOWLIST_KIND_SYMBOL_HASH => {
+            committed(fields.symbol.trim_end_matches(char::from(0))) == value
+        }
+        ALLOWLIST_KIND_UPDATE_AUTHORITY => fields.update_authority == Some(allowlist.value),
+        _ => false,
+    }
+}
+
+// merkle leaves and nodes are domain separated to prevent second preimage attacks,
+// and the nodes are hashed as sorted pairs so that the proof doesn't need the positions
+//   leaf = keccak(0x00 || mint)
+//   node = keccak(0x01 || min(a, b) || max(a, b))
+pub fn get_merkle_leaf(mint: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[&[0x00], mint.as_ref()]).to_bytes()
+}
+
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&[0x01], &node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[&[0x01], sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+// the merkle denylist commits to the ranges between the sorted denied mints, with
+// Pubkey::default() and [0xff; 32] as the sentinels at both ends, so that the proof
+// of the range that strictly contains the mint shows that the mint is not denied
+//   range leaf = keccak(0x02 || lo || hi)
+// the proof is passed in as [lo, hi, nodes...]
+pub fn get_merkle_range_leaf(lo: &[u8; 32], hi: &[u8; 32]) -> [u8; 32] {
+    keccak::hashv(&[&[0x02], lo, hi]).to_bytes()
+}
+
+pub fn verify_merkle_non_membership(proof: &[[u8; 32]], root: &[u8; 32], mint: &Pubkey) -> bool {
+    if proof.len() < 2 {
+        return false;
+    }
+    let (lo, hi) = (&proof[0], &proof[1]);
+    let key = mint.to_bytes();
+    *lo < key && key < *hi && verify_merkle_proof(&proof[2..], root, get_merkle_range_leaf(lo, hi))
+}
+
+// the merkle proof nodes are passed in as remaining accounts, right after the
+// creator accounts (accounts_per_creator accounts per metadata creator) that are
+// used to pay the royalties. The creator accounts are required whenever the pool
+// has a merkle allowlist, even if no royalty is going to be paid.
+fn get_merkle_proof(
+    remaining_accounts: &[AccountInfo],
+    parsed_metadata: &Metadata,
+    accounts_per_creator: usize,
+) -> Vec<[u8; 32]> {
+    let creators_len = parsed_metadata
+        .data
+        .creators
+        .as_ref()
+        .map_or(0, |creators| creators.len());
+    remaining_accounts
+        .iter()
+        .skip(creators_len * accounts_per_creator)
+        .map(|account| account.key.to_bytes())
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn check_allowlists_for_mint(
     allowlists: &[Allowlist],
     mint: &Account<Mint>,
     metadata: &AccountInfo,
     master_edition: Option<&AccountInfo>,
     allowlist_aux: Option<String>,
-) -> Result<Metadata> {
+    remaining_accounts: &[AccountInfo],
+    accounts_per_creator: usize,
+) -> Result<(Metadata, Option<Allowlist>)> {
     // We need to check the following validation rules
     // 1. make sure the metadata is correctly derived from the metadata pda with the mint
     // 2. make sure mint+metadata(e.g. first verified creator address) can match one of the allowlist
-    // 3. note that the allowlist is unioned together, not intersection
+    // 3. note that the allowlist is unioned together, except for the deny and the
+    //    required entries, see check_allowlist_matches
     // 4. skip if the allowlist.is_empty()
     // 5. verify that nft either does not have master edition or is master edition
+    // 6. for the merkle allowlist, verify the proof of the mint against the root, and
+    //    for the merkle denylist, verify the proof of the range around the mint
+    // 7. the overflow allowlist and the registry entries are already extended into
+    //    the allowlists by get_allowlists, from the allowlist_aux_account
+    // 8. print editions are only accepted if one of the edition allowlists matches
+    //    the parent of the edition
 
     if *metadata.owner != token_metadata_program_key() {
         return Err(ErrorCode::AccountOwnedByWrongProgram.into());
@@ -50,6 +278,7 @@ pub fn check_allowlists_for_mint(
         return Err(ErrorCode::ConstraintSeeds.into());
     }
     let parsed_metadata = Metadata::from_account_info(metadata)?;
+    let mut edition_parent = None;
     if let Some(master_edition) = master_edition {
         if find_master_edition_account(&mint.key()).0 != master_edition.key() {
             return Err(ErrorCode::ConstraintSeeds.into());
@@ -59,11 +288,47 @@ pub fn check_allowlists_for_mint(
                 return Err(ErrorCode::AccountOwnedByWrongProgram.into());
             }
             if !check_master_edition(master_edition) {
-                return Err(MMMErrorCode::InvalidMasterEdition.into());
+                let edition = Edition::from_account_info(master_edition)
+                    .map_err(|_| MMMErrorCode::InvalidMasterEdition)?;
+                edition_parent = Some(edition.parent);
             }
         }
     }
 
+    let merkle_proof = get_merkle_proof(remaining_accounts, &parsed_metadata, accounts_per_creator);
+    let allowlist_match = check_allowlists_for_metadata(
+        allowlists,
+        &mint.key(),
+        &parsed_metadata,
+        allowlist_aux,
+        &merkle_proof,
+        edition_parent.as_ref(),
+    )?;
+    if let Some(ref parent) = edition_parent {
+        if !allowlists.iter().any(|allowlist| {
+            allowlist.base_kind() == ALLOWLIST_KIND_EDITION
+                && !allowlist.is_deny()
+                && check_edition_parent(allowlist, parent)
+        }) {
+            return Err(MMMErrorCode::InvalidMasterEdition.into());
+        }
+    }
+
+    Ok((parsed_metadata, allowlist_match))
+}
+
+// check_allowlists_for_metadata matches the already verified metadata of the asset
+// against the allowlists, the asset_key is the mint for the token based assets,
+// or the asset id for the assets without a mint (e.g. compressed nfts). The
+// edition_parent is the master edition of the asset if it's a print edition.
+pub fn check_allowlists_for_metadata(
+    allowlists: &[Allowlist],
+    asset_key: &Pubkey,
+    parsed_metadata: &Metadata,
+    allowlist_aux: Option<String>,
+    merkle_proof: &[[u8; 32]],
+    edition_parent: Option<&Pubkey>,
+) -> Result<Option<Allowlist>> {
     if allowlists
         .iter()
         .any(|&val| val.kind == ALLOWLIST_KIND_METADATA)
@@ -82,58 +347,278 @@ pub fn check_allowlists_for_mint(
         }
     }
 
-    for allowlist_val in allowlists.iter() {
-        match allowlist_val.kind {
-            ALLOWLIST_KIND_EMPTY => {}
-            ALLOWLIST_KIND_FVCA => {
-                if let Some(ref creators) = parsed_metadata.data.creators {
-                    // TODO: can we make sure we only take master_edition here?
-                    if !creators.is_empty()
+    check_allowlist_matches(allowlists, |allowlist_val| {
+        Ok(match allowlist_val.base_kind() {
+            ALLOWLIST_KIND_FVCA => match parsed_metadata.data.creators {
+                // TODO: can we make sure we only take master_edition here?
+                Some(ref creators) => {
+                    !creators.is_empty()
                         && creators[0].address == allowlist_val.value
                         && creators[0].verified
-                    {
-                        return Ok(parsed_metadata);
-                    }
                 }
-            }
-            ALLOWLIST_KIND_MINT => {
-                if mint.key() == allowlist_val.value {
-                    return Ok(parsed_metadata);
+                None => false,
+            },
+            ALLOWLIST_KIND_MINT => *asset_key == allowlist_val.value,
+            ALLOWLIST_KIND_MCC => match parsed_metadata.collection {
+                Some(ref collection_data) => {
+                    collection_data.key == allowlist_val.value && collection_data.verified
                 }
+                None => false,
+            },
+            // Do not validate URI here, as we already did it above.
+            // These checks are separate since allowlist values are unioned together.
+            ALLOWLIST_KIND_METADATA => false,
+            // a deny merkle entry matches unless the mint is proven to be outside of it
+            ALLOWLIST_KIND_MERKLE if allowlist_val.is_deny() => !verify_merkle_non_membership(
+                merkle_proof,
+                &allowlist_val.value.to_bytes(),
+                asset_key,
+            ),
+            ALLOWLIST_KIND_MERKLE => verify_merkle_proof(
+                merkle_proof,
+                &allowlist_val.value.to_bytes(),
+                get_merkle_leaf(asset_key),
+            ),
+            // token-2022 groups are only checked in check_allowlists_for_ext_mint
+            ALLOWLIST_KIND_GROUP => false,
+            ALLOWLIST_KIND_EDITION => {
+                edition_parent.is_some_and(|parent| check_edition_parent(allowlist_val, parent))
             }
-            ALLOWLIST_KIND_MCC => {
-                if let Some(ref collection_data) = parsed_metadata.collection {
-                    if collection_data.key == allowlist_val.value && collection_data.verified {
-                        return Ok(parsed_metadata);
-                    }
-                }
+            // the registry entries are already extended in get_allowlists
+            ALLOWLIST_KIND_REGISTRY => false,
+            ALLOWLIST_KIND_URI_PREFIX_HASH
+            | ALLOWLIST_KIND_NAME_PREFIX_HASH
+            | ALLOWLIST_KIND_SYMBOL_HASH
+            | ALLOWLIST_KIND_UPDATE_AUTHORITY => {
+                let fields = MetadataFields {
+                    name: &parsed_metadata.data.name,
+                    symbol: &parsed_metadata.data.symbol,
+                    uri: &parsed_metadata.data.uri,
+                    update_authority: Some(parsed_metadata.update_authority)
+                        .filter(|update_authority| *update_authority != Pubkey::default()),
+                };
+                check_metadata_commitment(allowlist_val, &fields, &allowlist_aux)
             }
-            ALLOWLIST_KIND_METADATA => {
-                // Do not validate URI here, as we already did it above.
-                // These checks are separate since allowlist values are unioned together.
-                continue;
+            _ => {
+                return Err(MMMErrorCode::InvalidAllowLists.into());
+            }
+        })
+    })
+}
+
+// check_allowlists_for_cnft checks the compressed nft against the allowlists, with the
+// metadata args that the leaf hash has verified. The leaves don't carry an update
+// authority, and the merkle proof nodes would compete with the leaf proof for the
+// remaining accounts, so the pools with merkle or update authority entries (e.g.
+// extended from the allowlist_aux_account) can't trade compressed nfts at all.
+pub fn check_allowlists_for_cnft(
+    allowlists: &[Allowlist],
+    asset_id: &Pubkey,
+    parsed_metadata: &Metadata,
+    allowlist_aux: Option<String>,
+) -> Result<Option<Allowlist>> {
+    if allowlists.iter().any(|allowlist| {
+        matches!(
+            allowlist.base_kind(),
+            ALLOWLIST_KIND_MERKLE | ALLOWLIST_KIND_UPDATE_AUTHORITY
+        )
+    }) {
+        return Err(MMMErrorCode::InvalidAllowLists.into());
+    }
+    check_allowlists_for_metadata(
+        allowlists,
+        asset_id,
+        parsed_metadata,
+        allowlist_aux,
+        &[],
+        None,
+    )
+}
+
+// check_allowlists_for_ext_mint checks the token-2022 mint against the allowlists,
+// using the group member and the token metadata extensions embedded in the mint.
+// FVCA, MCC, merkle and edition entries never match token-2022 mints, except that a deny
+// merkle entry rejects every token-2022 mint as there is no proof to check.
+// The update authority of the token metadata is set by the mint creator without a
+// signature of that authority, so update authority entries are handled like the merkle
+// ones. The group entries match the signed group membership instead.
+pub fn check_allowlists_for_ext_mint(
+    allowlists: &[Allowlist],
+    asset_mint: &AccountInfo,
+    allowlist_aux: Option<String>,
+) -> Result<Option<Allowlist>> {
+    if allowlists
+        .iter()
+        .any(|&val| val.kind == ALLOWLIST_KIND_METADATA)
+    {
+        // If allowlist_aux is not passed in, do not validate URI.
+        if let Some(ref aux_key) = allowlist_aux {
+            let token_metadata =
+                get_token_metadata(asset_mint)?.ok_or(MMMErrorCode::InvalidTokenExtension)?;
+            if !token_metadata.uri.trim().starts_with(aux_key) {
+                msg!(
+                    "Failed metadata validation. Expected URI: |{}| but got |{}|",
+                    *aux_key,
+                    token_metadata.uri
+                );
+                return Err(MMMErrorCode::UnexpectedMetadataUri.into());
             }
+        }
+    }
+
+    let token_group = get_token_group(asset_mint)?;
+    let token_metadata = if allowlists.iter().any(|val| val.is_metadata_commitment()) {
+        get_token_metadata(asset_mint)?
+    } else {
+        None
+    };
+    check_allowlist_matches(allowlists, |allowlist_val| {
+        Ok(match allowlist_val.base_kind() {
+            ALLOWLIST_KIND_FVCA
+            | ALLOWLIST_KIND_MCC
+            | ALLOWLIST_KIND_REGISTRY
+            | ALLOWLIST_KIND_EDITION => false,
+            ALLOWLIST_KIND_MERKLE | ALLOWLIST_KIND_UPDATE_AUTHORITY => allowlist_val.is_deny(),
+            ALLOWLIST_KIND_MINT => *asset_mint.key == allowlist_val.value,
+            // Do not validate URI here, as we already did it above.
+            ALLOWLIST_KIND_METADATA => false,
+            ALLOWLIST_KIND_GROUP => token_group == Some(allowlist_val.value),
+            ALLOWLIST_KIND_URI_PREFIX_HASH
+            | ALLOWLIST_KIND_NAME_PREFIX_HASH
+            | ALLOWLIST_KIND_SYMBOL_HASH => match token_metadata {
+                Some(ref token_metadata) => {
+                    let fields = MetadataFields {
+                        name: &token_metadata.name,
+                        symbol: &token_metadata.symbol,
+                        uri: &token_metadata.uri,
+                        update_authority: None,
+                    };
+                    check_metadata_commitment(allowlist_val, &fields, &allowlist_aux)
+                }
+                None => false,
+            },
             _ => {
                 return Err(MMMErrorCode::InvalidAllowLists.into());
             }
+        })
+    })
+}
+
+// check_allowlists_for_mpl_core_asset checks the core asset against the allowlists,
+// the MCC allowlist matches the collection of the asset, which is verified by
+// mpl-core when the asset is added to the collection.
+// FVCA, merkle, group and edition entries never match core assets, except that a deny
+// merkle entry rejects every core asset as there is no proof to check.
+pub fn check_allowlists_for_mpl_core_asset(
+    allowlists: &[Allowlist],
+    asset_key: &Pubkey,
+    asset: &MplCoreAsset,
+    allowlist_aux: Option<String>,
+) -> Result<Option<Allowlist>> {
+    if allowlists
+        .iter()
+        .any(|&val| val.kind == ALLOWLIST_KIND_METADATA)
+    {
+        // If allowlist_aux is not passed in, do not validate URI.
+        if let Some(ref aux_key) = allowlist_aux {
+            if !asset.base.uri.trim().starts_with(aux_key) {
+                msg!(
+                    "Failed metadata validation. Expected URI: |{}| but got |{}|",
+                    *aux_key,
+                    asset.base.uri
+                );
+                return Err(MMMErrorCode::UnexpectedMetadataUri.into());
+            }
         }
     }
 
-    // at the end, we didn't find a match, thus return err
-    Err(MMMErrorCode::InvalidAllowLists.into())
+    check_allowlist_matches(allowlists, |allowlist_val| {
+        Ok(match allowlist_val.base_kind() {
+            ALLOWLIST_KIND_FVCA
+            | ALLOWLIST_KIND_GROUP
+            | ALLOWLIST_KIND_REGISTRY
+            | ALLOWLIST_KIND_EDITION => false,
+            ALLOWLIST_KIND_MERKLE => allowlist_val.is_deny(),
+            ALLOWLIST_KIND_MINT => *asset_key == allowlist_val.value,
+            ALLOWLIST_KIND_MCC => asset.collection() == Some(allowlist_val.value),
+            ALLOWLIST_KIND_URI_PREFIX_HASH
+            | ALLOWLIST_KIND_NAME_PREFIX_HASH
+            | ALLOWLIST_KIND_SYMBOL_HASH
+            | ALLOWLIST_KIND_UPDATE_AUTHORITY => {
+                // core assets don't have a symbol, so only the empty symbol matches
+                let fields = MetadataFields {
+                    name: &asset.base.name,
+                    symbol: "",
+                    uri: &asset.base.uri,
+                    update_authority: asset.update_authority(),
+                };
+                check_metadata_commitment(allowlist_val, &fields, &allowlist_aux)
+            }
+            // Do not validate URI here, as we already did it above.
+            ALLOWLIST_KIND_METADATA => false,
+            _ => {
+                return Err(MMMErrorCode::InvalidAllowLists.into());
+            }
+        })
+    })
+}
+
+// get_allowlist_multiplier_bp returns the price multiplier of the matched allowlist
+// entry. The entries of the pool carry their own multipliers, the registry entries
+// take the multiplier of the registry entry of the pool, and the overflow entries
+// are not scaled. The entries of the pool are matched before the aux entries, and
+// the aux entries only come from one account (see get_allowlists), so an asset that
+// matches several tiers is always priced by the same one.
+pub fn get_allowlist_multiplier_bp(
+    pool: &Pool,
+    allowlist_match: Option<Allowlist>,
+    allowlist_aux_account: &AccountInfo,
+) -> u16 {
+    let Some(allowlist_match) = allowlist_match else {
+        return 10000;
+    };
+    let position = pool
+        .allowlists
+        .iter()
+        .position(|allowlist| {
+            allowlist.kind == allowlist_match.kind && allowlist.value == allowlist_match.value
+        })
+        .or_else(|| {
+            pool.allowlists.iter().position(|allowlist| {
+                allowlist.kind == ALLOWLIST_KIND_REGISTRY
+                    && allowlist.value == *allowlist_aux_account.key
+            })
+        });
+    match position.map(|i| pool.allowlist_price_multipliers_bp[i]) {
+        Some(multiplier_bp) if multiplier_bp != 0 => multiplier_bp,
+        _ => 10000,
+    }
 }
 
 pub fn check_curve(curve_type: u8, curve_delta: u64) -> Result<()> {
-    // So far we only allow linear and exponential curves
+    // So far we only allow linear and exponential curves, and their reverse,
+    // and the constant product curve
     // 0: linear
     // 1: exp
-    if curve_type > 1 {
+    // 2: reverse linear
+    // 3: reverse exp
+    // 4: constant product
+    // 5: price ladder
+    // 6: dutch auction
+    if curve_type > CURVE_KIND_DUTCH_AUCTION {
         return Err(MMMErrorCode::InvalidCurveType.into());
     }
 
     // If the curve type is exp, then the curve_delta should follow bp format,
     // which is less than 10000
-    if curve_type == 1 && curve_delta > 10000 {
+    if (curve_type == CURVE_KIND_EXP || curve_type == CURVE_KIND_REVERSE_EXP) && curve_delta > 10000
+    {
+        return Err(MMMErrorCode::InvalidCurveDelta.into());
+    }
+
+    // If the curve type is constant product, then the curve_delta is the virtual
+    // asset reserve, which has to be positive so that the pool can't be drained
+    if curve_type == CURVE_KIND_CONSTANT_PRODUCT && curve_delta == 0 {
         return Err(MMMErrorCode::InvalidCurveDelta.into());
     }
 
@@ -198,183 +683,777 @@ pub fn get_sol_fee(total_sol_price: u64, fee_bp: i16) -> Result<i64> {
     .map_err(|_| MMMErrorCode::NumericOverflow.into())
 }
 
-pub fn get_sol_total_price_and_next_price(
-    pool: &Pool,
-    n: u64,
-    fulfill_buy: bool,
-) -> Result<(u64, u64)> {
-    // the price needs to go down
-    let p = pool.spot_price;
-    let delta = pool.curve_delta;
-    let ret = match fulfill_buy {
-        true => {
-            match pool.curve_type {
-                CURVE_KIND_LINEAR => {
-                    // n*(2*p-(n-1)*delta)/2
-                    let total_price = n
-                        .checked_mul(
-                            p.checked_mul(2)
-                                .ok_or(MMMErrorCode::NumericOverflow)?
-                                .checked_sub(
-                                    n.checked_sub(1)
-                                        .ok_or(MMMErrorCode::NumericOverflow)?
-                                        .checked_mul(delta)
-                                        .ok_or(MMMErrorCode::NumericOverflow)?,
-                                )
-                                .ok_or(MMMErrorCode::NumericOverflow)?,
-                        )
+// get_protocol_fee is the fee that the taker pays to the treasury of the global config
+pub fn get_protocol_fee(total_sol_price: u64, protocol_fee_bp: u16) -> Result<u64> {
+    u64::try_from(
+        (total_sol_price as u128)
+            .checked_mul(protocol_fee_bp as u128)
+            .ok_or(MMMErrorCode::NumericOverflow)?
+            .checked_div(10000)
+            .ok_or(MMMErrorCode::NumericOverflow)?,
+    )
+    .map_err(|_| MMMErrorCode::NumericOverflow.into())
+}
+
+// linear curve that moves the price down by delta for each unit
+// the prices are p, p-delta, ..., p-(n-1)*delta, and the next price is p-n*delta
+fn get_linear_down_total_price_and_next_price(p: u64, delta: u64, n: u64) -> Result<(u64, u64)> {
+    // n*(2*p-(n-1)*delta)/2
+    let total_price = n
+        .checked_mul(
+            p.checked_mul(2)
+                .ok_or(MMMErrorCode::NumericOverflow)?
+                .checked_sub(
+                    n.checked_sub(1)
                         .ok_or(MMMErrorCode::NumericOverflow)?
-                        .checked_div(2)
-                        .ok_or(MMMErrorCode::NumericOverflow)?;
-                    // p - n * delta
-                    let final_price = p
-                        .checked_sub(n.checked_mul(delta).ok_or(MMMErrorCode::NumericOverflow)?)
-                        .ok_or(MMMErrorCode::NumericOverflow)?;
-                    Ok((total_price, final_price))
-                }
-                CURVE_KIND_EXP => {
-                    // for loop to prevent overflow
-                    let mut total_price: u64 = 0;
-                    let mut curr_price: u128 = p as u128;
-                    for _ in 0..n {
-                        total_price = total_price
-                            .checked_add(curr_price as u64)
-                            .ok_or(MMMErrorCode::NumericOverflow)?;
-                        curr_price = curr_price
-                            .checked_mul(10000)
-                            .ok_or(MMMErrorCode::NumericOverflow)?
-                            .checked_div(
-                                (delta as u128)
-                                    .checked_add(10000)
-                                    .ok_or(MMMErrorCode::NumericOverflow)?,
-                            )
-                            .ok_or(MMMErrorCode::NumericOverflow)?;
-                    }
-                    Ok((total_price, curr_price as u64))
-                }
-                _ => Err(MMMErrorCode::InvalidCurveType.into()),
-            }
-        }
-        false => {
-            // for sales, all prices will be one "step" away from the spot price to prevent pool drain
-            match pool.curve_type {
-                CURVE_KIND_LINEAR => {
-                    // n*(2*p+(n+1)*delta)/2
-                    let total_price = n
-                        .checked_mul(
-                            p.checked_mul(2)
-                                .ok_or(MMMErrorCode::NumericOverflow)?
-                                .checked_add(
-                                    n.checked_add(1)
-                                        .ok_or(MMMErrorCode::NumericOverflow)?
-                                        .checked_mul(delta)
-                                        .ok_or(MMMErrorCode::NumericOverflow)?,
-                                )
-                                .ok_or(MMMErrorCode::NumericOverflow)?,
-                        )
+                        .checked_mul(delta)
+                        .ok_or(MMMErrorCode::NumericOverflow)?,
+                )
+                .ok_or(MMMErrorCode::NumericOverflow)?,
+        )
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_div(2)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    // p - n * delta
+    let final_price = p
+        .checked_sub(n.checked_mul(delta).ok_or(MMMErrorCode::NumericOverflow)?)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    Ok((total_price, final_price))
+}
+
+// linear curve that moves the price up by delta for each unit
+// the prices are p, p+delta, ..., p+(n-1)*delta, and the next price is p+n*delta
+fn get_linear_up_total_price_and_next_price(p: u64, delta: u64, n: u64) -> Result<(u64, u64)> {
+    // n*(2*p+(n-1)*delta)/2
+    let total_price = n
+        .checked_mul(
+            p.checked_mul(2)
+                .ok_or(MMMErrorCode::NumericOverflow)?
+                .checked_add(
+                    n.checked_sub(1)
                         .ok_or(MMMErrorCode::NumericOverflow)?
-                        .checked_div(2)
-                        .ok_or(MMMErrorCode::NumericOverflow)?;
-                    // p - n * delta
-                    let final_price = p
-                        .checked_add(n.checked_mul(delta).ok_or(MMMErrorCode::NumericOverflow)?)
-                        .ok_or(MMMErrorCode::NumericOverflow)?;
-                    Ok((total_price, final_price))
-                }
-                CURVE_KIND_EXP => {
-                    let mut total_price: u64 = 0;
-                    let mut curr_price: u128 = p as u128;
-                    for _ in 0..n {
-                        curr_price = curr_price
-                            .checked_mul(
-                                (delta as u128)
-                                    .checked_add(10000)
-                                    .ok_or(MMMErrorCode::NumericOverflow)?,
-                            )
-                            .ok_or(MMMErrorCode::NumericOverflow)?
-                            .checked_div(10000)
-                            .ok_or(MMMErrorCode::NumericOverflow)?;
-                        total_price = total_price
-                            .checked_add(curr_price as u64)
-                            .ok_or(MMMErrorCode::NumericOverflow)?;
-                    }
-                    Ok((total_price, curr_price as u64))
-                }
-                _ => Err(MMMErrorCode::InvalidCurveType.into()),
-            }
-        }
-    };
+                        .checked_mul(delta)
+                        .ok_or(MMMErrorCode::NumericOverflow)?,
+                )
+                .ok_or(MMMErrorCode::NumericOverflow)?,
+        )
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_div(2)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    // p + n * delta
+    let final_price = p
+        .checked_add(n.checked_mul(delta).ok_or(MMMErrorCode::NumericOverflow)?)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    Ok((total_price, final_price))
+}
 
-    match ret {
-        Ok((total_price, final_price)) => {
-            if total_price == 0 {
-                return Err(MMMErrorCode::NumericOverflow.into());
-            }
+// fixed point numbers with 64 fractional bits, used by the closed form exp curves
+const FP_ONE: u128 = 1 << 64;
+const FP_MASK: u128 = FP_ONE - 1;
 
-            if total_price > MAX_TOTAL_PRICE {
-                return Err(MMMErrorCode::NumericOverflow.into());
-            }
-            Ok((total_price, final_price))
-        }
-        Err(e) => Err(e),
-    }
+// mul_fp returns x * y / 2^64 rounded down. The 256 bits product is split into
+// 64 bits limbs, and it's None if the result doesn't fit in u128.
+fn mul_fp(x: u128, y: u128) -> Option<u128> {
+    let (xh, xl) = (x >> 64, x & FP_MASK);
+    let (yh, yl) = (y >> 64, y & FP_MASK);
+    xh.checked_mul(yh)?
+        .checked_mul(FP_ONE)?
+        .checked_add(xh * yl)?
+        .checked_add(xl * yh)?
+        .checked_add((xl * yl) >> 64)
 }
 
-pub fn try_close_pool<'info>(pool: &Account<'info, Pool>, owner: AccountInfo<'info>) -> Result<()> {
-    if pool.sellside_asset_amount != 0 {
-        return Ok(());
+// get_exp_lower_bound bounds the prices p, floor(p*r), ... of the exp curve from below,
+// when each floor loses at most loss / den. They are bounded by l_k = r * l_(k-1) - e
+// with e = loss / den, whose closed form is l_k = (p - q) * r^k + q with q = e / (r - 1).
+// r^k and the geometric series 1 + r + ... + r^(k-1) are computed in fixed point by
+// binary exponentiation over the bits of n, and every step is rounded down. The
+// prices don't move when the floor cancels the ratio, i.e. floor(p*r) = p, which is
+// priced exactly.
+fn get_exp_lower_bound(p: u64, num: u64, den: u64, loss: u64, n: u64) -> Result<(u64, u64)> {
+    let overflow = || -> Error { MMMErrorCode::NumericOverflow.into() };
+    let stuck = if num >= den {
+        (p as u128) * ((num - den) as u128) < den as u128
+    } else {
+        p == 0
+    };
+    if stuck {
+        return Ok((p.checked_mul(n).ok_or_else(overflow)?, p));
     }
 
-    if pool.buyside_payment_amount != 0 {
-        return Ok(());
+    let (num, den, loss) = (num as u128, den as u128, (loss as u128) << 64);
+    let r = (num << 64) / den;
+    // pow is r^m and sum is 1 + r + ... + r^(m-1), where m is the prefix of the bits of n
+    let mut pow = FP_ONE;
+    let mut sum: u128 = 0;
+    for i in (0..64 - n.leading_zeros()).rev() {
+        // m -> 2m
+        sum = sum
+            .checked_add(mul_fp(sum, pow).ok_or_else(overflow)?)
+            .ok_or_else(overflow)?;
+        pow = mul_fp(pow, pow).ok_or_else(overflow)?;
+        // m -> m+1
+        if (n >> i) & 1 == 1 {
+            sum = sum.checked_add(pow).ok_or_else(overflow)?;
+            pow = mul_fp(pow, r).ok_or_else(overflow)?;
+        }
     }
 
-    pool.to_account_info()
-        .data
-        .borrow_mut()
-        .copy_from_slice(&[0; Pool::LEN]);
+    let p = (p as u128) << 64;
+    let (total_price, next_price) = if num > den {
+        // q is positive, and p >= q as the price isn't stuck
+        let q_down = loss / (num - den);
+        let q_up = (loss + num - den - 1) / (num - den);
+        let p_minus_q = p.saturating_sub(q_up);
+        (
+            mul_fp(p_minus_q, sum)
+                .and_then(|total_price| total_price.checked_add((n as u128).checked_mul(q_down)?))
+                .ok_or_else(overflow)?,
+            mul_fp(p_minus_q, pow)
+                .and_then(|next_price| next_price.checked_add(q_down))
+                .ok_or_else(overflow)?,
+        )
+    } else {
+        // q is negative, so the bound is clamped at 0, i.e. the prices are
+        // (p + q') * r^k - q' with q' = -q
+        let q_down = loss / (den - num);
+        let q_up = (loss + den - num - 1) / (den - num);
+        (
+            mul_fp(p, sum).ok_or_else(overflow)?.saturating_sub(
+                (n as u128)
+                    .saturating_mul(q_up)
+                    .saturating_sub(mul_fp(q_down, sum).ok_or_else(overflow)?),
+            ),
+            mul_fp(p, pow)
+                .ok_or_else(overflow)?
+                .saturating_sub(q_up - mul_fp(q_down, pow).ok_or_else(overflow)?),
+        )
+    };
 
-    let curr_lamports = pool.to_account_info().lamports();
-    **pool.to_account_info().lamports.borrow_mut() = 0;
-    **owner.lamports.borrow_mut() = owner.lamports().checked_add(curr_lamports).unwrap();
-    Ok(())
+    // the first price is p, which the bound of the down curve can fall below
+    let total_price = if n == 0 {
+        total_price
+    } else {
+        total_price.max(p)
+    };
+    // the prices are integers, so the bounds are rounded up
+    let ceil = |x: u128| u64::try_from((x >> 64) + (x & FP_MASK != 0) as u128);
+    Ok((
+        ceil(total_price).map_err(|_| overflow())?,
+        ceil(next_price).map_err(|_| overflow())?,
+    ))
 }
 
-pub fn try_close_escrow<'info>(
-    escrow: &AccountInfo<'info>,
-    pool: &Account<'info, Pool>,
-    system_program: &Program<'info, System>,
-    escrow_seeds: &[&[&[u8]]],
-) -> Result<()> {
-    let min_rent = Rent::get()?.minimum_balance(0);
-    let escrow_lamports = escrow.lamports();
-    if escrow_lamports == 0 || escrow_lamports > min_rent {
-        Ok(())
-    } else {
-        anchor_lang::solana_program::program::invoke_signed(
-            &anchor_lang::solana_program::system_instruction::transfer(
-                escrow.key,
-                &pool.key(),
-                escrow_lamports,
-            ),
-            &[
-                escrow.clone(),
-                pool.to_account_info(),
-                system_program.to_account_info(),
-            ],
-            escrow_seeds,
-        )?;
-        Ok(())
+// exp curve that moves the price by the ratio r = num / den for each unit
+// the prices are p, floor(p*r), ..., i.e. every price is floored from the previous one
+// like a per unit loop would, and the next price is the n-th one
+//
+// With r = a / b in lowest terms, the floors don't lose anything while b^k divides p,
+// so those units are priced exactly, and they take at most log_b(p) iterations. A
+// floor loses at most (b - 1) / b after that, so the rest of the units are priced by
+// the lower bound from the last exact price. So the result is never above the per
+// unit loop, and it's below the loop by at most the flooring that the loop
+// accumulates after the exact units, i.e. for n such units:
+//   next price:  at most 1 + r + ... + r^(n-1) lamports
+//   total price: at most the sum of the next price bounds of 0..n-1 units
+fn get_exp_total_price_and_next_price(p: u64, num: u64, den: u64, n: u64) -> Result<(u64, u64)> {
+    let (mut gcd, mut rem) = (num, den);
+    while rem != 0 {
+        (gcd, rem) = (rem, gcd % rem);
     }
+    let b = den / gcd;
+    let (mut exact_units, mut rest) = (0, p);
+    while exact_units < n && b > 1 && rest > 0 && rest % b == 0 {
+        exact_units += 1;
+        rest /= b;
+    }
+
+    let (exact_total_price, exact_price) = get_exp_lower_bound(p, num, den, 0, exact_units)?;
+    let (total_price, next_price) =
+        get_exp_lower_bound(exact_price, num, den, den - gcd, n - exact_units)?;
+    Ok((
+        exact_total_price
+            .checked_add(total_price)
+            .ok_or(MMMErrorCode::NumericOverflow)?,
+        next_price,
+    ))
 }
 
-pub fn try_close_sell_state<'info>(
-    sell_state: &Account<'info, SellState>,
-    owner: AccountInfo<'info>,
-) -> Result<()> {
-    if sell_state.asset_amount != 0 {
-        return Ok(());
-    }
+// exp curve that moves the price down by delta bp for each unit
+// the prices are p, p/(1+delta), ..., and the next price is p/(1+delta)^n
+fn get_exp_down_total_price_and_next_price(p: u64, delta: u64, n: u64) -> Result<(u64, u64)> {
+    let den = delta
+        .checked_add(10000)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    get_exp_total_price_and_next_price(p, 10000, den, n)
+}
+
+// exp curve that moves the price up by delta bp for each unit
+// the prices are p, p*(1+delta), ..., and the next price is p*(1+delta)^n
+fn get_exp_up_total_price_and_next_price(p: u64, delta: u64, n: u64) -> Result<(u64, u64)> {
+    let num = delta
+        .checked_add(10000)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    get_exp_total_price_and_next_price(p, num, 10000, n)
+}
+
+// constant product curve that prices from the reserves of the pool, x * y = k, where
+// x is the buyside payment amount and y is the sellside asset amount plus the virtual
+// asset reserve (delta). The spot price x / y is only a cache of the reserves.
+// the pool buys n units for x * n / (y + n), and the next price is x' / (y + n)
+fn get_constant_product_buy_total_price_and_next_price(
+    x: u64,
+    y: u64,
+    n: u64,
+) -> Result<(u64, u64)> {
+    let (x, y, n) = (x as u128, y as u128, n as u128);
+    let y_after = y.checked_add(n).ok_or(MMMErrorCode::NumericOverflow)?;
+    let total_price = x
+        .checked_mul(n)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_div(y_after)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    let next_price = x
+        .checked_sub(total_price)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_div(y_after)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    Ok((total_price as u64, next_price as u64))
+}
+
+// the pool sells n units for x * n / (y - n) rounded up, and the next price is x' / (y - n).
+// the sales are priced from the payment reserve, so a pool without one can't sell
+fn get_constant_product_sell_total_price_and_next_price(
+    x: u64,
+    y: u64,
+    n: u64,
+) -> Result<(u64, u64)> {
+    if x == 0 {
+        msg!("InvalidSpotPrice: no payment reserve to price the constant product sales");
+        return Err(MMMErrorCode::InvalidSpotPrice.into());
+    }
+    let (x, y, n) = (x as u128, y as u128, n as u128);
+    let y_after = y
+        .checked_sub(n)
+        .filter(|y_after| *y_after > 0)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    let total_price = x
+        .checked_mul(n)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(y_after - 1)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        / y_after;
+    let next_price = x
+        .checked_add(total_price)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        / y_after;
+    Ok((
+        u64::try_from(total_price).map_err(|_| MMMErrorCode::NumericOverflow)?,
+        u64::try_from(next_price).map_err(|_| MMMErrorCode::NumericOverflow)?,
+    ))
+}
+
+// the spot_price of the constant product curve is only a cache of the reserves, so it's
+// refreshed whenever the reserves change. The fulfills refresh it from the escrow balance too,
+// since the fees, the royalties and a disabled reinvest don't move the reserves along the curve
+pub fn refresh_constant_product_spot_price(pool: &mut Pool) -> Result<()> {
+    if pool.curve_type == CURVE_KIND_CONSTANT_PRODUCT {
+        let y = pool
+            .sellside_asset_amount
+            .checked_add(pool.curve_delta)
+            .ok_or(MMMErrorCode::NumericOverflow)?;
+        pool.spot_price = pool
+            .buyside_payment_amount
+            .checked_div(y)
+            .ok_or(MMMErrorCode::InvalidCurveDelta)?;
+    }
+    Ok(())
+}
+
+// check_price_ladder makes sure that the price ladder curve has at least one step,
+// and that the empty steps are only at the end of the ladder
+pub fn check_price_ladder(curve_type: u8, price_ladder: &[PriceLadderStep]) -> Result<()> {
+    if curve_type != CURVE_KIND_PRICE_LADDER {
+        return Ok(());
+    }
+    let steps_len = price_ladder
+        .iter()
+        .take_while(|step| !step.is_empty())
+        .count();
+    if steps_len == 0
+        || price_ladder[steps_len..]
+            .iter()
+            .any(|step| step.amount != 0 || !step.is_empty())
+    {
+        return Err(MMMErrorCode::InvalidPriceLadder.into());
+    }
+    Ok(())
+}
+
+// set_price_ladder replaces the price ladder and restarts it from the first step if
+// it's set, and the spot_price of the price ladder curve is only a cache of the ladder
+pub fn set_price_ladder(
+    pool: &mut Pool,
+    price_ladder: Option<[PriceLadderStep; PRICE_LADDER_MAX_LEN]>,
+) -> Result<()> {
+    if let Some(price_ladder) = price_ladder {
+        pool.price_ladder = price_ladder;
+        pool.price_ladder_position = 0;
+    }
+    check_price_ladder(pool.curve_type, &pool.price_ladder)?;
+    if pool.curve_type == CURVE_KIND_PRICE_LADDER {
+        pool.spot_price = get_price_ladder_price(&pool.price_ladder, pool.price_ladder_position);
+    }
+    Ok(())
+}
+
+// get_price_ladder_price returns the price of the unit at the position
+pub fn get_price_ladder_price(price_ladder: &[PriceLadderStep], position: u64) -> u64 {
+    let mut start: u64 = 0;
+    let mut price = 0;
+    for step in price_ladder.iter().take_while(|step| !step.is_empty()) {
+        price = step.price;
+        start = start.saturating_add(step.amount);
+        if position < start {
+            break;
+        }
+    }
+    price
+}
+
+// price ladder curve that prices the units by the steps of the ladder, the units from
+// the position (inclusive) to the end position (exclusive) are summed up by step
+fn get_price_ladder_total_price(
+    price_ladder: &[PriceLadderStep],
+    position: u64,
+    end_position: u64,
+) -> Result<u64> {
+    let steps = price_ladder
+        .iter()
+        .take_while(|step| !step.is_empty())
+        .collect::<Vec<_>>();
+    let mut total_price: u64 = 0;
+    let mut start: u64 = 0;
+    for (i, step) in steps.iter().enumerate() {
+        let end = if i == steps.len() - 1 {
+            u64::MAX
+        } else {
+            start.saturating_add(step.amount)
+        };
+        let units = end.min(end_position).saturating_sub(start.max(position));
+        total_price = units
+            .checked_mul(step.price)
+            .and_then(|price| price.checked_add(total_price))
+            .ok_or(MMMErrorCode::NumericOverflow)?;
+        start = end;
+    }
+    Ok(total_price)
+}
+
+// the pool buys the units from the position forward, and the next price is the price
+// of the unit at the new position
+fn get_price_ladder_buy_total_price_and_next_price(
+    price_ladder: &[PriceLadderStep],
+    position: u64,
+    n: u64,
+) -> Result<(u64, u64)> {
+    let end_position = position
+        .checked_add(n)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    let total_price = get_price_ladder_total_price(price_ladder, position, end_position)?;
+    Ok((
+        total_price,
+        get_price_ladder_price(price_ladder, end_position),
+    ))
+}
+
+// the pool sells the units back from the position, i.e. at the prices that it bought
+// them, and the units beyond the start of the ladder are priced at the first step
+fn get_price_ladder_sell_total_price_and_next_price(
+    price_ladder: &[PriceLadderStep],
+    position: u64,
+    n: u64,
+) -> Result<(u64, u64)> {
+    let start_position = position.saturating_sub(n);
+    let total_price = get_price_ladder_total_price(price_ladder, start_position, position)?
+        .checked_add(
+            n.checked_sub(position - start_position)
+                .ok_or(MMMErrorCode::NumericOverflow)?
+                .checked_mul(price_ladder[0].price)
+                .ok_or(MMMErrorCode::NumericOverflow)?,
+        )
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    Ok((
+        total_price,
+        get_price_ladder_price(price_ladder, start_position),
+    ))
+}
+
+// set_sell_curve_delta sets the sell side delta of the linear and exp curves, and
+// None means that both sides move by curve_delta. The curve is anchored at the
+// spot_price, so it's called whenever the spot_price is set by the owner or operator
+pub fn set_sell_curve_delta(pool: &mut Pool, sell_curve_delta: Option<u64>) -> Result<()> {
+    if let Some(sell_curve_delta) = sell_curve_delta {
+        if pool.curve_type > CURVE_KIND_REVERSE_EXP {
+            return Err(MMMErrorCode::InvalidCurveType.into());
+        }
+        check_curve(pool.curve_type, sell_curve_delta)?;
+    }
+    pool.asymmetric_curve_delta = sell_curve_delta.is_some();
+    pool.sell_curve_delta = sell_curve_delta.unwrap_or_default();
+    pool.curve_anchor_price = pool.spot_price;
+    Ok(())
+}
+
+// get_curve_segment prices n units on the linear or exp curve from p, i.e. the prices
+// are p, ..., and the next price is n steps away from p
+fn get_curve_segment(curve_type: u8, p: u64, delta: u64, up: bool, n: u64) -> Result<(u64, u64)> {
+    match (curve_type, up) {
+        (CURVE_KIND_LINEAR | CURVE_KIND_REVERSE_LINEAR, true) => {
+            get_linear_up_total_price_and_next_price(p, delta, n)
+        }
+        (CURVE_KIND_LINEAR | CURVE_KIND_REVERSE_LINEAR, false) => {
+            get_linear_down_total_price_and_next_price(p, delta, n)
+        }
+        (CURVE_KIND_EXP | CURVE_KIND_REVERSE_EXP, true) => {
+            get_exp_up_total_price_and_next_price(p, delta, n)
+        }
+        (CURVE_KIND_EXP | CURVE_KIND_REVERSE_EXP, false) => {
+            get_exp_down_total_price_and_next_price(p, delta, n)
+        }
+        _ => Err(MMMErrorCode::InvalidCurveType.into()),
+    }
+}
+
+// get_units_to_anchor returns the number of units from p until the price reaches the
+// anchor, or None if it doesn't within n units. The exp curves are searched by the
+// next price of the closed form, which only moves away from p, and an overflow means
+// that the price is already past the anchor.
+fn get_units_to_anchor(
+    curve_type: u8,
+    p: u64,
+    delta: u64,
+    up: bool,
+    anchor: u64,
+    n: u64,
+) -> Option<u64> {
+    let units = match curve_type {
+        CURVE_KIND_LINEAR | CURVE_KIND_REVERSE_LINEAR => {
+            let distance = p.abs_diff(anchor);
+            distance
+                .checked_div(delta)
+                .map(|units| units + (units * delta < distance) as u64)
+        }
+        _ => {
+            let reached = |units| match get_curve_segment(curve_type, p, delta, up, units) {
+                Ok((_, next_price)) if up => next_price >= anchor,
+                Ok((_, next_price)) => next_price <= anchor,
+                Err(_) => true,
+            };
+            if !reached(n) {
+                return None;
+            }
+            let (mut lo, mut hi) = (1, n);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if reached(mid) {
+                    hi = mid;
+                } else {
+                    lo = mid + 1;
+                }
+            }
+            Some(lo)
+        }
+    };
+    units.filter(|units| *units <= n)
+}
+
+// asymmetric curve that moves by curve_delta on the buy side of the anchor price, i.e.
+// the side that fulfill_buy moves the price toward, and by sell_curve_delta on the other
+// side. Every price level only depends on how far the price is from the anchor, and
+// not on the direction of the trades, so round trips can't drain the pool. When the
+// price walks back toward the anchor, it's clamped at the anchor.
+//
+// So the units are priced by at most two closed form segments, the units toward the
+// anchor by the delta of the side that the price is on, and the units from the anchor
+// on by the delta of the side that the price moves into.
+fn get_asymmetric_total_price_and_next_price(
+    pool: &Pool,
+    n: u64,
+    fulfill_buy: bool,
+) -> Result<(u64, u64)> {
+    let p = pool.spot_price;
+    if n == 0 {
+        return Ok((0, p));
+    }
+    let buy_up = matches!(
+        pool.curve_type,
+        CURVE_KIND_REVERSE_LINEAR | CURVE_KIND_REVERSE_EXP
+    );
+    let up = buy_up == fulfill_buy;
+    let anchor = pool.curve_anchor_price;
+    let (toward_delta, away_delta) = if fulfill_buy {
+        (pool.sell_curve_delta, pool.curve_delta)
+    } else {
+        (pool.curve_delta, pool.sell_curve_delta)
+    };
+
+    // the first unit is bought at the spot price
+    let toward_anchor = if up { p < anchor } else { p > anchor };
+    let (total_price, price, units) = if toward_anchor {
+        match get_units_to_anchor(pool.curve_type, p, toward_delta, up, anchor, n) {
+            Some(units) => {
+                let (total_price, _) =
+                    get_curve_segment(pool.curve_type, p, toward_delta, up, units)?;
+                (total_price, anchor, n - units)
+            }
+            None => {
+                let (total_price, next_price) =
+                    get_curve_segment(pool.curve_type, p, toward_delta, up, n)?;
+                (total_price, next_price, 0)
+            }
+        }
+    } else {
+        (0, p, n)
+    };
+    let (total_price, next_price) = if units > 0 {
+        let (away_total_price, next_price) =
+            get_curve_segment(pool.curve_type, price, away_delta, up, units)?;
+        (
+            total_price
+                .checked_add(away_total_price)
+                .ok_or(MMMErrorCode::NumericOverflow)?,
+            next_price,
+        )
+    } else {
+        (total_price, price)
+    };
+
+    if fulfill_buy {
+        return Ok((total_price, next_price));
+    }
+    // for sales, all prices will be one "step" away from the spot price
+    let total_price = total_price
+        .checked_sub(p)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_add(next_price)
+        .ok_or(MMMErrorCode::NumericOverflow)?;
+    Ok((total_price, next_price))
+}
+
+// dutch auction curve that only prices the sales, the ask decays from the spot price
+// toward the floor over time, and the units are priced from the ask up by delta like
+// the linear curve, i.e. ask, ask+delta, ..., and the next auction starts at ask+n*delta
+fn get_dutch_auction_sell_total_price_and_next_price(
+    pool: &Pool,
+    n: u64,
+    now: i64,
+) -> Result<(u64, u64)> {
+    let elapsed = now.saturating_sub(pool.dutch_auction_started_at).max(0) as u64;
+    let ask = pool
+        .spot_price
+        .saturating_sub(pool.dutch_auction.decay_per_second.saturating_mul(elapsed))
+        .max(pool.dutch_auction.floor);
+    get_linear_up_total_price_and_next_price(ask, pool.curve_delta, n)
+}
+
+// reset_dutch_auction restarts the dutch auction from the spot_price, it's called
+// whenever the spot_price is set, i.e. by update_pool, operator_update_pool and the sales
+pub fn reset_dutch_auction(pool: &mut Pool) -> Result<()> {
+    if pool.curve_type != CURVE_KIND_DUTCH_AUCTION {
+        return Ok(());
+    }
+    if pool.dutch_auction.floor == 0 || pool.dutch_auction.floor > pool.spot_price {
+        return Err(MMMErrorCode::InvalidDutchAuction.into());
+    }
+    pool.dutch_auction_started_at = Clock::get()?.unix_timestamp;
+    Ok(())
+}
+
+// advance_curve updates the curve state of the pool after the fulfill, on top of the
+// spot_price, i.e. the position of the price ladder curve, and the dutch auction
+// restarts after each sale
+pub fn advance_curve(pool: &mut Pool, n: u64, fulfill_buy: bool) -> Result<()> {
+    match pool.curve_type {
+        CURVE_KIND_PRICE_LADDER => {
+            pool.price_ladder_position = if fulfill_buy {
+                pool.price_ladder_position
+                    .checked_add(n)
+                    .ok_or(MMMErrorCode::NumericOverflow)?
+            } else {
+                pool.price_ladder_position.saturating_sub(n)
+            };
+        }
+        CURVE_KIND_DUTCH_AUCTION => reset_dutch_auction(pool)?,
+        _ => {}
+    }
+    Ok(())
+}
+
+// the total price is scaled by the allowlist multiplier of the asset, while the
+// next price stays on the curve of the pool
+pub fn get_sol_total_price_and_next_price(
+    pool: &Pool,
+    n: u64,
+    fulfill_buy: bool,
+    allowlist_multiplier_bp: u16,
+) -> Result<(u64, u64)> {
+    let p = pool.spot_price;
+    let delta = pool.curve_delta;
+    let ret = match fulfill_buy {
+        _ if pool.asymmetric_curve_delta => {
+            get_asymmetric_total_price_and_next_price(pool, n, fulfill_buy)
+        }
+        true => {
+            // the first unit is bought at the spot price
+            match pool.curve_type {
+                // the price needs to go down
+                CURVE_KIND_LINEAR => get_linear_down_total_price_and_next_price(p, delta, n),
+                CURVE_KIND_EXP => get_exp_down_total_price_and_next_price(p, delta, n),
+                // the price needs to go up
+                CURVE_KIND_REVERSE_LINEAR => get_linear_up_total_price_and_next_price(p, delta, n),
+                CURVE_KIND_REVERSE_EXP => get_exp_up_total_price_and_next_price(p, delta, n),
+                CURVE_KIND_CONSTANT_PRODUCT => get_constant_product_buy_total_price_and_next_price(
+                    pool.buyside_payment_amount,
+                    pool.sellside_asset_amount
+                        .checked_add(delta)
+                        .ok_or(MMMErrorCode::NumericOverflow)?,
+                    n,
+                ),
+                CURVE_KIND_PRICE_LADDER => get_price_ladder_buy_total_price_and_next_price(
+                    &pool.price_ladder,
+                    pool.price_ladder_position,
+                    n,
+                ),
+                // the dutch auction curve is only for the sellside inventory
+                _ => Err(MMMErrorCode::InvalidCurveType.into()),
+            }
+        }
+        // the constant product prices already move away from the spot price for sales
+        false if pool.curve_type == CURVE_KIND_CONSTANT_PRODUCT => {
+            get_constant_product_sell_total_price_and_next_price(
+                pool.buyside_payment_amount,
+                pool.sellside_asset_amount
+                    .checked_add(delta)
+                    .ok_or(MMMErrorCode::NumericOverflow)?,
+                n,
+            )
+        }
+        // the dutch auction ask is the first sale price, so it's not shifted either
+        false if pool.curve_type == CURVE_KIND_DUTCH_AUCTION => {
+            get_dutch_auction_sell_total_price_and_next_price(pool, n, Clock::get()?.unix_timestamp)
+        }
+        // the price ladder sales are priced by the units that the pool bought before
+        false if pool.curve_type == CURVE_KIND_PRICE_LADDER => {
+            get_price_ladder_sell_total_price_and_next_price(
+                &pool.price_ladder,
+                pool.price_ladder_position,
+                n,
+            )
+        }
+        false => {
+            // for sales, all prices will be one "step" away from the spot price to prevent pool drain
+            // which means that the prices are shifted by one unit compared to the fulfill_buy ones,
+            // e.g. the linear prices are p+delta, p+2*delta, ..., p+n*delta
+            let ret = match pool.curve_type {
+                // the price needs to go up
+                CURVE_KIND_LINEAR => get_linear_up_total_price_and_next_price(p, delta, n),
+                CURVE_KIND_EXP => get_exp_up_total_price_and_next_price(p, delta, n),
+                // the price needs to go down
+                CURVE_KIND_REVERSE_LINEAR => {
+                    get_linear_down_total_price_and_next_price(p, delta, n)
+                }
+                CURVE_KIND_REVERSE_EXP => get_exp_down_total_price_and_next_price(p, delta, n),
+                _ => Err(MMMErrorCode::InvalidCurveType.into()),
+            };
+            ret.and_then(|(total_price, final_price)| {
+                let total_price = total_price
+                    .checked_sub(p)
+                    .ok_or(MMMErrorCode::NumericOverflow)?
+                    .checked_add(final_price)
+                    .ok_or(MMMErrorCode::NumericOverflow)?;
+                Ok((total_price, final_price))
+            })
+        }
+    };
+
+    match ret {
+        Ok((total_price, final_price)) => {
+            let total_price = u64::try_from(
+                (total_price as u128)
+                    .checked_mul(allowlist_multiplier_bp as u128)
+                    .ok_or(MMMErrorCode::NumericOverflow)?
+                    / 10000,
+            )
+            .map_err(|_| MMMErrorCode::NumericOverflow)?;
+            if total_price == 0 {
+                return Err(MMMErrorCode::NumericOverflow.into());
+            }
+
+            if total_price > MAX_TOTAL_PRICE {
+                return Err(MMMErrorCode::NumericOverflow.into());
+            }
+            Ok((total_price, final_price))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub fn try_close_pool<'info>(pool: &Account<'info, Pool>, owner: AccountInfo<'info>) -> Result<()> {
+    if pool.sellside_asset_amount != 0 {
+        return Ok(());
+    }
+
+    if pool.buyside_payment_amount != 0 {
+        return Ok(());
+    }
+
+    pool.to_account_info()
+        .data
+        .borrow_mut()
+        .copy_from_slice(&[0; Pool::LEN]);
+
+    let curr_lamports = pool.to_account_info().lamports();
+    **pool.to_account_info().lamports.borrow_mut() = 0;
+    **owner.lamports.borrow_mut() = owner.lamports().checked_add(curr_lamports).unwrap();
+    Ok(())
+}
+
+pub fn try_close_escrow<'info>(
+    escrow: &AccountInfo<'info>,
+    pool: &Account<'info, Pool>,
+    system_program: &Program<'info, System>,
+    escrow_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let min_rent = Rent::get()?.minimum_balance(0);
+    let escrow_lamports = escrow.lamports();
+    if escrow_lamports == 0 || escrow_lamports > min_rent {
+        Ok(())
+    } else {
+        anchor_lang::solana_program::program::invoke_signed(
+            &anchor_lang::solana_program::system_instruction::transfer(
+                escrow.key,
+                &pool.key(),
+                escrow_lamports,
+            ),
+            &[
+                escrow.clone(),
+                pool.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            escrow_seeds,
+        )?;
+        Ok(())
+    }
+}
+
+pub fn try_close_sell_state<'info>(
+    sell_state: &Account<'info, SellState>,
+    owner: AccountInfo<'info>,
+) -> Result<()> {
+    if sell_state.asset_amount != 0 {
+        return Ok(());
+    }
 
     sell_state
         .to_account_info()
@@ -388,11 +1467,19 @@ pub fn try_close_sell_state<'info>(
     Ok(())
 }
 
+// get_metadata_royalty_bp returns the royalty bp of the metadata, or the dynamic
+// royalty bp if the asset is governed by an ocp policy.
 pub fn get_metadata_royalty_bp(
-    _total_price: u64,
+    total_price: u64,
     parsed_metadata: &Metadata,
+    policy: Option<&Policy>,
 ) -> u16 {
-    parsed_metadata.data.seller_fee_basis_points
+    match policy {
+        Some(policy) => {
+            policy.get_royalty_bp(total_price, parsed_metadata.data.seller_fee_basis_points)
+        }
+        None => parsed_metadata.data.seller_fee_basis_points,
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -405,6 +1492,32 @@ pub fn pay_creator_fees_in_sol<'info>(
     metadata_royalty_bp: u16,
     payer_seeds: &[&[&[u8]]],
     system_program: AccountInfo<'info>,
+) -> Result<u64> {
+    pay_creator_fees_in_sol_for_data(
+        buyside_creator_royalty_bp,
+        total_price,
+        &parsed_metadata.data,
+        creator_accounts,
+        payer,
+        metadata_royalty_bp,
+        payer_seeds,
+        system_program,
+    )
+}
+
+// pay_creator_fees_in_sol_for_data is the same as pay_creator_fees_in_sol, but only
+// needs the creators and seller_fee_basis_points, so that the assets without a
+// token metadata account (e.g. metaplex core) can share the same royalty math.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_creator_fees_in_sol_for_data<'info>(
+    buyside_creator_royalty_bp: u16,
+    total_price: u64,
+    metadata_data: &Data,
+    creator_accounts: &[AccountInfo<'info>],
+    payer: AccountInfo<'info>,
+    metadata_royalty_bp: u16,
+    payer_seeds: &[&[&[u8]]],
+    system_program: AccountInfo<'info>,
 ) -> Result<u64> {
     // total royalty paid by the buyer, it's one of the following
     //   - buyside_sol_escrow_account (when fulfill buy)
@@ -425,7 +1538,7 @@ pub fn pay_creator_fees_in_sol<'info>(
         return Ok(0);
     }
 
-    let creators = if let Some(creators) = &parsed_metadata.data.creators {
+    let creators = if let Some(creators) = &metadata_data.creators {
         creators
     } else {
         return Ok(0);
@@ -436,7 +1549,7 @@ pub fn pay_creator_fees_in_sol<'info>(
     }
 
     // hardcoded the max threshold for InvalidMetadataCreatorRoyalty
-    if parsed_metadata.data.seller_fee_basis_points > MAX_METADATA_CREATOR_ROYALTY_BP {
+    if metadata_data.seller_fee_basis_points > MAX_METADATA_CREATOR_ROYALTY_BP {
         return Err(MMMErrorCode::InvalidMetadataCreatorRoyalty.into());
     }
     let min_rent = Rent::get()?.minimum_balance(0);
@@ -487,6 +1600,141 @@ pub fn pay_creator_fees_in_sol<'info>(
     Ok(total_royalty)
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn pay_creator_fees_in_spl<'info>(
+    buyside_creator_royalty_bp: u16,
+    total_price: u64,
+    parsed_metadata: &Metadata,
+    creator_accounts: &[AccountInfo<'info>],
+    payer: AccountInfo<'info>,
+    payer_token_account: AccountInfo<'info>,
+    metadata_royalty_bp: u16,
+    payer_seeds: &[&[&[u8]]],
+    payment_mint: AccountInfo<'info>,
+    rent_payer: AccountInfo<'info>,
+    associated_token_program: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+) -> Result<u64> {
+    // same as pay_creator_fees_in_sol, but the royalty is paid in the payment_mint
+    // from the payer_token_account, which is one of the following
+    //   - buyside_payment_escrow_account (when fulfill buy, authority is the pool)
+    //   - payer_payment_token_account    (when fulfill sell, authority is the payer)
+    // creator_accounts are pairs of [creator, creator_payment_token_account] in the
+    // same order as the creators in the metadata
+    let royalty = ((total_price as u128)
+        .checked_mul(metadata_royalty_bp as u128)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_div(10000)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_mul(buyside_creator_royalty_bp as u128)
+        .ok_or(MMMErrorCode::NumericOverflow)?
+        .checked_div(10000)
+        .ok_or(MMMErrorCode::NumericOverflow)?) as u64;
+
+    if royalty == 0 {
+        return Ok(0);
+    }
+
+    let creators = if let Some(creators) = &parsed_metadata.data.creators {
+        creators
+    } else {
+        return Ok(0);
+    };
+
+    let payer_balance =
+        anchor_spl::token::accessor::amount(&payer_token_account).unwrap_or_default();
+    if payer_balance < royalty {
+        return Err(MMMErrorCode::NotEnoughBalance.into());
+    }
+
+    // hardcoded the max threshold for InvalidMetadataCreatorRoyalty
+    if parsed_metadata.data.seller_fee_basis_points > MAX_METADATA_CREATOR_ROYALTY_BP {
+        return Err(MMMErrorCode::InvalidMetadataCreatorRoyalty.into());
+    }
+    let mut total_royalty: u64 = 0;
+
+    let creator_accounts_iter = &mut creator_accounts.iter();
+    for (index, creator) in creators.iter().enumerate() {
+        let creator_fee = if index == creators.len() - 1 {
+            royalty
+                .checked_sub(total_royalty)
+                .ok_or(MMMErrorCode::NumericOverflow)?
+        } else {
+            (royalty as u128)
+                .checked_mul(creator.share as u128)
+                .ok_or(MMMErrorCode::NumericOverflow)?
+                .checked_div(100)
+                .ok_or(MMMErrorCode::NumericOverflow)? as u64
+        };
+        let current_creator_info = next_account_info(creator_accounts_iter)?;
+        let current_creator_token_account_info = next_account_info(creator_accounts_iter)?;
+        if creator.address.ne(current_creator_info.key) {
+            return Err(MMMErrorCode::InvalidCreatorAddress.into());
+        }
+        if creator_fee > 0 {
+            init_if_needed_ata(
+                current_creator_token_account_info.to_account_info(),
+                rent_payer.to_account_info(),
+                current_creator_info.to_account_info(),
+                payment_mint.to_account_info(),
+                associated_token_program.to_account_info(),
+                token_program.to_account_info(),
+                system_program.to_account_info(),
+                rent.to_account_info(),
+            )?;
+            anchor_spl::token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    anchor_spl::token::Transfer {
+                        from: payer_token_account.to_account_info(),
+                        to: current_creator_token_account_info.to_account_info(),
+                        authority: payer.to_account_info(),
+                    },
+                    payer_seeds,
+                ),
+                creator_fee,
+            )?;
+            total_royalty = total_royalty
+                .checked_add(creator_fee)
+                .ok_or(MMMErrorCode::NumericOverflow)?;
+        }
+    }
+    Ok(total_royalty)
+}
+
+// realloc_account grows the account to new_len if it's shorter, e.g. an account
+// created with an older layout, and the payer tops up the rent exemption.
+pub fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    new_len: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let rent_top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if rent_top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_top_up,
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
 pub fn log_pool(prefix: &str, pool: &Pool) -> Result<()> {
     msg!(prefix);
     sol_log_data(&[&pool.try_to_vec()?]);
@@ -501,8 +1749,7 @@ pub fn assert_is_programmable(parsed_metadata: &Metadata) -> Result<()> {
     }
 }
 
-pub fn assert_valid_fees_bp(maker_fee_bp: i16, taker_fee_bp: i16) -> Result<()> {
-    let bound = MAX_REFERRAL_FEE_BP;
+pub fn assert_valid_fees_bp(maker_fee_bp: i16, taker_fee_bp: i16, bound: i16) -> Result<()> {
     if !(0..=bound).contains(&taker_fee_bp) {
         return Err(MMMErrorCode::InvalidMakerOrTakerFeeBP.into());
     }
@@ -518,3 +1765,1442 @@ pub fn assert_valid_fees_bp(maker_fee_bp: i16, taker_fee_bp: i16) -> Result<()>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{ALLOWLIST_MAX_LEN, POOL_VERSION, SELL_STATE_VERSION};
+
+    // the per unit loop that the closed form exp curve replaced, it yields the total
+    // and next price of 0, 1, ... units until they overflow
+    fn get_exp_total_price_and_next_price_by_loop(
+        p: u64,
+        num: u64,
+        den: u64,
+    ) -> impl Iterator<Item = (u64, u64)> {
+        let mut total_price: Option<u64> = Some(0);
+        let mut curr_price: Option<u128> = Some(p as u128);
+        std::iter::from_fn(move || {
+            let prices = (total_price?, u64::try_from(curr_price?).ok()?);
+            total_price = prices.0.checked_add(prices.1);
+            curr_price = (prices.1 as u128)
+                .checked_mul(num as u128)
+                .map(|price| price / den as u128);
+            Some(prices)
+        })
+    }
+
+    fn allowlist(kind: u8, value: Pubkey) -> Allowlist {
+        Allowlist { kind, value }
+    }
+
+    // get_allowlists of the pool at pool_key, with the aux account at aux_key
+    fn get_allowlists_with_aux<T: AccountSerialize>(
+        pool: &Pool,
+        pool_key: Pubkey,
+        aux: &T,
+        aux_key: Pubkey,
+    ) -> Result<Vec<Allowlist>> {
+        let mut pool_data = Vec::new();
+        pool.try_serialize(&mut pool_data)?;
+        let mut aux_data = Vec::new();
+        aux.try_serialize(&mut aux_data)?;
+        let (mut pool_lamports, mut aux_lamports) = (0, 0);
+        let pool_info = AccountInfo::new(
+            &pool_key,
+            false,
+            false,
+            &mut pool_lamports,
+            &mut pool_data,
+            &crate::ID,
+            false,
+            0,
+        );
+        let aux_info = AccountInfo::new(
+            &aux_key,
+            false,
+            false,
+            &mut aux_lamports,
+            &mut aux_data,
+            &crate::ID,
+            false,
+            0,
+        );
+        get_allowlists(&Account::<Pool>::try_from(&pool_info)?, &aux_info)
+    }
+
+    #[test]
+    fn test_get_allowlists_overflow_allowlist() {
+        let pool_key = Pubkey::new_unique();
+        let mut pool = Pool::default();
+        pool.allowlists[0] = allowlist(ALLOWLIST_KIND_MINT, Pubkey::new_unique());
+        let overflow_entry = allowlist(ALLOWLIST_KIND_MCC, Pubkey::new_unique());
+        let mut overflow_allowlist = OverflowAllowlist {
+            pool: pool_key,
+            allowlists: vec![overflow_entry],
+        };
+
+        let allowlists =
+            get_allowlists_with_aux(&pool, pool_key, &overflow_allowlist, Pubkey::new_unique())
+                .unwrap();
+        assert_eq!(allowlists.len(), ALLOWLIST_MAX_LEN + 1);
+        assert_eq!(allowlists[ALLOWLIST_MAX_LEN].value, overflow_entry.value);
+
+        // the overflow allowlist of another pool
+        overflow_allowlist.pool = Pubkey::new_unique();
+        assert!(get_allowlists_with_aux(
+            &pool,
+            pool_key,
+            &overflow_allowlist,
+            Pubkey::new_unique()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_allowlists_registry() {
+        let registry_key = Pubkey::new_unique();
+        let mut pool = Pool::default();
+        pool.allowlists[0] = allowlist(ALLOWLIST_KIND_REGISTRY, registry_key);
+        let registry_entry = allowlist(ALLOWLIST_KIND_FVCA, Pubkey::new_unique());
+        let registry = AllowlistRegistry {
+            entries: vec![registry_entry],
+            ..Default::default()
+        };
+
+        let allowlists =
+            get_allowlists_with_aux(&pool, Pubkey::new_unique(), &registry, registry_key).unwrap();
+        assert_eq!(allowlists.len(), ALLOWLIST_MAX_LEN + 1);
+        assert_eq!(allowlists[ALLOWLIST_MAX_LEN].value, registry_entry.value);
+        // the registry entry itself never matches, so only the resolved entries do
+        let matched = check_allowlist_matches(&allowlists, |allowlist_val| {
+            Ok(allowlist_val.kind == ALLOWLIST_KIND_FVCA)
+        })
+        .unwrap();
+        assert_eq!(matched.unwrap().value, registry_entry.value);
+
+        // a registry that the pool doesn't reference is ignored
+        let allowlists =
+            get_allowlists_with_aux(&pool, Pubkey::new_unique(), &registry, Pubkey::new_unique())
+                .unwrap();
+        assert_eq!(allowlists.len(), ALLOWLIST_MAX_LEN);
+    }
+
+    #[test]
+    fn test_valid_overflow_and_registry_entries() {
+        let value = Pubkey::new_unique();
+        assert!(allowlist(ALLOWLIST_KIND_MINT, value).valid_overflow_entry());
+        assert!(allowlist(ALLOWLIST_KIND_MINT, value).valid_registry_entry());
+        // the registry can't be resolved from the overflow allowlist or another registry
+        assert!(!allowlist(ALLOWLIST_KIND_REGISTRY, value).valid_overflow_entry());
+        assert!(!allowlist(ALLOWLIST_KIND_REGISTRY, value).valid_registry_entry());
+        // both are optional for the taker, so they can't deny or require
+        let deny = allowlist(ALLOWLIST_KIND_MINT | ALLOWLIST_FLAG_DENY, value);
+        let required = allowlist(ALLOWLIST_KIND_MINT | ALLOWLIST_FLAG_REQUIRED, value);
+        assert!(deny.valid() && !deny.valid_overflow_entry() && !deny.valid_registry_entry());
+        assert!(
+            required.valid()
+                && !required.valid_overflow_entry()
+                && !required.valid_registry_entry()
+        );
+    }
+
+    #[test]
+    fn test_check_metadata_commitment() {
+        let commitment =
+            |preimage: &str| Pubkey::new_from_array(keccak::hash(preimage.as_bytes()).to_bytes());
+        let update_authority = Pubkey::new_unique();
+        let fields = MetadataFields {
+            name: "Degen Ape #42",
+            // the symbol and the uri are padded with zeroes in the metadata account
+            symbol: "DAPE\0\0\0\0\0\0",
+            uri: "https://arweave.net/apes/42.json\0\0",
+            update_authority: Some(update_authority),
+        };
+        let aux = |prefix: &str| Some(prefix.to_string());
+
+        let uri_prefix = allowlist(
+            ALLOWLIST_KIND_URI_PREFIX_HASH,
+            commitment("https://arweave.net/apes/"),
+        );
+        assert!(check_metadata_commitment(
+            &uri_prefix,
+            &fields,
+            &aux("https://arweave.net/apes/")
+        ));
+        // the prefix has to be passed in, and it has to hash to the committed value
+        assert!(!check_metadata_commitment(&uri_prefix, &fields, &None));
+        assert!(!check_metadata_commitment(
+            &uri_prefix,
+            &fields,
+            &aux("https://arweave.net/")
+        ));
+        let other_uri_prefix = allowlist(
+            ALLOWLIST_KIND_URI_PREFIX_HASH,
+            commitment("https://arweave.net/cats/"),
+        );
+        assert!(!check_metadata_commitment(
+            &other_uri_prefix,
+            &fields,
+            &aux("https://arweave.net/cats/")
+        ));
+
+        let name_prefix = allowlist(ALLOWLIST_KIND_NAME_PREFIX_HASH, commitment("Degen Ape #"));
+        assert!(check_metadata_commitment(
+            &name_prefix,
+            &fields,
+            &aux("Degen Ape #")
+        ));
+        assert!(!check_metadata_commitment(
+            &name_prefix,
+            &fields,
+            &aux("Degen")
+        ));
+
+        let symbol = allowlist(ALLOWLIST_KIND_SYMBOL_HASH, commitment("DAPE"));
+        assert!(check_metadata_commitment(&symbol, &fields, &None));
+        assert!(!check_metadata_commitment(
+            &allowlist(ALLOWLIST_KIND_SYMBOL_HASH, commitment("DAP")),
+            &fields,
+            &None
+        ));
+
+        let authority = allowlist(ALLOWLIST_KIND_UPDATE_AUTHORITY, update_authority);
+        assert!(check_metadata_commitment(&authority, &fields, &None));
+        assert!(!check_metadata_commitment(
+            &allowlist(ALLOWLIST_KIND_UPDATE_AUTHORITY, Pubkey::new_unique()),
+            &fields,
+            &None
+        ));
+        // e.g. the compressed nfts without a verifiable update authority
+        let unverified_fields = MetadataFields {
+            update_authority: None,
+            ..fields
+        };
+        assert!(!check_metadata_commitment(
+            &authority,
+            &unverified_fields,
+            &None
+        ));
+    }
+
+    #[test]
+    fn test_check_allowlists_for_metadata_commitments() {
+        let mut metadata = Metadata {
+            update_authority: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        metadata.data.symbol = "DAPE".to_string();
+        let mint = Pubkey::new_unique();
+        let symbol = allowlist(
+            ALLOWLIST_KIND_SYMBOL_HASH,
+            Pubkey::new_from_array(keccak::hash(b"DAPE").to_bytes()),
+        );
+        let authority = allowlist(ALLOWLIST_KIND_UPDATE_AUTHORITY, metadata.update_authority);
+
+        let matched =
+            check_allowlists_for_metadata(&[symbol], &mint, &metadata, None, &[], None).unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_SYMBOL_HASH);
+        let matched =
+            check_allowlists_for_metadata(&[authority], &mint, &metadata, None, &[], None).unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_UPDATE_AUTHORITY);
+
+        metadata.data.symbol = "CAT".to_string();
+        metadata.update_authority = Pubkey::default();
+        assert!(check_allowlists_for_metadata(
+            &[symbol, authority],
+            &mint,
+            &metadata,
+            None,
+            &[],
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_allowlists_for_ext_mint_update_authority() {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token_2022::spl_token_2022;
+
+        // a token-2022 mint whose embedded metadata claims the update authority of a
+        // collection, which anyone can do without the signature of that authority
+        let (mint, update_authority) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let token_metadata = (
+            update_authority,
+            mint,
+            "spoofed".to_string(),
+            "DAPE".to_string(),
+            "https://example.com".to_string(),
+            Vec::<(String, String)>::new(),
+        )
+            .try_to_vec()
+            .unwrap();
+        let mut data = vec![0; spl_token_2022::state::Account::LEN];
+        data.push(1); // AccountType::Mint
+                      // MetadataPointer (authority, address) pointing back to the mint
+        data.extend_from_slice(&18u16.to_le_bytes());
+        data.extend_from_slice(&64u16.to_le_bytes());
+        data.extend_from_slice(update_authority.as_ref());
+        data.extend_from_slice(mint.as_ref());
+        // TokenMetadata
+        data.extend_from_slice(&19u16.to_le_bytes());
+        data.extend_from_slice(&(token_metadata.len() as u16).to_le_bytes());
+        data.extend_from_slice(&token_metadata);
+        let mut lamports = 0;
+        let mint_info = AccountInfo::new(
+            &mint,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &spl_token_2022::ID,
+            false,
+            0,
+        );
+
+        let symbol = allowlist(
+            ALLOWLIST_KIND_SYMBOL_HASH,
+            Pubkey::new_from_array(keccak::hash(b"DAPE").to_bytes()),
+        );
+        let matched = check_allowlists_for_ext_mint(&[symbol], &mint_info, None).unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_SYMBOL_HASH);
+
+        // the claimed update authority never matches
+        let authority = allowlist(ALLOWLIST_KIND_UPDATE_AUTHORITY, update_authority);
+        assert!(check_allowlists_for_ext_mint(&[authority], &mint_info, None).is_err());
+        // and a denied update authority can't be dodged by claiming another one
+        let deny_authority = allowlist(
+            ALLOWLIST_KIND_UPDATE_AUTHORITY | ALLOWLIST_FLAG_DENY,
+            Pubkey::new_unique(),
+        );
+        assert!(
+            check_allowlists_for_ext_mint(&[symbol, deny_authority], &mint_info, None).is_err()
+        );
+    }
+
+    #[test]
+    fn test_check_allowlists_for_cnft() {
+        let creator = Pubkey::new_unique();
+        let mut metadata = Metadata::default();
+        metadata.data.creators = Some(vec![mpl_token_metadata::state::Creator {
+            address: creator,
+            verified: true,
+            share: 100,
+        }]);
+        let asset_id = Pubkey::new_unique();
+        let fvca = allowlist(ALLOWLIST_KIND_FVCA, creator);
+
+        let matched = check_allowlists_for_cnft(&[fvca], &asset_id, &metadata, None).unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_FVCA);
+
+        // the merkle and update authority entries can't be checked against a leaf, so
+        // they reject the compressed nfts whether they allow or deny
+        for kind in [ALLOWLIST_KIND_MERKLE, ALLOWLIST_KIND_UPDATE_AUTHORITY] {
+            for flags in [0, ALLOWLIST_FLAG_DENY, ALLOWLIST_FLAG_REQUIRED] {
+                let entry = allowlist(kind | flags, Pubkey::new_unique());
+                assert!(
+                    check_allowlists_for_cnft(&[fvca, entry], &asset_id, &metadata, None).is_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_allowlist_matches_composition() {
+        let (fvca, mcc, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        // the asset is verified by the fvca and the mcc, but not by the mint entries
+        let check = |allowlists: &[Allowlist]| {
+            check_allowlist_matches(allowlists, |allowlist_val| {
+                Ok(match allowlist_val.base_kind() {
+                    ALLOWLIST_KIND_FVCA => allowlist_val.value == fvca,
+                    ALLOWLIST_KIND_MCC => allowlist_val.value == mcc,
+                    _ => false,
+                })
+            })
+        };
+
+        // the union entries
+        let matched = check(&[
+            allowlist(ALLOWLIST_KIND_MINT, mint),
+            allowlist(ALLOWLIST_KIND_MCC, mcc),
+        ])
+        .unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_MCC);
+        assert!(check(&[allowlist(ALLOWLIST_KIND_MINT, mint)]).is_err());
+        assert!(check(&[Allowlist::default()]).is_err());
+
+        // a matching deny entry rejects the asset even if a union entry matches
+        let deny_fvca = allowlist(ALLOWLIST_KIND_FVCA | ALLOWLIST_FLAG_DENY, fvca);
+        assert!(check(&[allowlist(ALLOWLIST_KIND_MCC, mcc), deny_fvca]).is_err());
+        let deny_mint = allowlist(ALLOWLIST_KIND_MINT | ALLOWLIST_FLAG_DENY, mint);
+        let matched = check(&[allowlist(ALLOWLIST_KIND_MCC, mcc), deny_mint]).unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_MCC);
+        // deny entries alone don't allow anything
+        assert!(check(&[deny_mint]).is_err());
+
+        // the required entries have to match next to one of the union entries
+        let required_fvca = allowlist(ALLOWLIST_KIND_FVCA | ALLOWLIST_FLAG_REQUIRED, fvca);
+        let required_mint = allowlist(ALLOWLIST_KIND_MINT | ALLOWLIST_FLAG_REQUIRED, mint);
+        let matched = check(&[required_fvca, allowlist(ALLOWLIST_KIND_MCC, mcc)]).unwrap();
+        assert_eq!(matched.unwrap().kind, ALLOWLIST_KIND_MCC);
+        assert!(check(&[required_mint, allowlist(ALLOWLIST_KIND_MCC, mcc)]).is_err());
+        assert!(check(&[required_fvca, allowlist(ALLOWLIST_KIND_MINT, mint)]).is_err());
+        // the required entries alone are enough, but there is no union entry to return
+        assert!(check(&[required_fvca]).unwrap().is_none());
+        assert!(check(&[required_fvca, deny_fvca]).is_err());
+    }
+
+    // builds the merkle root and the proof of the first leaf over the sorted pairs
+    fn get_merkle_root_and_proof(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<[u8; 32]>) {
+        let hash_pair = |a: &[u8; 32], b: &[u8; 32]| {
+            let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+            keccak::hashv(&[&[0x01], lo, hi]).to_bytes()
+        };
+        let (mut level, mut index, mut proof) = (leaves.to_vec(), 0, vec![]);
+        while level.len() > 1 {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            index /= 2;
+        }
+        (level[0], proof)
+    }
+
+    #[test]
+    fn test_merkle_allowlist_and_denylist() {
+        let mint = Pubkey::new_unique();
+        let others: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let mut leaves = vec![get_merkle_leaf(&mint)];
+        leaves.extend(others.iter().map(get_merkle_leaf));
+        let (root, proof) = get_merkle_root_and_proof(&leaves);
+        assert!(verify_merkle_proof(&proof, &root, get_merkle_leaf(&mint)));
+        assert!(!verify_merkle_proof(
+            &proof,
+            &root,
+            get_merkle_leaf(&others[0])
+        ));
+        assert!(!verify_merkle_proof(
+            &proof[1..],
+            &root,
+            get_merkle_leaf(&mint)
+        ));
+        // a node can't be passed in as a leaf because of the domain separation
+        assert!(!verify_merkle_proof(&proof[1..], &root, proof[0]));
+
+        // the denylist of the other mints, the mint is in the range between two of them
+        let mut denied: Vec<[u8; 32]> = others.iter().map(|key| key.to_bytes()).collect();
+        denied.extend([[0; 32], [0xff; 32]]);
+        denied.sort();
+        let range = denied
+            .windows(2)
+            .position(|w| w[0] < mint.to_bytes() && mint.to_bytes() < w[1])
+            .unwrap();
+        let mut ranges: Vec<[u8; 32]> = denied
+            .windows(2)
+            .map(|w| get_merkle_range_leaf(&w[0], &w[1]))
+            .collect();
+        ranges.swap(0, range);
+        let (deny_root, nodes) = get_merkle_root_and_proof(&ranges);
+        let non_membership = [vec![denied[range], denied[range + 1]], nodes.clone()].concat();
+        assert!(verify_merkle_non_membership(
+            &non_membership,
+            &deny_root,
+            &mint
+        ));
+        // the denied mints are never strictly inside of a range
+        for other in others.iter() {
+            assert!(!verify_merkle_non_membership(
+                &non_membership,
+                &deny_root,
+                other
+            ));
+        }
+        // a range that isn't committed to
+        let forged = [vec![[0; 32], [0xff; 32]], nodes].concat();
+        assert!(!verify_merkle_non_membership(&forged, &deny_root, &mint));
+        assert!(!verify_merkle_non_membership(&[], &deny_root, &mint));
+
+        let metadata = Metadata::default();
+        let check = |allowlists: &[Allowlist], proof: &[[u8; 32]], mint: &Pubkey| {
+            check_allowlists_for_metadata(allowlists, mint, &metadata, None, proof, None)
+        };
+        let merkle = allowlist(ALLOWLIST_KIND_MERKLE, Pubkey::new_from_array(root));
+        assert!(check(&[merkle], &proof, &mint).unwrap().is_some());
+        assert!(check(&[merkle], &proof, &others[0]).is_err());
+        // the deny merkle entry is composed with the union entries
+        let deny_merkle = allowlist(
+            ALLOWLIST_KIND_MERKLE | ALLOWLIST_FLAG_DENY,
+            Pubkey::new_from_array(deny_root),
+        );
+        let mint_entry = |key: &Pubkey| allowlist(ALLOWLIST_KIND_MINT, *key);
+        assert!(
+            check(&[deny_merkle, mint_entry(&mint)], &non_membership, &mint)
+                .unwrap()
+                .is_some()
+        );
+        assert!(check(
+            &[deny_merkle, mint_entry(&others[0])],
+            &non_membership,
+            &others[0]
+        )
+        .is_err());
+        // without the proof the mint is treated as denied
+        assert!(check(&[deny_merkle, mint_entry(&mint)], &[], &mint).is_err());
+    }
+
+    #[test]
+    fn test_check_allowlists_merkle_composition() {
+        let (root, other_root) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let merkle = allowlist(ALLOWLIST_KIND_MERKLE, root);
+        let deny_merkle = allowlist(ALLOWLIST_KIND_MERKLE | ALLOWLIST_FLAG_DENY, other_root);
+        let required_merkle =
+            allowlist(ALLOWLIST_KIND_MERKLE | ALLOWLIST_FLAG_REQUIRED, other_root);
+        let mint = allowlist(ALLOWLIST_KIND_MINT, Pubkey::new_unique());
+
+        assert!(check_allowlists(&[merkle, allowlist(ALLOWLIST_KIND_MERKLE, other_root)]).is_ok());
+        assert!(check_allowlists(&[deny_merkle, mint]).is_ok());
+        assert!(check_allowlists(&[required_merkle, mint]).is_ok());
+        // the merkle entries would have to share the proof
+        assert!(check_allowlists(&[merkle, deny_merkle]).is_err());
+        assert!(check_allowlists(&[merkle, required_merkle]).is_err());
+        // both flags at once aren't valid
+        assert!(check_allowlists(&[allowlist(
+            ALLOWLIST_KIND_MINT | ALLOWLIST_FLAGS,
+            Pubkey::new_unique()
+        )])
+        .is_err());
+    }
+
+    #[test]
+    fn test_edition_allowlist() {
+        let master_mint = Pubkey::new_unique();
+        let master_edition = find_master_edition_account(&master_mint).0;
+        let other_master_edition = find_master_edition_account(&Pubkey::new_unique()).0;
+
+        // the value is either the mint or the master edition
+        let by_mint = allowlist(ALLOWLIST_KIND_EDITION, master_mint);
+        let by_master_edition = allowlist(ALLOWLIST_KIND_EDITION, master_edition);
+        assert!(check_edition_parent(&by_mint, &master_edition));
+        assert!(check_edition_parent(&by_master_edition, &master_edition));
+        assert!(!check_edition_parent(&by_mint, &other_master_edition));
+        assert!(!check_edition_parent(&by_master_edition, &master_mint));
+
+        let metadata = Metadata::default();
+        let mint = Pubkey::new_unique();
+        let check = |allowlists: &[Allowlist], parent: Option<&Pubkey>| {
+            check_allowlists_for_metadata(allowlists, &mint, &metadata, None, &[], parent)
+        };
+        assert!(check(&[by_mint], Some(&master_edition)).unwrap().is_some());
+        assert!(check(&[by_mint], Some(&other_master_edition)).is_err());
+        // the master edition itself isn't a print of it
+        assert!(check(&[by_mint], None).is_err());
+
+        // the prints of a master edition can be denied next to the other entries
+        let deny_prints = allowlist(ALLOWLIST_KIND_EDITION | ALLOWLIST_FLAG_DENY, master_mint);
+        assert!(deny_prints.valid());
+        let mint_entry = allowlist(ALLOWLIST_KIND_MINT, mint);
+        assert!(check(&[deny_prints, mint_entry], Some(&master_edition)).is_err());
+        assert!(
+            check(&[deny_prints, mint_entry], Some(&other_master_edition))
+                .unwrap()
+                .is_some()
+        );
+        assert!(check(&[deny_prints, mint_entry], None).unwrap().is_some());
+        assert!(!allowlist(ALLOWLIST_KIND_EDITION + 1, master_mint).valid());
+    }
+
+    #[test]
+    fn test_allowlist_price_multiplier() {
+        let (fvca, mcc) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut pool = Pool {
+            curve_type: CURVE_KIND_LINEAR,
+            spot_price: 1_000_000_000,
+            curve_delta: 100_000_000,
+            ..Default::default()
+        };
+        pool.allowlists[0] = allowlist(ALLOWLIST_KIND_FVCA, fvca);
+        pool.allowlists[1] = allowlist(ALLOWLIST_KIND_MCC, mcc);
+        pool.allowlist_price_multipliers_bp[0] = 15000;
+
+        let (aux_key, mut aux_lamports, mut aux_data) = (Pubkey::new_unique(), 0, vec![]);
+        let aux = AccountInfo::new(
+            &aux_key,
+            false,
+            false,
+            &mut aux_lamports,
+            &mut aux_data,
+            &aux_key,
+            false,
+            0,
+        );
+        let multiplier_bp = get_allowlist_multiplier_bp(&pool, Some(pool.allowlists[0]), &aux);
+        assert_eq!(multiplier_bp, 15000);
+        // a stored 0 and no match at all keep the price
+        assert_eq!(
+            get_allowlist_multiplier_bp(&pool, Some(pool.allowlists[1]), &aux),
+            10000
+        );
+        assert_eq!(get_allowlist_multiplier_bp(&pool, None, &aux), 10000);
+
+        // the pool buys 2 units at 1 and 0.9 SOL, and sells them at 1.1 and 1.2 SOL,
+        // the next prices are not scaled as they're stored in the pool
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 2, true, multiplier_bp).unwrap(),
+            (2_850_000_000, 800_000_000)
+        );
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 2, false, multiplier_bp).unwrap(),
+            (3_450_000_000, 1_200_000_000)
+        );
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 2, true, 10000).unwrap(),
+            (1_900_000_000, 800_000_000)
+        );
+    }
+
+    #[test]
+    fn test_allowlist_price_multiplier_two_tiers() {
+        let pool_key = Pubkey::new_unique();
+        let (registry_key, fvca, mcc) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut pool = Pool::default();
+        pool.allowlists[0] = allowlist(ALLOWLIST_KIND_REGISTRY, registry_key);
+        pool.allowlists[1] = allowlist(ALLOWLIST_KIND_FVCA, fvca);
+        pool.allowlist_price_multipliers_bp[0] = 8000;
+        pool.allowlist_price_multipliers_bp[1] = 15000;
+        let registry = AllowlistRegistry {
+            entries: vec![allowlist(ALLOWLIST_KIND_MCC, mcc)],
+            ..Default::default()
+        };
+
+        let (mut aux_lamports, mut aux_data) = (0, vec![]);
+        let aux = AccountInfo::new(
+            &registry_key,
+            false,
+            false,
+            &mut aux_lamports,
+            &mut aux_data,
+            &registry_key,
+            false,
+            0,
+        );
+        // the multiplier of the asset with the matched values, resolved with or
+        // without the registry as the allowlist_aux_account
+        let multiplier_bp = |values: &[Pubkey], aux_key: Pubkey| {
+            let allowlists = get_allowlists_with_aux(&pool, pool_key, &registry, aux_key).unwrap();
+            let allowlist_match = check_allowlist_matches(&allowlists, |allowlist_val| {
+                Ok(values.contains(&allowlist_val.value))
+            })
+            .unwrap();
+            get_allowlist_multiplier_bp(&pool, allowlist_match, &aux)
+        };
+
+        // the asset of both tiers is priced by the entry of the pool either way
+        assert_eq!(multiplier_bp(&[fvca, mcc], registry_key), 15000);
+        assert_eq!(multiplier_bp(&[fvca, mcc], Pubkey::new_unique()), 15000);
+        // and the asset that's only listed by the registry by the registry tier
+        assert_eq!(multiplier_bp(&[mcc], registry_key), 8000);
+
+        // the taker can't pick the tier by another registry or the overflow allowlist
+        let mut allowlists = pool.allowlists;
+        allowlists[2] = allowlist(ALLOWLIST_KIND_REGISTRY, Pubkey::new_unique());
+        assert!(check_allowlists(&allowlists).is_err());
+        let overflow_allowlist = OverflowAllowlist {
+            pool: pool_key,
+            allowlists: vec![allowlist(ALLOWLIST_KIND_MCC, mcc)],
+        };
+        assert!(get_allowlists_with_aux(
+            &pool,
+            pool_key,
+            &overflow_allowlist,
+            Pubkey::new_unique()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_constant_product_curve() {
+        // 10 SOL of payment reserve, no assets and a virtual asset reserve of 2
+        let mut pool = Pool {
+            curve_type: CURVE_KIND_CONSTANT_PRODUCT,
+            curve_delta: 2,
+            buyside_payment_amount: 10_000_000_000,
+            ..Default::default()
+        };
+        refresh_constant_product_spot_price(&mut pool).unwrap();
+        assert_eq!(pool.spot_price, 5_000_000_000);
+
+        // the pool buys 1 unit for x / (y + 1), and k never goes down
+        let (total_price, next_price) =
+            get_sol_total_price_and_next_price(&pool, 1, true, 10000).unwrap();
+        assert_eq!((total_price, next_price), (3_333_333_333, 2_222_222_222));
+        let x_after = (pool.buyside_payment_amount - total_price) as u128;
+        assert!(x_after * 3 >= pool.buyside_payment_amount as u128 * 2);
+
+        // the deposits and withdrawals move the cached spot price with the reserves
+        pool.sellside_asset_amount = 3;
+        refresh_constant_product_spot_price(&mut pool).unwrap();
+        assert_eq!(pool.spot_price, 2_000_000_000);
+        // the pool sells 1 unit for x / (y - 1) rounded up
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 1, false, 10000).unwrap(),
+            (2_500_000_000, 3_125_000_000)
+        );
+        // the virtual asset reserve can't be sold
+        assert!(get_sol_total_price_and_next_price(&pool, 5, false, 10000).is_err());
+
+        // a pool without a payment reserve can't price the sales
+        pool.buyside_payment_amount = 0;
+        refresh_constant_product_spot_price(&mut pool).unwrap();
+        assert_eq!(pool.spot_price, 0);
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 1, false, 10000).unwrap_err(),
+            MMMErrorCode::InvalidSpotPrice.into()
+        );
+        assert!(get_sol_total_price_and_next_price(&pool, 1, true, 10000).is_err());
+
+        // the other curves keep their spot price
+        let mut linear_pool = Pool {
+            spot_price: 1,
+            buyside_payment_amount: 10,
+            ..Default::default()
+        };
+        refresh_constant_product_spot_price(&mut linear_pool).unwrap();
+        assert_eq!(linear_pool.spot_price, 1);
+    }
+
+    #[test]
+    fn test_reverse_curves() {
+        let pool = |curve_type: u8, spot_price: u64, curve_delta: u64| Pool {
+            curve_type,
+            spot_price,
+            curve_delta,
+            ..Default::default()
+        };
+        let price = |pool: &Pool, n: u64, fulfill_buy: bool| {
+            get_sol_total_price_and_next_price(pool, n, fulfill_buy, 10000)
+        };
+
+        // reverse linear: the pool buys up from the spot price, i.e. 1, 1.1, 1.2
+        let reverse_linear = pool(CURVE_KIND_REVERSE_LINEAR, 1_000_000_000, 100_000_000);
+        assert_eq!(
+            price(&reverse_linear, 3, true).unwrap(),
+            (3_300_000_000, 1_300_000_000)
+        );
+        // and it sells down one step away from the spot price, i.e. 0.9, 0.8, 0.7
+        assert_eq!(
+            price(&reverse_linear, 3, false).unwrap(),
+            (2_400_000_000, 700_000_000)
+        );
+        // the sell side walks down to 0 and not past it
+        assert_eq!(
+            price(&reverse_linear, 10, false).unwrap(),
+            (4_500_000_000, 0)
+        );
+        assert_eq!(
+            price(&reverse_linear, 11, false).unwrap_err(),
+            MMMErrorCode::NumericOverflow.into()
+        );
+        // so a pool at the floor can't sell
+        let floor = pool(CURVE_KIND_REVERSE_LINEAR, 100_000_000, 100_000_000);
+        assert_eq!(
+            price(&floor, 1, false).unwrap_err(),
+            MMMErrorCode::NumericOverflow.into()
+        );
+        assert_eq!(price(&floor, 1, true).unwrap(), (100_000_000, 200_000_000));
+        // the buy side overflows the u64 and MAX_TOTAL_PRICE
+        assert!(price(&pool(CURVE_KIND_REVERSE_LINEAR, u64::MAX - 1, 1), 2, true).is_err());
+        let max = pool(CURVE_KIND_REVERSE_LINEAR, MAX_TOTAL_PRICE, 1);
+        assert_eq!(
+            price(&max, 1, true).unwrap(),
+            (MAX_TOTAL_PRICE, MAX_TOTAL_PRICE + 1)
+        );
+        assert_eq!(
+            price(&max, 2, true).unwrap_err(),
+            MMMErrorCode::NumericOverflow.into()
+        );
+
+        // reverse exp: the pool buys up by 10% from the spot price, i.e. 1, 1.1, 1.21
+        let reverse_exp = pool(CURVE_KIND_REVERSE_EXP, 1_000_000_000, 1000);
+        assert_eq!(
+            price(&reverse_exp, 3, true).unwrap(),
+            (3_310_000_000, 1_331_000_000)
+        );
+        // and it sells down by 10% one step away from the spot price, so the sales
+        // walk the buys back, i.e. 1.21, 1.1, 1
+        let reverse_exp = pool(CURVE_KIND_REVERSE_EXP, 1_331_000_000, 1000);
+        assert_eq!(
+            price(&reverse_exp, 3, false).unwrap(),
+            (3_310_000_000, 1_000_000_000)
+        );
+        // the sell side is floored, i.e. 5, 2, 1 from 10 at 50%, and the floor of 1
+        // is 0, so a pool at 1 can't sell
+        let floor = pool(CURVE_KIND_REVERSE_EXP, 10, 10000);
+        assert_eq!(price(&floor, 3, false).unwrap(), (8, 1));
+        assert_eq!(
+            price(&pool(CURVE_KIND_REVERSE_EXP, 1, 10000), 1, false).unwrap_err(),
+            MMMErrorCode::NumericOverflow.into()
+        );
+        // the buy side doubles at 100%, and overflows MAX_TOTAL_PRICE
+        let max = pool(CURVE_KIND_REVERSE_EXP, MAX_TOTAL_PRICE / 4, 10000);
+        assert_eq!(
+            price(&max, 2, true).unwrap(),
+            (MAX_TOTAL_PRICE / 4 * 3, MAX_TOTAL_PRICE)
+        );
+        assert_eq!(
+            price(&max, 3, true).unwrap_err(),
+            MMMErrorCode::NumericOverflow.into()
+        );
+        assert!(price(&pool(CURVE_KIND_REVERSE_EXP, u64::MAX / 2, 10000), 2, true).is_err());
+
+        // the allowlist multiplier scales the total price, and not the next price
+        assert_eq!(
+            get_sol_total_price_and_next_price(&reverse_exp, 3, false, 15000).unwrap(),
+            (4_965_000_000, 1_000_000_000)
+        );
+    }
+
+    fn price_ladder(steps: &[(u64, u64)]) -> [PriceLadderStep; PRICE_LADDER_MAX_LEN] {
+        let mut price_ladder = [PriceLadderStep::default(); PRICE_LADDER_MAX_LEN];
+        for (step, (price, amount)) in price_ladder.iter_mut().zip(steps) {
+            *step = PriceLadderStep {
+                price: *price,
+                amount: *amount,
+            };
+        }
+        price_ladder
+    }
+
+    #[test]
+    fn test_set_price_ladder() {
+        let mut pool = Pool {
+            curve_type: CURVE_KIND_PRICE_LADDER,
+            spot_price: 42,
+            ..Default::default()
+        };
+        // the ladder curve needs at least one step, and no gaps between the steps
+        assert!(set_price_ladder(&mut pool, Some(price_ladder(&[]))).is_err());
+        assert!(set_price_ladder(&mut pool, Some(price_ladder(&[(100, 1), (0, 1)]))).is_err());
+        assert!(
+            set_price_ladder(&mut pool, Some(price_ladder(&[(100, 1), (0, 0), (80, 1)]))).is_err()
+        );
+        // the empty ladder is fine for the other curves
+        pool.curve_type = CURVE_KIND_LINEAR;
+        set_price_ladder(&mut pool, Some(price_ladder(&[]))).unwrap();
+        assert_eq!(pool.spot_price, 42);
+
+        pool.curve_type = CURVE_KIND_PRICE_LADDER;
+        set_price_ladder(&mut pool, Some(price_ladder(&[(100, 2), (80, 1), (50, 0)]))).unwrap();
+        assert_eq!((pool.spot_price, pool.price_ladder_position), (100, 0));
+
+        // the pool buys 3 units at 100, 100 and 80, and the next unit is at 50
+        let (total_price, next_price) =
+            get_sol_total_price_and_next_price(&pool, 3, true, 10000).unwrap();
+        assert_eq!((total_price, next_price), (280, 50));
+        advance_curve(&mut pool, 3, true).unwrap();
+        pool.spot_price = next_price;
+        assert_eq!(pool.price_ladder_position, 3);
+        // the last step lasts forever
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 2, true, 10000).unwrap(),
+            (100, 50)
+        );
+
+        // the pool sells back at the prices that it bought, and beyond the start of
+        // the ladder at the first step
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 2, false, 10000).unwrap(),
+            (180, 100)
+        );
+        assert_eq!(
+            get_sol_total_price_and_next_price(&pool, 5, false, 10000).unwrap(),
+            (480, 100)
+        );
+        advance_curve(&mut pool, 5, false).unwrap();
+        assert_eq!(pool.price_ladder_position, 0);
+        advance_curve(&mut pool, 2, true).unwrap();
+
+        // the spot_price set by the owner or the operator is only a cache of the ladder
+        pool.spot_price = 1;
+        set_price_ladder(&mut pool, None).unwrap();
+        assert_eq!((pool.spot_price, pool.price_ladder_position), (80, 2));
+        // a new ladder restarts from the first step
+        set_price_ladder(&mut pool, Some(price_ladder(&[(90, 1), (70, 1)]))).unwrap();
+        assert_eq!((pool.spot_price, pool.price_ladder_position), (90, 0));
+    }
+
+    // the syscall stub of the clock sysvar, for reset_dutch_auction
+    struct ClockStub(i64);
+
+    impl anchor_lang::solana_program::program_stubs::SyscallStubs for ClockStub {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                unix_timestamp: self.0,
+                ..Default::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    fn set_clock(unix_timestamp: i64) {
+        anchor_lang::solana_program::program_stubs::set_syscall_stubs(Box::new(ClockStub(
+            unix_timestamp,
+        )));
+    }
+
+    #[test]
+    fn test_dutch_auction() {
+        let mut pool = Pool {
+            curve_type: CURVE_KIND_DUTCH_AUCTION,
+            spot_price: 10_000,
+            curve_delta: 100,
+            dutch_auction: DutchAuction {
+                floor: 4_000,
+                decay_per_second: 10,
+            },
+            ..Default::default()
+        };
+        set_clock(1_000);
+        reset_dutch_auction(&mut pool).unwrap();
+        assert_eq!(pool.dutch_auction_started_at, 1_000);
+
+        // the ask decays over time, and the units are priced from the ask up by delta
+        let prices_at = |pool: &Pool, now| {
+            get_dutch_auction_sell_total_price_and_next_price(pool, 2, now).unwrap()
+        };
+        assert_eq!(prices_at(&pool, 1_000), (20_100, 10_200));
+        assert_eq!(prices_at(&pool, 1_300), (14_100, 7_200));
+        // but it never goes below the floor
+        assert_eq!(prices_at(&pool, 1_600), (8_100, 4_200));
+        assert_eq!(prices_at(&pool, i64::MAX), (8_100, 4_200));
+        // e.g. the clock of the validator is behind the auction start
+        assert_eq!(prices_at(&pool, 900), (20_100, 10_200));
+        // the dutch auction only sells
+        assert!(get_sol_total_price_and_next_price(&pool, 1, true, 10000).is_err());
+
+        // the auction restarts after each sale, and whenever the spot_price is set
+        set_clock(1_300);
+        pool.spot_price = 7_200;
+        advance_curve(&mut pool, 2, false).unwrap();
+        assert_eq!(pool.dutch_auction_started_at, 1_300);
+        assert_eq!(prices_at(&pool, 1_300), (14_500, 7_400));
+        set_clock(1_500);
+        pool.spot_price = 20_000;
+        reset_dutch_auction(&mut pool).unwrap();
+        assert_eq!(pool.dutch_auction_started_at, 1_500);
+
+        // the floor has to be positive and at most the spot_price
+        pool.dutch_auction.floor = 0;
+        assert!(reset_dutch_auction(&mut pool).is_err());
+        pool.dutch_auction.floor = 20_001;
+        assert!(reset_dutch_auction(&mut pool).is_err());
+
+        // the other curves don't have an auction to restart
+        pool.curve_type = CURVE_KIND_LINEAR;
+        set_clock(2_000);
+        reset_dutch_auction(&mut pool).unwrap();
+        assert_eq!(pool.dutch_auction_started_at, 1_500);
+    }
+
+    // the per unit loop that the closed form asymmetric curve replaced
+    fn get_asymmetric_total_price_and_next_price_by_loop(
+        pool: &Pool,
+        n: u64,
+        fulfill_buy: bool,
+    ) -> Result<(u64, u64)> {
+        let buy_up = matches!(
+            pool.curve_type,
+            CURVE_KIND_REVERSE_LINEAR | CURVE_KIND_REVERSE_EXP
+        );
+        let buy_side = if buy_up {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Less
+        };
+        let up = buy_up == fulfill_buy;
+        let anchor = pool.curve_anchor_price;
+        let mut total_price: u64 = 0;
+        let mut price = pool.spot_price;
+        for _ in 0..n {
+            if fulfill_buy {
+                total_price = total_price
+                    .checked_add(price)
+                    .ok_or(MMMErrorCode::NumericOverflow)?;
+            }
+            let on_buy_side = match price.cmp(&anchor) {
+                std::cmp::Ordering::Equal => fulfill_buy,
+                side => side == buy_side,
+            };
+            let delta = if on_buy_side {
+                pool.curve_delta
+            } else {
+                pool.sell_curve_delta
+            };
+            price = get_curve_segment(pool.curve_type, price, delta, up, 1)?.1;
+            if on_buy_side != fulfill_buy {
+                price = if up {
+                    price.min(anchor)
+                } else {
+                    price.max(anchor)
+                };
+            }
+            if !fulfill_buy {
+                total_price = total_price
+                    .checked_add(price)
+                    .ok_or(MMMErrorCode::NumericOverflow)?;
+            }
+        }
+        Ok((total_price, price))
+    }
+
+    #[test]
+    fn test_asymmetric_closed_form_matches_loop() {
+        // the linear closed form matches the loop, and the exp one is never above it
+        let check = |curve_type, deltas: &[u64], prices: &[u64], anchor| {
+            for &curve_delta in deltas {
+                for &sell_curve_delta in deltas {
+                    for &spot_price in prices {
+                        let pool = Pool {
+                            curve_type,
+                            spot_price,
+                            curve_delta,
+                            sell_curve_delta,
+                            curve_anchor_price: anchor,
+                            asymmetric_curve_delta: true,
+                            ..Default::default()
+                        };
+                        for n in 0..=24 {
+                            for fulfill_buy in [true, false] {
+                                let closed = get_asymmetric_total_price_and_next_price(
+                                    &pool,
+                                    n,
+                                    fulfill_buy,
+                                )
+                                .ok();
+                                let by_loop = get_asymmetric_total_price_and_next_price_by_loop(
+                                    &pool,
+                                    n,
+                                    fulfill_buy,
+                                )
+                                .ok();
+                                let matches = match (closed, by_loop) {
+                                    (Some(closed), Some(by_loop))
+                                        if curve_type == CURVE_KIND_EXP
+                                            || curve_type == CURVE_KIND_REVERSE_EXP =>
+                                    {
+                                        closed.0 <= by_loop.0 && closed.1 <= by_loop.1
+                                    }
+                                    _ => closed == by_loop,
+                                };
+                                assert!(
+                                    matches,
+                                    "curve_type={} delta={} sell_delta={} p={} n={} buy={}: \
+                                     {:?} vs {:?}",
+                                    curve_type,
+                                    curve_delta,
+                                    sell_curve_delta,
+                                    spot_price,
+                                    n,
+                                    fulfill_buy,
+                                    closed,
+                                    by_loop
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+        let prices = [1, 500, 997, 1_000, 1_003, 1_040, 2_000, 1_000_000];
+        for curve_type in [CURVE_KIND_LINEAR, CURVE_KIND_REVERSE_LINEAR] {
+            check(curve_type, &[0, 1, 3, 40, 999, 1_000], &prices, 1_000);
+        }
+        let prices = [
+            1,
+            999,
+            500_000,
+            999_000,
+            1_000_000,
+            1_001_000,
+            2_000_000,
+            1 << 40,
+        ];
+        for curve_type in [CURVE_KIND_EXP, CURVE_KIND_REVERSE_EXP] {
+            check(curve_type, &[0, 1, 50, 500, 10000], &prices, 1_000_000);
+        }
+    }
+
+    // the pools of the price fixtures that the sdk is checked against, see
+    // tests/fixtures/prices.json. Set UPDATE_PRICE_FIXTURES to regenerate them.
+    fn get_price_fixture_pools() -> Vec<Pool> {
+        let pool = |curve_type, spot_price, curve_delta| Pool {
+            curve_type,
+            spot_price,
+            curve_delta,
+            ..Default::default()
+        };
+        let asymmetric = |curve_type, spot_price, curve_delta, sell_curve_delta, anchor| Pool {
+            sell_curve_delta,
+            curve_anchor_price: anchor,
+            asymmetric_curve_delta: true,
+            ..pool(curve_type, spot_price, curve_delta)
+        };
+        let constant_product = |buyside_payment_amount, sellside_asset_amount, curve_delta| Pool {
+            buyside_payment_amount,
+            sellside_asset_amount,
+            ..pool(CURVE_KIND_CONSTANT_PRODUCT, 0, curve_delta)
+        };
+        vec![
+            pool(CURVE_KIND_LINEAR, 1_000_000_000, 100_000_000),
+            pool(CURVE_KIND_LINEAR, 1_000_000_000, 0),
+            pool(CURVE_KIND_REVERSE_LINEAR, 500_000_000, 30_000_000),
+            pool(CURVE_KIND_EXP, 1_000_000_000, 500),
+            pool(CURVE_KIND_EXP, 123_456_789, 1),
+            pool(CURVE_KIND_EXP, 7, 10000),
+            pool(CURVE_KIND_EXP, 3, 1),
+            pool(CURVE_KIND_EXP, 150, 77),
+            pool(CURVE_KIND_REVERSE_EXP, 9_999, 1),
+            pool(CURVE_KIND_REVERSE_EXP, 1_000_000_000, 2500),
+            pool(CURVE_KIND_REVERSE_EXP, u64::MAX / 2, 10000),
+            constant_product(10_000_000_000, 3, 2),
+            constant_product(0, 3, 2),
+            asymmetric(
+                CURVE_KIND_LINEAR,
+                1_000_000_000,
+                100_000_000,
+                50_000_000,
+                1_000_000_000,
+            ),
+            asymmetric(
+                CURVE_KIND_LINEAR,
+                1_200_000_000,
+                100_000_000,
+                50_000_000,
+                1_000_000_000,
+            ),
+            asymmetric(
+                CURVE_KIND_LINEAR,
+                850_000_000,
+                100_000_000,
+                50_000_000,
+                1_000_000_000,
+            ),
+            asymmetric(
+                CURVE_KIND_REVERSE_LINEAR,
+                1_000_000_000,
+                30_000_000,
+                10_000_000,
+                1_100_000_000,
+            ),
+            asymmetric(CURVE_KIND_EXP, 1_000_000_000, 500, 1000, 1_000_000_000),
+            asymmetric(CURVE_KIND_EXP, 1_300_000_000, 500, 1000, 1_000_000_000),
+            asymmetric(CURVE_KIND_EXP, 700_000_000, 300, 700, 1_000_000_000),
+            asymmetric(CURVE_KIND_EXP, 1_100_000_000, 500, 0, 1_000_000_000),
+            asymmetric(
+                CURVE_KIND_REVERSE_EXP,
+                1_000_000_000,
+                250,
+                50,
+                1_200_000_000,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_price_fixtures() {
+        let mut cases = vec![];
+        for pool in get_price_fixture_pools() {
+            for n in [1, 4, 25] {
+                for fulfill_buy in [true, false] {
+                    for allowlist_multiplier_bp in [10000, 15000] {
+                        if allowlist_multiplier_bp != 10000 && n != 4 {
+                            continue;
+                        }
+                        let mut fields = vec![
+                            (
+                                "pool",
+                                format!(
+                                    "{{ \"spotPrice\": \"{}\", \"curveType\": {}, \
+                                     \"curveDelta\": \"{}\", \"sellCurveDelta\": \"{}\", \
+                                     \"curveAnchorPrice\": \"{}\", \
+                                     \"asymmetricCurveDelta\": {}, \
+                                     \"buysidePaymentAmount\": \"{}\", \
+                                     \"sellsideAssetAmount\": \"{}\" }}",
+                                    pool.spot_price,
+                                    pool.curve_type,
+                                    pool.curve_delta,
+                                    pool.sell_curve_delta,
+                                    pool.curve_anchor_price,
+                                    pool.asymmetric_curve_delta,
+                                    pool.buyside_payment_amount,
+                                    pool.sellside_asset_amount,
+                                ),
+                            ),
+                            ("n", n.to_string()),
+                            ("fulfillBuy", fulfill_buy.to_string()),
+                            ("allowlistMultiplierBp", allowlist_multiplier_bp.to_string()),
+                        ];
+                        match get_sol_total_price_and_next_price(
+                            &pool,
+                            n,
+                            fulfill_buy,
+                            allowlist_multiplier_bp,
+                        ) {
+                            Ok((total_price, next_price)) => {
+                                fields.push(("totalPrice", format!("\"{}\"", total_price)));
+                                fields.push(("nextPrice", format!("\"{}\"", next_price)));
+                            }
+                            Err(Error::AnchorError(e)) => {
+                                fields.push(("error", format!("\"{}\"", e.error_name)));
+                            }
+                            Err(e) => panic!("{}", e),
+                        }
+                        let fields: Vec<String> = fields
+                            .iter()
+                            .map(|(key, value)| format!("    \"{}\": {}", key, value))
+                            .collect();
+                        cases.push(format!("  {{\n{}\n  }}", fields.join(",\n")));
+                    }
+                }
+            }
+        }
+        let fixtures = format!("[\n{}\n]\n", cases.join(",\n"));
+
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../tests/fixtures/prices.json"
+        );
+        if std::env::var("UPDATE_PRICE_FIXTURES").is_ok() {
+            std::fs::write(path, &fixtures).unwrap();
+        }
+        assert!(
+            std::fs::read_to_string(path).unwrap() == fixtures,
+            "the price fixtures are outdated, run the test with UPDATE_PRICE_FIXTURES=1"
+        );
+    }
+
+    #[test]
+    fn test_exp_closed_form_matches_loop() {
+        // check_exp_closed_form compares the closed form with the loop for 0..=64 units
+        // from p, until the loop overflows or is above MAX_TOTAL_PRICE
+        let check_exp_closed_form = |p: u64, num: u64, den: u64| {
+            // next_bound is at least 1 + r + ... + r^(n-1), and total_bound is the sum
+            // of the next_bound of 0..n-1 units
+            let (mut next_bound, mut total_bound): (u128, u128) = (0, 0);
+            let by_loop = get_exp_total_price_and_next_price_by_loop(p, num, den)
+                .take_while(|(loop_total, _)| *loop_total <= MAX_TOTAL_PRICE)
+                .take(65);
+            for (n, (loop_total, loop_next)) in (0..).zip(by_loop) {
+                let (total, next) = get_exp_total_price_and_next_price(p, num, den, n).unwrap();
+                assert!(
+                    total <= loop_total && (loop_total - total) as u128 <= total_bound,
+                    "total p={} num={} den={} n={}: {} vs {}",
+                    p,
+                    num,
+                    den,
+                    n,
+                    total,
+                    loop_total
+                );
+                assert!(
+                    next <= loop_next && (loop_next - next) as u128 <= next_bound,
+                    "next p={} num={} den={} n={}: {} vs {}",
+                    p,
+                    num,
+                    den,
+                    n,
+                    next,
+                    loop_next
+                );
+                total_bound += next_bound;
+                next_bound = (next_bound * num as u128).div_ceil(den as u128) + 1;
+            }
+        };
+
+        // the prices that aren't floored are exact, e.g. 2.1 * 1.05^k and 1 / 2^k
+        assert_eq!(
+            get_exp_total_price_and_next_price(2_100_000_000, 10500, 10000, 4).unwrap(),
+            (9_051_262_500, 2_552_563_125)
+        );
+        assert_eq!(
+            get_exp_total_price_and_next_price(1 << 40, 10000, 20000, 64).unwrap(),
+            ((1 << 41) - 1, 0)
+        );
+
+        // every small price with every small delta, where the flooring matters the most
+        for delta in 0..=100 {
+            for (num, den) in [(10000, delta + 10000), (delta + 10000, 10000)] {
+                for p in 0..=200 {
+                    check_exp_closed_form(p, num, den);
+                }
+            }
+        }
+        // and every delta with a range of prices
+        let prices = [
+            201,
+            1_001,
+            9_999,
+            65_537,
+            1_000_000_007,
+            123_456_789_012,
+            MAX_TOTAL_PRICE / 3,
+            MAX_TOTAL_PRICE,
+        ];
+        for delta in 0..=10000 {
+            for (num, den) in [(10000, delta + 10000), (delta + 10000, 10000)] {
+                for p in prices {
+                    check_exp_closed_form(p, num, den);
+                }
+            }
+        }
+    }
+
+    // the v0 layouts are encoded by hand from the fields before the version was added,
+    // so that the test doesn't depend on the current structs
+    #[test]
+    fn test_v0_accounts_deserialize() {
+        const V0_POOL_LEN: usize = 849;
+        const V0_SELL_STATE_LEN: usize = 344;
+
+        let owner = Pubkey::new_unique();
+        let cosigner = Pubkey::new_unique();
+        let uuid = Pubkey::new_unique();
+        let referral = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+        let asset_mint = Pubkey::new_unique();
+        let annotation = [7u8; 32];
+
+        let mut data = Pool::DISCRIMINATOR.to_vec();
+        data.extend(1_500_000_000u64.to_le_bytes()); // spot_price
+        data.push(CURVE_KIND_EXP); // curve_type
+        data.extend(300u64.to_le_bytes()); // curve_delta
+        data.extend([1u8, 0u8]); // reinvest_fulfill_buy, reinvest_fulfill_sell
+        data.extend(42i64.to_le_bytes()); // expiry
+        data.extend(150u16.to_le_bytes()); // lp_fee_bp
+        data.extend(referral.to_bytes());
+        data.extend(0u16.to_le_bytes()); // referral_bp
+        data.extend(500u16.to_le_bytes()); // buyside_creator_royalty_bp
+        data.extend(annotation);
+        data.extend(1u64.to_le_bytes()); // sellside_asset_amount
+        data.extend(12_345u64.to_le_bytes()); // lp_fee_earned
+        data.extend(owner.to_bytes());
+        data.extend(cosigner.to_bytes());
+        data.extend(uuid.to_bytes());
+        data.extend(Pubkey::default().to_bytes()); // payment_mint
+        data.push(ALLOWLIST_KIND_MCC);
+        data.extend(collection.to_bytes());
+        data.extend([0u8; 33 * (ALLOWLIST_MAX_LEN - 1)]);
+        data.extend(2_000_000_000u64.to_le_bytes()); // buyside_payment_amount
+        data.resize(V0_POOL_LEN, 0);
+        assert_eq!(Pool::LEN, V0_POOL_LEN);
+
+        let mut pool = Pool::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(pool.version, 0);
+        assert_eq!(pool.spot_price, 1_500_000_000);
+        assert_eq!(pool.curve_type, CURVE_KIND_EXP);
+        assert_eq!(pool.curve_delta, 300);
+        assert!(pool.reinvest_fulfill_buy);
+        assert!(!pool.reinvest_fulfill_sell);
+        assert_eq!(pool.expiry, 42);
+        assert_eq!(pool.lp_fee_bp, 150);
+        assert_eq!(pool.referral, referral);
+        assert_eq!(pool.buyside_creator_royalty_bp, 500);
+        assert_eq!(pool.cosigner_annotation, annotation);
+        assert_eq!(pool.sellside_asset_amount, 1);
+        assert_eq!(pool.lp_fee_earned, 12_345);
+        assert_eq!(pool.owner, owner);
+        assert_eq!(pool.seed_owner(), owner);
+        assert_eq!(pool.cosigner, cosigner);
+        assert_eq!(pool.uuid, uuid);
+        assert_eq!(pool.allowlists[0].kind, ALLOWLIST_KIND_MCC);
+        assert_eq!(pool.allowlists[0].value, collection);
+        assert!(pool.allowlists[1..].iter().all(|a| a.is_empty()));
+        assert_eq!(pool.buyside_payment_amount, 2_000_000_000);
+
+        // the fields added since v0 read as zero, which keeps the v0 behavior
+        let fresh = Pool {
+            spot_price: pool.spot_price,
+            curve_type: pool.curve_type,
+            curve_delta: pool.curve_delta,
+            reinvest_fulfill_buy: pool.reinvest_fulfill_buy,
+            reinvest_fulfill_sell: pool.reinvest_fulfill_sell,
+            expiry: pool.expiry,
+            lp_fee_bp: pool.lp_fee_bp,
+            referral: pool.referral,
+            referral_bp: pool.referral_bp,
+            buyside_creator_royalty_bp: pool.buyside_creator_royalty_bp,
+            cosigner_annotation: pool.cosigner_annotation,
+            sellside_asset_amount: pool.sellside_asset_amount,
+            lp_fee_earned: pool.lp_fee_earned,
+            owner: pool.owner,
+            cosigner: pool.cosigner,
+            uuid: pool.uuid,
+            payment_mint: pool.payment_mint,
+            allowlists: pool.allowlists,
+            buyside_payment_amount: pool.buyside_payment_amount,
+            ..Default::default()
+        };
+        let mut fresh_data = vec![0u8; Pool::LEN];
+        fresh.try_serialize(&mut &mut fresh_data[..]).unwrap();
+        assert_eq!(fresh_data, data);
+
+        // migrate_pool only sets the version, and it's idempotent
+        pool.version = POOL_VERSION;
+        let mut migrated = data.clone();
+        pool.try_serialize(&mut &mut migrated[..]).unwrap();
+        let changed: Vec<usize> = (0..data.len())
+            .filter(|&i| data[i] != migrated[i])
+            .collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(migrated[changed[0]], POOL_VERSION);
+        let pool = Pool::try_deserialize(&mut &migrated[..]).unwrap();
+        assert_eq!(pool.version, POOL_VERSION);
+        let mut remigrated = migrated.clone();
+        pool.try_serialize(&mut &mut remigrated[..]).unwrap();
+        assert_eq!(remigrated, migrated);
+
+        let pool_key = Pubkey::new_unique();
+        let mut data = SellState::DISCRIMINATOR.to_vec();
+        data.extend(pool_key.to_bytes());
+        data.extend(owner.to_bytes()); // pool_owner
+        data.extend(asset_mint.to_bytes());
+        data.extend(1u64.to_le_bytes()); // asset_amount
+        data.extend(annotation);
+        data.resize(V0_SELL_STATE_LEN, 0);
+        assert_eq!(SellState::LEN, V0_SELL_STATE_LEN);
+
+        let mut sell_state = SellState::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(sell_state.version, 0);
+        assert_eq!(sell_state.pool, pool_key);
+        assert_eq!(sell_state.pool_owner, owner);
+        assert_eq!(sell_state.asset_mint, asset_mint);
+        assert_eq!(sell_state.asset_amount, 1);
+        assert_eq!(sell_state.cosigner_annotation, annotation);
+
+        sell_state.version = SELL_STATE_VERSION;
+        let mut migrated = data.clone();
+        sell_state.try_serialize(&mut &mut migrated[..]).unwrap();
+        let changed: Vec<usize> = (0..data.len())
+            .filter(|&i| data[i] != migrated[i])
+            .collect();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(migrated[changed[0]], SELL_STATE_VERSION);
+    }
+}
@@ -2,11 +2,19 @@ export const PREFIXES = {
   POOL: 'mmm_pool',
   BUYSIDE_SOL_ESCROW: 'mmm_buyside_sol_escrow_account',
   SELL_STATE: 'mmm_sell_state',
+  GLOBAL_CONFIG: 'mmm_global_config',
+  ALLOWLIST_REGISTRY: 'mmm_allowlist_registry',
+  OVERFLOW_ALLOWLIST: 'mmm_overflow_allowlist',
 };
 
 export enum CurveKind {
   linear = 0,
   exp = 1,
+  reverseLinear = 2,
+  reverseExp = 3,
+  constantProduct = 4,
+  priceLadder = 5,
+  dutchAuction = 6,
 }
 
 export enum AllowlistKind {
@@ -15,4 +23,18 @@ export enum AllowlistKind {
   mint = 2,
   mcc = 3,
   metadata = 4,
+  merkle = 5,
+  group = 6,
+  registry = 7,
+  uriPrefixHash = 8,
+  namePrefixHash = 9,
+  symbolHash = 10,
+  updateAuthority = 11,
+  edition = 12,
 }
+
+// the flags are or-ed into the AllowlistKind of an entry, a deny entry rejects the
+// matching assets and a required entry has to match next to the other entries
+export const ALLOWLIST_FLAG_DENY = 0x80;
+export const ALLOWLIST_FLAG_REQUIRED = 0x40;
+
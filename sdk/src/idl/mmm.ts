@@ -20,6 +20,11 @@ export type Mmm = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -46,12 +51,17 @@ export type Mmm = {
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
@@ -64,38 +74,35 @@ export type Mmm = {
       ]
     },
     {
-      "name": "solClosePool",
+      "name": "updateAllowlists",
       "accounts": [
         {
           "name": "owner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateAllowlistsArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "solDepositBuy",
+      "name": "updateOverflowAllowlist",
       "accounts": [
         {
           "name": "owner",
@@ -105,15 +112,15 @@ export type Mmm = {
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "pool",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "buysideSolEscrowAccount",
+          "name": "overflowAllowlist",
           "isMut": true,
           "isSigner": false
         },
@@ -127,31 +134,21 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "SolDepositBuyArgs"
+            "defined": "UpdateOverflowAllowlistArgs"
           }
         }
       ]
     },
     {
-      "name": "solWithdrawBuy",
+      "name": "createAllowlistRegistry",
       "accounts": [
         {
-          "name": "owner",
+          "name": "curator",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
+          "name": "registry",
           "isMut": true,
           "isSigner": false
         },
@@ -165,102 +162,139 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "SolWithdrawBuyArgs"
+            "defined": "CreateAllowlistRegistryArgs"
           }
         }
       ]
     },
     {
-      "name": "solFulfillBuy",
+      "name": "updateAllowlistRegistry",
       "accounts": [
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "curator",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "owner",
+          "name": "registry",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "cosigner",
+          "name": "args",
+          "type": {
+            "defined": "UpdateAllowlistRegistryArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePoolCosigner",
+      "accounts": [
+        {
+          "name": "owner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "referral",
-          "isMut": true,
+          "name": "cosigner",
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "assetMetadata",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolCosignerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePoolOperator",
+      "accounts": [
         {
-          "name": "assetMasterEdition",
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "assetMint",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerAssetAccount",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolOperatorArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "operatorUpdatePool",
+      "accounts": [
         {
-          "name": "ownerTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "allowlistAuxAccount",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellState",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "globalConfig",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tokenProgram",
+          "name": "args",
+          "type": {
+            "defined": "OperatorUpdatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPoolPause",
+      "accounts": [
+        {
+          "name": "owner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "pool",
+          "isMut": true,
           "isSigner": false
         }
       ],
@@ -268,97 +302,96 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "SolFulfillBuyArgs"
+            "defined": "SetPoolPauseArgs"
           }
         }
       ]
     },
     {
-      "name": "solFulfillSell",
+      "name": "initGlobalConfig",
       "accounts": [
         {
-          "name": "payer",
+          "name": "admin",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "owner",
+          "name": "globalConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "cosigner",
+          "name": "treasury",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "so that the fees below the rent exempt minimum can still be transferred to it"
+          ]
         },
         {
-          "name": "assetMetadata",
+          "name": "program",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "assetMasterEdition",
+          "name": "programData",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "assetMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "payerAssetAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "args",
+          "type": {
+            "defined": "InitGlobalConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGlobalConfig",
+      "accounts": [
         {
-          "name": "allowlistAuxAccount",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "sellState",
+          "name": "globalConfig",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "treasury",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "args",
+          "type": {
+            "defined": "UpdateGlobalConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setGlobalPause",
+      "accounts": [
         {
-          "name": "associatedTokenProgram",
+          "name": "admin",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "globalConfig",
+          "isMut": true,
           "isSigner": false
         }
       ],
@@ -366,13 +399,13 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "SolFulfillSellArgs"
+            "defined": "SetGlobalPauseArgs"
           }
         }
       ]
     },
     {
-      "name": "withdrawSell",
+      "name": "solClosePool",
       "accounts": [
         {
           "name": "owner",
@@ -382,7 +415,7 @@ export type Mmm = {
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "pool",
@@ -390,135 +423,151 @@ export type Mmm = {
           "isSigner": false
         },
         {
-          "name": "assetMint",
+          "name": "buysideSolEscrowAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "assetTokenAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposePoolOwner",
+      "accounts": [
         {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellState",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
           "name": "args",
           "type": {
-            "defined": "WithdrawSellArgs"
+            "defined": "ProposePoolOwnerArgs"
           }
         }
       ]
     },
     {
-      "name": "depositSell",
+      "name": "acceptPoolOwner",
       "accounts": [
         {
-          "name": "owner",
-          "isMut": true,
+          "name": "newOwner",
+          "isMut": false,
           "isSigner": true
         },
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "pool",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncSellStateOwner",
+      "accounts": [
         {
-          "name": "assetMetadata",
+          "name": "pool",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
         {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "pool might be shorter than Pool::LEN before the realloc"
+          ]
         },
         {
-          "name": "assetTokenAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSellState",
+      "accounts": [
         {
-          "name": "sellsideEscrowTokenAccount",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "sellState",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "sell state might be shorter than SellState::LEN before the realloc"
+          ]
         },
         {
-          "name": "allowlistAuxAccount",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "solDepositBuy",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "systemProgram",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "pool",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -527,13 +576,13 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "DepositSellArgs"
+            "defined": "SolDepositBuyArgs"
           }
         }
       ]
     },
     {
-      "name": "mip1DepositSell",
+      "name": "solWithdrawBuy",
       "accounts": [
         {
           "name": "owner",
@@ -543,7 +592,7 @@ export type Mmm = {
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "pool",
@@ -551,70 +600,108 @@ export type Mmm = {
           "isSigner": false
         },
         {
-          "name": "assetMetadata",
+          "name": "buysideSolEscrowAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
+          "name": "args",
+          "type": {
+            "defined": "SolWithdrawBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "assetTokenAccount",
+          "name": "owner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellsideEscrowTokenAccount",
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellState",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "allowlistAuxAccount",
+          "name": "globalConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
+          "name": "treasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
+          "name": "buysideSolEscrowAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "assetMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "assetMasterEdition",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "assetMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -640,51 +727,46 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "DepositSellArgs"
+            "defined": "SolFulfillBuyArgs"
           }
         }
       ]
     },
     {
-      "name": "mip1WithdrawSell",
+      "name": "solFulfillSell",
       "accounts": [
         {
-          "name": "owner",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
+          "name": "owner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetMint",
+          "name": "cosigner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "assetMasterEdition",
-          "isMut": false,
+          "name": "referral",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetMetadata",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetTokenAccount",
-          "isMut": true,
+          "name": "globalConfig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellsideEscrowTokenAccount",
+          "name": "treasury",
           "isMut": true,
           "isSigner": false
         },
@@ -694,43 +776,38 @@ export type Mmm = {
           "isSigner": false
         },
         {
-          "name": "allowlistAuxAccount",
+          "name": "assetMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellState",
-          "isMut": true,
+          "name": "assetMasterEdition",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
-          "isMut": true,
+          "name": "assetMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
+          "name": "sellsideEscrowTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
+          "name": "payerAssetAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "allowlistAuxAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
-          "isMut": false,
+          "name": "sellState",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -758,32 +835,22 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "WithdrawSellArgs"
+            "defined": "SolFulfillSellArgs"
           }
         }
       ]
     },
     {
-      "name": "solMip1FulfillSell",
+      "name": "withdrawSell",
       "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
           "isSigner": false
         },
         {
@@ -792,72 +859,115 @@ export type Mmm = {
           "isSigner": false
         },
         {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
+          "name": "assetMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "assetMetadata",
+          "name": "assetTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetMint",
-          "isMut": false,
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetMasterEdition",
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellsideEscrowTokenAccount",
+          "name": "sellState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "payerAssetAccount",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "allowlistAuxAccount",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellState",
-          "isMut": true,
+          "name": "associatedTokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "depositSell",
+      "accounts": [
+        {
+          "name": "owner",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "destinationTokenRecord",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "assetMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "assetMasterEdition",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "assetMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
           "isMut": false,
           "isSigner": false
         },
@@ -886,32 +996,22 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "SolMip1FulfillSellArgs"
+            "defined": "DepositSellArgs"
           }
         }
       ]
     },
     {
-      "name": "solMip1FulfillBuy",
+      "name": "splClosePool",
       "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
         {
           "name": "owner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "cosigner",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
           "isSigner": false
         },
         {
@@ -920,92 +1020,117 @@ export type Mmm = {
           "isSigner": false
         },
         {
-          "name": "buysideSolEscrowAccount",
+          "name": "buysidePaymentEscrowAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "assetMetadata",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "assetMint",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "splDepositBuy",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "assetMasterEdition",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payerAssetAccount",
+          "name": "pool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "sellsideEscrowTokenAccount",
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "ownerTokenAccount",
+          "name": "buysidePaymentEscrowAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "allowlistAuxAccount",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "sellState",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenOwnerTokenRecord",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is the token record for the seller"
-          ]
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "poolTokenRecord",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is the token record for the pool - will always be required"
-          ]
-        },
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
-          "name": "poolOwnerTokenRecord",
+          "name": "args",
+          "type": {
+            "defined": "SplDepositBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splWithdrawBuy",
+      "accounts": [
+        {
+          "name": "owner",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is the token record for the pool owner - will be required if reinvest = true"
-          ]
+          "isSigner": true
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "cosigner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
+          "name": "pool",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "authorizationRules",
+          "name": "paymentMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
-          "isMut": false,
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1033,146 +1158,7616 @@ export type Mmm = {
         {
           "name": "args",
           "type": {
-            "defined": "SolFulfillBuyArgs"
+            "defined": "SplWithdrawBuyArgs"
           }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "pool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "spotPrice",
-            "type": "u64"
-          },
-          {
-            "name": "curveType",
-            "type": "u8"
-          },
-          {
-            "name": "curveDelta",
-            "type": "u64"
-          },
-          {
-            "name": "reinvestFulfillBuy",
-            "type": "bool"
-          },
-          {
-            "name": "reinvestFulfillSell",
-            "type": "bool"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
-          },
-          {
-            "name": "lpFeeBp",
-            "type": "u16"
-          },
-          {
-            "name": "referral",
-            "type": "publicKey"
-          },
-          {
-            "name": "referralBp",
-            "type": "u16"
-          },
-          {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
-          },
-          {
-            "name": "cosignerAnnotation",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "sellsideAssetAmount",
-            "type": "u64"
-          },
-          {
-            "name": "lpFeeEarned",
-            "type": "u64"
-          },
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "cosigner",
-            "type": "publicKey"
-          },
-          {
-            "name": "uuid",
-            "type": "publicKey"
-          },
-          {
+      "name": "splFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1DepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "DepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1WithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solMip1FulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolMip1FulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solMip1FulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenOwnerTokenRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is the token record for the seller"
+          ]
+        },
+        {
+          "name": "poolTokenRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is the token record for the pool - will always be required"
+          ]
+        },
+        {
+          "name": "poolOwnerTokenRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is the token record for the pool owner - will be required if reinvest = true"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cnftDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CnftDepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cnftWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CnftWithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solCnftFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolCnftFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solCnftFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolCnftFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "extDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExtDepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "extWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExtWithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solExtFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolExtFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solExtFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolExtFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mplCoreDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MplCoreDepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mplCoreWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "solMplCoreFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolMplCoreFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solMplCoreFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolMplCoreFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ocpDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "DepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ocpWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solOcpFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solOcpFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolOcpFulfillSellArgs"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "pool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveType",
+            "type": "u8"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "reinvestFulfillBuy",
+            "type": "bool"
+          },
+          {
+            "name": "reinvestFulfillSell",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "referralBp",
+            "type": "u16"
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sellsideAssetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeEarned",
+            "type": "u64"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "cosigner",
+            "type": "publicKey"
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowlists",
+            "type": {
+              "array": [
+                {
+                  "defined": "Allowlist"
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "buysidePaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "originalOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingCosigner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingNoCosigner",
+            "type": "bool"
+          },
+          {
+            "name": "cosignerRotationRequestedAt",
+            "type": "i64"
+          },
+          {
+            "name": "noCosigner",
+            "type": "bool"
+          },
+          {
+            "name": "operator",
+            "type": "publicKey"
+          },
+          {
+            "name": "buysidePaused",
+            "type": "bool"
+          },
+          {
+            "name": "sellsidePaused",
+            "type": "bool"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "allowlistPriceMultipliersBp",
+            "type": {
+              "array": [
+                "u16",
+                6
+              ]
+            }
+          },
+          {
+            "name": "priceLadder",
+            "type": {
+              "array": [
+                {
+                  "defined": "PriceLadderStep"
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "priceLadderPosition",
+            "type": "u64"
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "defined": "DutchAuction"
+            }
+          },
+          {
+            "name": "dutchAuctionStartedAt",
+            "type": "i64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "curveAnchorPrice",
+            "type": "u64"
+          },
+          {
+            "name": "asymmetricCurveDelta",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "overflowAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowlists",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "allowlistRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "curator",
+            "type": "publicKey"
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "revision",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "protocolFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxLpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxReferralFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "Uses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "UseMethod"
+            }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Collection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Creator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": {
+                "defined": "TokenStandard"
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "Collection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "Uses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": {
+              "defined": "TokenProgramVersion"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateAllowlistRegistryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveType",
+            "type": "u8"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reinvestFulfillBuy",
+            "type": "bool"
+          },
+          {
+            "name": "reinvestFulfillSell",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "priceLadder",
+            "type": {
+              "array": [
+                {
+                  "defined": "PriceLadderStep"
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "option": {
+                "defined": "DutchAuction"
+              }
+            }
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
             "name": "paymentMint",
             "type": "publicKey"
           },
           {
-            "name": "allowlists",
-            "type": {
-              "array": [
-                {
-                  "defined": "Allowlist"
-                },
-                6
-              ]
-            }
+            "name": "allowlists",
+            "type": {
+              "array": [
+                {
+                  "defined": "Allowlist"
+                },
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitGlobalConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "protocolFeeBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OperatorUpdatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposePoolOwnerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newOwner",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetGlobalPauseArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPoolPauseArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buysidePaused",
+            "type": "bool"
+          },
+          {
+            "name": "sellsidePaused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateAllowlistRegistryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newCurator",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateAllowlistsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowlists",
+            "type": {
+              "array": [
+                {
+                  "defined": "Allowlist"
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "allowlistPriceMultipliersBp",
+            "type": {
+              "array": [
+                "u16",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGlobalConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxLpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxReferralFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateOverflowAllowlistArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowlists",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolCosignerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newCosigner",
+            "type": "publicKey"
+          },
+          {
+            "name": "noCosigner",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolOperatorArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveType",
+            "type": "u8"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reinvestFulfillBuy",
+            "type": "bool"
+          },
+          {
+            "name": "reinvestFulfillSell",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "priceLadder",
+            "type": {
+              "option": {
+                "array": [
+                  {
+                    "defined": "PriceLadderStep"
+                  },
+                  6
+                ]
+              }
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "option": {
+                "defined": "DutchAuction"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CnftDepositSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataArgs",
+            "type": {
+              "defined": "MetadataArgs"
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CnftWithdrawSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolCnftFulfillBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataArgs",
+            "type": {
+              "defined": "MetadataArgs"
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "minPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolCnftFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataArgs",
+            "type": {
+              "defined": "MetadataArgs"
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExtDepositSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExtWithdrawSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolExtFulfillBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolExtFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolMip1FulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MplCoreDepositSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolMplCoreFulfillBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolMplCoreFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolOcpFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DepositSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolDepositBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolFulfillBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolWithdrawBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SplDepositBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SplWithdrawBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WithdrawSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DynamicRoyaltyPriceLinear",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          },
+          {
+            "name": "startMultiplierBp",
+            "type": "u16"
+          },
+          {
+            "name": "endMultiplierBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DynamicRoyalty",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "DynamicRoyaltyKind"
+            }
+          },
+          {
+            "name": "overrideRoyaltyBp",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "priceLinear",
+            "type": {
+              "option": {
+                "defined": "DynamicRoyaltyPriceLinear"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Policy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "dynamicRoyalty",
+            "type": {
+              "option": {
+                "defined": "DynamicRoyalty"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "policy",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Allowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "kind",
+            "type": "u8"
+          },
+          {
+            "name": "value",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceLadderStep",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DutchAuction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "floor",
+            "type": "u64"
+          },
+          {
+            "name": "decayPerSecond",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenProgramVersion",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Original"
+          },
+          {
+            "name": "Token2022"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenStandard",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "FungibleAsset"
+          },
+          {
+            "name": "Fungible"
           },
           {
-            "name": "buysidePaymentAmount",
-            "type": "u64"
+            "name": "NonFungibleEdition"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UseMethod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Multiple"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateAuthority",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Address",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "Collection",
+            "fields": [
+              "publicKey"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DynamicRoyaltyKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "PriceLinear"
+          }
+        ]
+      }
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidLPFee",
+      "msg": "lp fee bp must be between 0 and 10000"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidAllowLists",
+      "msg": "invalid allowlists"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidBP",
+      "msg": "invalid bp"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidCurveType",
+      "msg": "invalid curve type"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidCurveDelta",
+      "msg": "invalid curve delta"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidCosigner",
+      "msg": "invalid cosigner"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidPaymentMint",
+      "msg": "invalid payment mint"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidOwner",
+      "msg": "invalid owner"
+    },
+    {
+      "code": 6008,
+      "name": "NumericOverflow",
+      "msg": "numeric overflow"
+    },
+    {
+      "code": 6009,
+      "name": "InvalidRequestedPrice",
+      "msg": "invalid requested price"
+    },
+    {
+      "code": 6010,
+      "name": "NotEmptyEscrowAccount",
+      "msg": "not empty escrow account"
+    },
+    {
+      "code": 6011,
+      "name": "NotEmptySellsideAssetAmount",
+      "msg": "not empty sell side asset amount"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidReferral",
+      "msg": "invalid referral"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidMasterEdition",
+      "msg": "invalid master edition"
+    },
+    {
+      "code": 6014,
+      "name": "Expired",
+      "msg": "expired"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidCreatorAddress",
+      "msg": "invalid creator address"
+    },
+    {
+      "code": 6016,
+      "name": "NotEnoughBalance",
+      "msg": "not enough balance"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidTokenOwner",
+      "msg": "invalid token owner"
+    },
+    {
+      "code": 6018,
+      "name": "PubkeyMismatch",
+      "msg": "pubkey mismatch"
+    },
+    {
+      "code": 6019,
+      "name": "UninitializedAccount",
+      "msg": "uninitialized account"
+    },
+    {
+      "code": 6020,
+      "name": "InvalidSpotPrice",
+      "msg": "invalid spot_price"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidMetadataCreatorRoyalty",
+      "msg": "invalid metadata creator royalty"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidMakerOrTakerFeeBP",
+      "msg": "invalid maker or taker bp"
+    },
+    {
+      "code": 6023,
+      "name": "InvalidOcpAssetParams",
+      "msg": "Invalid OCP mint supply constraints"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidAccountState",
+      "msg": "Invalid account state"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidTokenMint",
+      "msg": "Invalid token mint"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidMip1AssetParams",
+      "msg": "Invalid MIP1 mint supply constraints"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidTokenStandard",
+      "msg": "Invalid token standard"
+    },
+    {
+      "code": 6028,
+      "name": "UnexpectedMetadataUri",
+      "msg": "Unexpected metadata uri"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTokenExtension",
+      "msg": "Invalid token extension"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTransferHookAccounts",
+      "msg": "Invalid transfer hook accounts"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidMplCoreAsset",
+      "msg": "Invalid mpl core asset"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidMplCoreCollection",
+      "msg": "Invalid mpl core collection"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidPendingOwner",
+      "msg": "Invalid pending owner"
+    },
+    {
+      "code": 6034,
+      "name": "CosignerRotationTimelocked",
+      "msg": "Cosigner rotation is timelocked"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidOperator",
+      "msg": "Invalid operator"
+    },
+    {
+      "code": 6036,
+      "name": "PoolPaused",
+      "msg": "Pool is paused"
+    },
+    {
+      "code": 6037,
+      "name": "PoolNotStarted",
+      "msg": "Pool is not started yet"
+    },
+    {
+      "code": 6038,
+      "name": "ProgramPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidTreasury",
+      "msg": "Invalid treasury"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidAdmin",
+      "msg": "Invalid admin"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidCurator",
+      "msg": "Invalid curator"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPriceLadder",
+      "msg": "Invalid price ladder"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidDutchAuction",
+      "msg": "Invalid dutch auction"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidPriceMultiplier",
+      "msg": "Invalid price multiplier"
+    }
+  ]
+};
+
+export const IDL: Mmm = {
+  "version": "0.1.0",
+  "name": "mmm",
+  "instructions": [
+    {
+      "name": "createPool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateAllowlists",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateAllowlistsArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateOverflowAllowlist",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "overflowAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateOverflowAllowlistArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createAllowlistRegistry",
+      "accounts": [
+        {
+          "name": "curator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CreateAllowlistRegistryArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateAllowlistRegistry",
+      "accounts": [
+        {
+          "name": "curator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "registry",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateAllowlistRegistryArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePoolCosigner",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolCosignerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updatePoolOperator",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdatePoolOperatorArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "operatorUpdatePool",
+      "accounts": [
+        {
+          "name": "operator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "OperatorUpdatePoolArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPoolPause",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetPoolPauseArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initGlobalConfig",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "so that the fees below the rent exempt minimum can still be transferred to it"
+          ]
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "InitGlobalConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateGlobalConfig",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "UpdateGlobalConfigArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setGlobalPause",
+      "accounts": [
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SetGlobalPauseArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solClosePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposePoolOwner",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ProposePoolOwnerArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "acceptPoolOwner",
+      "accounts": [
+        {
+          "name": "newOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "syncSellStateOwner",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "pool might be shorter than Pool::LEN before the realloc"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateSellState",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "sell state might be shorter than SellState::LEN before the realloc"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "solDepositBuy",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolDepositBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solWithdrawBuy",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolWithdrawBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "withdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "depositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "DepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splClosePool",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "splDepositBuy",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SplDepositBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splWithdrawBuy",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SplWithdrawBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "splFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "paymentMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buysidePaymentEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "referralPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryPaymentTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1DepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "DepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mip1WithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solMip1FulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destinationTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolMip1FulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solMip1FulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMasterEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenOwnerTokenRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is the token record for the seller"
+          ]
+        },
+        {
+          "name": "poolTokenRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is the token record for the pool - will always be required"
+          ]
+        },
+        {
+          "name": "poolOwnerTokenRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is the token record for the pool owner - will be required if reinvest = true"
+          ]
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRules",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cnftDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CnftDepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "cnftWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "CnftWithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solCnftFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leafDelegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolCnftFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solCnftFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "merkleTree",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "logWrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "compressionProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bubblegumProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolCnftFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "extDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExtDepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "extWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "ExtWithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solExtFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolExtFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solExtFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolExtFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mplCoreDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "MplCoreDepositSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "mplCoreWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "solMplCoreFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolMplCoreFulfillBuyArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solMplCoreFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "asset",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collection",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolMplCoreFulfillSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "ocpDepositSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "DepositSellArgs"
           }
-        ]
-      }
+        }
+      ]
+    },
+    {
+      "name": "ocpWithdrawSell",
+      "accounts": [
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "WithdrawSellArgs"
+          }
+        }
+      ]
+    },
+    {
+      "name": "solOcpFulfillBuy",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolFulfillBuyArgs"
+          }
+        }
+      ]
     },
     {
-      "name": "sellState",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "poolOwner",
-            "type": "publicKey"
-          },
-          {
-            "name": "assetMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "assetAmount",
-            "type": "u64"
-          },
-          {
-            "name": "cosignerAnnotation",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+      "name": "solOcpFulfillSell",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "cosigner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "buysideSolEscrowAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "assetMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "assetMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellsideEscrowTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payerAssetAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allowlistAuxAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sellState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpMintState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "ocpPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpFreezeAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ocpProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cmtProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "args",
+          "type": {
+            "defined": "SolOcpFulfillSellArgs"
           }
-        ]
-      }
+        }
+      ]
     }
   ],
-  "types": [
+  "accounts": [
     {
-      "name": "CreatePoolArgs",
+      "name": "pool",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1208,6 +8803,14 @@ export type Mmm = {
             "name": "referral",
             "type": "publicKey"
           },
+          {
+            "name": "referralBp",
+            "type": "u16"
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
           {
             "name": "cosignerAnnotation",
             "type": {
@@ -1218,8 +8821,20 @@ export type Mmm = {
             }
           },
           {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
+            "name": "sellsideAssetAmount",
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeEarned",
+            "type": "u64"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "cosigner",
+            "type": "publicKey"
           },
           {
             "name": "uuid",
@@ -1239,1472 +8854,945 @@ export type Mmm = {
                 6
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "UpdatePoolArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "spotPrice",
+            "name": "buysidePaymentAmount",
             "type": "u64"
           },
           {
-            "name": "curveType",
-            "type": "u8"
+            "name": "originalOwner",
+            "type": "publicKey"
           },
           {
-            "name": "curveDelta",
-            "type": "u64"
+            "name": "pendingOwner",
+            "type": "publicKey"
           },
           {
-            "name": "reinvestFulfillBuy",
-            "type": "bool"
+            "name": "pendingCosigner",
+            "type": "publicKey"
           },
           {
-            "name": "reinvestFulfillSell",
+            "name": "pendingNoCosigner",
             "type": "bool"
           },
           {
-            "name": "expiry",
+            "name": "cosignerRotationRequestedAt",
             "type": "i64"
           },
           {
-            "name": "lpFeeBp",
-            "type": "u16"
+            "name": "noCosigner",
+            "type": "bool"
           },
           {
-            "name": "referral",
+            "name": "operator",
             "type": "publicKey"
           },
           {
-            "name": "cosignerAnnotation",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "name": "buysidePaused",
+            "type": "bool"
           },
           {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SolMip1FulfillSellArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "assetAmount",
-            "type": "u64"
+            "name": "sellsidePaused",
+            "type": "bool"
           },
           {
-            "name": "maxPaymentAmount",
-            "type": "u64"
+            "name": "startTime",
+            "type": "i64"
           },
           {
-            "name": "allowlistAux",
-            "type": {
-              "option": "string"
-            }
+            "name": "buysideExpiry",
+            "type": "i64"
           },
           {
-            "name": "makerFeeBp",
-            "type": "i16"
+            "name": "sellsideExpiry",
+            "type": "i64"
           },
           {
-            "name": "takerFeeBp",
-            "type": "i16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "DepositSellArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "assetAmount",
-            "type": "u64"
+            "name": "version",
+            "type": "u8"
           },
           {
-            "name": "allowlistAux",
+            "name": "allowlistPriceMultipliersBp",
             "type": {
-              "option": "string"
+              "array": [
+                "u16",
+                6
+              ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "SolDepositBuyArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "paymentAmount",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SolFulfillBuyArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "assetAmount",
-            "type": "u64"
+            "name": "priceLadder",
+            "type": {
+              "array": [
+                {
+                  "defined": "PriceLadderStep"
+                },
+                6
+              ]
+            }
           },
           {
-            "name": "minPaymentAmount",
+            "name": "priceLadderPosition",
             "type": "u64"
           },
           {
-            "name": "allowlistAux",
+            "name": "dutchAuction",
             "type": {
-              "option": "string"
+              "defined": "DutchAuction"
             }
           },
           {
-            "name": "makerFeeBp",
-            "type": "i16"
+            "name": "dutchAuctionStartedAt",
+            "type": "i64"
           },
           {
-            "name": "takerFeeBp",
-            "type": "i16"
+            "name": "sellCurveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "curveAnchorPrice",
+            "type": "u64"
+          },
+          {
+            "name": "asymmetricCurveDelta",
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "SolFulfillSellArgs",
+      "name": "sellState",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "assetAmount",
-            "type": "u64"
+            "name": "pool",
+            "type": "publicKey"
           },
           {
-            "name": "maxPaymentAmount",
-            "type": "u64"
+            "name": "poolOwner",
+            "type": "publicKey"
           },
           {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
+            "name": "assetMint",
+            "type": "publicKey"
           },
           {
-            "name": "allowlistAux",
-            "type": {
-              "option": "string"
-            }
+            "name": "assetAmount",
+            "type": "u64"
           },
           {
-            "name": "makerFeeBp",
-            "type": "i16"
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "takerFeeBp",
-            "type": "i16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "SolWithdrawBuyArgs",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "paymentAmount",
-            "type": "u64"
+            "name": "version",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "WithdrawSellArgs",
+      "name": "overflowAllowlist",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "assetAmount",
-            "type": "u64"
+            "name": "pool",
+            "type": "publicKey"
           },
           {
-            "name": "allowlistAux",
+            "name": "allowlists",
             "type": {
-              "option": "string"
+              "vec": {
+                "defined": "Allowlist"
+              }
             }
           }
         ]
       }
     },
     {
-      "name": "Allowlist",
+      "name": "allowlistRegistry",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "kind",
-            "type": "u8"
+            "name": "curator",
+            "type": "publicKey"
           },
           {
-            "name": "value",
+            "name": "uuid",
             "type": "publicKey"
-          }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidLPFee",
-      "msg": "lp fee bp must be between 0 and 10000"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidAllowLists",
-      "msg": "invalid allowlists"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidBP",
-      "msg": "invalid bp"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidCurveType",
-      "msg": "invalid curve type"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidCurveDelta",
-      "msg": "invalid curve delta"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidCosigner",
-      "msg": "invalid cosigner"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidPaymentMint",
-      "msg": "invalid payment mint"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidOwner",
-      "msg": "invalid owner"
-    },
-    {
-      "code": 6008,
-      "name": "NumericOverflow",
-      "msg": "numeric overflow"
-    },
-    {
-      "code": 6009,
-      "name": "InvalidRequestedPrice",
-      "msg": "invalid requested price"
-    },
-    {
-      "code": 6010,
-      "name": "NotEmptyEscrowAccount",
-      "msg": "not empty escrow account"
-    },
-    {
-      "code": 6011,
-      "name": "NotEmptySellsideAssetAmount",
-      "msg": "not empty sell side asset amount"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidReferral",
-      "msg": "invalid referral"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidMasterEdition",
-      "msg": "invalid master edition"
-    },
-    {
-      "code": 6014,
-      "name": "Expired",
-      "msg": "expired"
-    },
-    {
-      "code": 6015,
-      "name": "InvalidCreatorAddress",
-      "msg": "invalid creator address"
-    },
-    {
-      "code": 6016,
-      "name": "NotEnoughBalance",
-      "msg": "not enough balance"
-    },
-    {
-      "code": 6017,
-      "name": "InvalidTokenOwner",
-      "msg": "invalid token owner"
-    },
-    {
-      "code": 6018,
-      "name": "PubkeyMismatch",
-      "msg": "pubkey mismatch"
-    },
-    {
-      "code": 6019,
-      "name": "UninitializedAccount",
-      "msg": "uninitialized account"
-    },
-    {
-      "code": 6020,
-      "name": "InvalidSpotPrice",
-      "msg": "invalid spot_price"
-    },
-    {
-      "code": 6021,
-      "name": "InvalidMetadataCreatorRoyalty",
-      "msg": "invalid metadata creator royalty"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidMakerOrTakerFeeBP",
-      "msg": "invalid maker or taker bp"
-    },
-    {
-      "code": 6023,
-      "name": "InvalidOcpAssetParams",
-      "msg": "Invalid OCP mint supply constraints"
-    },
-    {
-      "code": 6024,
-      "name": "InvalidAccountState",
-      "msg": "Invalid account state"
-    },
-    {
-      "code": 6025,
-      "name": "InvalidTokenMint",
-      "msg": "Invalid token mint"
-    },
-    {
-      "code": 6026,
-      "name": "InvalidMip1AssetParams",
-      "msg": "Invalid MIP1 mint supply constraints"
-    },
-    {
-      "code": 6027,
-      "name": "InvalidTokenStandard",
-      "msg": "Invalid token standard"
+          },
+          {
+            "name": "revision",
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6028,
-      "name": "UnexpectedMetadataUri",
-      "msg": "Unexpected metadata uri"
+      "name": "globalConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "protocolFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxLpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxReferralFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
     }
-  ]
-};
-
-export const IDL: Mmm = {
-  "version": "0.1.0",
-  "name": "mmm",
-  "instructions": [
+  ],
+  "types": [
     {
-      "name": "createPool",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "CreatePoolArgs"
+      "name": "Uses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "UseMethod"
+            }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "updatePool",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "UpdatePoolArgs"
+      "name": "Collection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
           }
-        }
-      ]
-    },
-    {
-      "name": "solClosePool",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
+        ]
+      }
     },
     {
-      "name": "solDepositBuy",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SolDepositBuyArgs"
+      "name": "Creator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "solWithdrawBuy",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SolWithdrawBuyArgs"
+      "name": "MetadataArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "type": "u16"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": "bool"
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "editionNonce",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "tokenStandard",
+            "type": {
+              "option": {
+                "defined": "TokenStandard"
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "type": {
+              "option": {
+                "defined": "Collection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "type": {
+              "option": {
+                "defined": "Uses"
+              }
+            }
+          },
+          {
+            "name": "tokenProgramVersion",
+            "type": {
+              "defined": "TokenProgramVersion"
+            }
+          },
+          {
+            "name": "creators",
+            "type": {
+              "vec": {
+                "defined": "Creator"
+              }
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "solFulfillBuy",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payerAssetAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SolFulfillBuyArgs"
+      "name": "CreateAllowlistRegistryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "solFulfillSell",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payerAssetAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SolFulfillSellArgs"
+      "name": "CreatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveType",
+            "type": "u8"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reinvestFulfillBuy",
+            "type": "bool"
+          },
+          {
+            "name": "reinvestFulfillSell",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "priceLadder",
+            "type": {
+              "array": [
+                {
+                  "defined": "PriceLadderStep"
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "option": {
+                "defined": "DutchAuction"
+              }
+            }
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "paymentMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "allowlists",
+            "type": {
+              "array": [
+                {
+                  "defined": "Allowlist"
+                },
+                6
+              ]
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "withdrawSell",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "WithdrawSellArgs"
+      "name": "InitGlobalConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "protocolFeeBp",
+            "type": "u16"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "depositSell",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "DepositSellArgs"
+      "name": "OperatorUpdatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "mip1DepositSell",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destinationTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "instructions",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "DepositSellArgs"
+      "name": "ProposePoolOwnerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newOwner",
+            "type": "publicKey"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "mip1WithdrawSell",
-      "accounts": [
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destinationTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "instructions",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "WithdrawSellArgs"
+      "name": "SetGlobalPauseArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paused",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SetPoolPauseArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "buysidePaused",
+            "type": "bool"
+          },
+          {
+            "name": "sellsidePaused",
+            "type": "bool"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "solMip1FulfillSell",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "payerAssetAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destinationTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "instructions",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SolMip1FulfillSellArgs"
+      "name": "UpdateAllowlistRegistryArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newCurator",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateAllowlistsArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowlists",
+            "type": {
+              "array": [
+                {
+                  "defined": "Allowlist"
+                },
+                6
+              ]
+            }
+          },
+          {
+            "name": "allowlistPriceMultipliersBp",
+            "type": {
+              "array": [
+                "u16",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateGlobalConfigArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxLpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "maxReferralFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateOverflowAllowlistArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allowlists",
+            "type": {
+              "vec": {
+                "defined": "Allowlist"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolCosignerArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newCosigner",
+            "type": "publicKey"
+          },
+          {
+            "name": "noCosigner",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolOperatorArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "operator",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdatePoolArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "spotPrice",
+            "type": "u64"
+          },
+          {
+            "name": "curveType",
+            "type": "u8"
+          },
+          {
+            "name": "curveDelta",
+            "type": "u64"
+          },
+          {
+            "name": "sellCurveDelta",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reinvestFulfillBuy",
+            "type": "bool"
+          },
+          {
+            "name": "reinvestFulfillSell",
+            "type": "bool"
+          },
+          {
+            "name": "expiry",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "buysideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "sellsideExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "lpFeeBp",
+            "type": "u16"
+          },
+          {
+            "name": "referral",
+            "type": "publicKey"
+          },
+          {
+            "name": "cosignerAnnotation",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
+          },
+          {
+            "name": "priceLadder",
+            "type": {
+              "option": {
+                "array": [
+                  {
+                    "defined": "PriceLadderStep"
+                  },
+                  6
+                ]
+              }
+            }
+          },
+          {
+            "name": "dutchAuction",
+            "type": {
+              "option": {
+                "defined": "DutchAuction"
+              }
+            }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "solMip1FulfillBuy",
-      "accounts": [
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "cosigner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "referral",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "buysideSolEscrowAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMetadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "assetMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "assetMasterEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payerAssetAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "sellsideEscrowTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "allowlistAuxAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "sellState",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenOwnerTokenRecord",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is the token record for the seller"
-          ]
-        },
-        {
-          "name": "poolTokenRecord",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is the token record for the pool - will always be required"
-          ]
-        },
-        {
-          "name": "poolOwnerTokenRecord",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is the token record for the pool owner - will be required if reinvest = true"
-          ]
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRules",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "instructions",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "args",
-          "type": {
-            "defined": "SolFulfillBuyArgs"
+      "name": "CnftDepositSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "metadataArgs",
+            "type": {
+              "defined": "MetadataArgs"
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          },
+          {
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
+        ]
+      }
+    },
+    {
+      "name": "CnftWithdrawSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "dataHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "creatorHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "index",
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
-      "name": "pool",
+      "name": "SolCnftFulfillBuyArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "spotPrice",
-            "type": "u64"
+            "name": "root",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "curveType",
-            "type": "u8"
+            "name": "metadataArgs",
+            "type": {
+              "defined": "MetadataArgs"
+            }
           },
           {
-            "name": "curveDelta",
+            "name": "nonce",
             "type": "u64"
           },
           {
-            "name": "reinvestFulfillBuy",
-            "type": "bool"
-          },
-          {
-            "name": "reinvestFulfillSell",
-            "type": "bool"
-          },
-          {
-            "name": "expiry",
-            "type": "i64"
+            "name": "index",
+            "type": "u32"
           },
           {
-            "name": "lpFeeBp",
-            "type": "u16"
+            "name": "minPaymentAmount",
+            "type": "u64"
           },
           {
-            "name": "referral",
-            "type": "publicKey"
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
           },
           {
-            "name": "referralBp",
-            "type": "u16"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
-          },
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolCnftFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "cosignerAnnotation",
+            "name": "root",
             "type": {
               "array": [
                 "u8",
@@ -2713,209 +9801,242 @@ export const IDL: Mmm = {
             }
           },
           {
-            "name": "sellsideAssetAmount",
-            "type": "u64"
+            "name": "metadataArgs",
+            "type": {
+              "defined": "MetadataArgs"
+            }
           },
           {
-            "name": "lpFeeEarned",
+            "name": "nonce",
             "type": "u64"
           },
           {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "cosigner",
-            "type": "publicKey"
+            "name": "index",
+            "type": "u32"
           },
           {
-            "name": "uuid",
-            "type": "publicKey"
+            "name": "maxPaymentAmount",
+            "type": "u64"
           },
           {
-            "name": "paymentMint",
-            "type": "publicKey"
+            "name": "buysideCreatorRoyaltyBp",
+            "type": "u16"
           },
           {
-            "name": "allowlists",
+            "name": "allowlistAux",
             "type": {
-              "array": [
-                {
-                  "defined": "Allowlist"
-                },
-                6
-              ]
+              "option": "string"
             }
           },
           {
-            "name": "buysidePaymentAmount",
-            "type": "u64"
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
           }
         ]
       }
     },
     {
-      "name": "sellState",
+      "name": "ExtDepositSellArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "poolOwner",
-            "type": "publicKey"
+            "name": "assetAmount",
+            "type": "u64"
           },
           {
-            "name": "assetMint",
-            "type": "publicKey"
-          },
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ExtWithdrawSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "assetAmount",
             "type": "u64"
           },
           {
-            "name": "cosignerAnnotation",
+            "name": "allowlistAux",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "string"
             }
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "CreatePoolArgs",
+      "name": "SolExtFulfillBuyArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "spotPrice",
+            "name": "assetAmount",
             "type": "u64"
           },
           {
-            "name": "curveType",
-            "type": "u8"
-          },
-          {
-            "name": "curveDelta",
+            "name": "minPaymentAmount",
             "type": "u64"
           },
           {
-            "name": "reinvestFulfillBuy",
-            "type": "bool"
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
           },
           {
-            "name": "reinvestFulfillSell",
-            "type": "bool"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "expiry",
-            "type": "i64"
-          },
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolExtFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "lpFeeBp",
-            "type": "u16"
+            "name": "assetAmount",
+            "type": "u64"
           },
           {
-            "name": "referral",
-            "type": "publicKey"
+            "name": "maxPaymentAmount",
+            "type": "u64"
           },
           {
-            "name": "cosignerAnnotation",
+            "name": "allowlistAux",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "string"
             }
           },
           {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "uuid",
-            "type": "publicKey"
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolMip1FulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "assetAmount",
+            "type": "u64"
           },
           {
-            "name": "paymentMint",
-            "type": "publicKey"
+            "name": "maxPaymentAmount",
+            "type": "u64"
           },
           {
-            "name": "allowlists",
+            "name": "allowlistAux",
             "type": {
-              "array": [
-                {
-                  "defined": "Allowlist"
-                },
-                6
-              ]
+              "option": "string"
             }
+          },
+          {
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
           }
         ]
       }
     },
     {
-      "name": "UpdatePoolArgs",
+      "name": "MplCoreDepositSellArgs",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "spotPrice",
-            "type": "u64"
-          },
-          {
-            "name": "curveType",
-            "type": "u8"
-          },
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolMplCoreFulfillBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "curveDelta",
+            "name": "minPaymentAmount",
             "type": "u64"
           },
           {
-            "name": "reinvestFulfillBuy",
-            "type": "bool"
+            "name": "allowlistAux",
+            "type": {
+              "option": "string"
+            }
           },
           {
-            "name": "reinvestFulfillSell",
-            "type": "bool"
+            "name": "makerFeeBp",
+            "type": "i16"
           },
           {
-            "name": "expiry",
-            "type": "i64"
+            "name": "takerFeeBp",
+            "type": "i16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SolMplCoreFulfillSellArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxPaymentAmount",
+            "type": "u64"
           },
           {
-            "name": "lpFeeBp",
+            "name": "buysideCreatorRoyaltyBp",
             "type": "u16"
           },
           {
-            "name": "referral",
-            "type": "publicKey"
-          },
-          {
-            "name": "cosignerAnnotation",
+            "name": "allowlistAux",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "string"
             }
           },
           {
-            "name": "buysideCreatorRoyaltyBp",
-            "type": "u16"
+            "name": "makerFeeBp",
+            "type": "i16"
+          },
+          {
+            "name": "takerFeeBp",
+            "type": "i16"
           }
         ]
       }
     },
     {
-      "name": "SolMip1FulfillSellArgs",
+      "name": "SolOcpFulfillSellArgs",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3050,6 +10171,30 @@ export const IDL: Mmm = {
         ]
       }
     },
+    {
+      "name": "SplDepositBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SplWithdrawBuyArgs",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
       "name": "WithdrawSellArgs",
       "type": {
@@ -3068,6 +10213,134 @@ export const IDL: Mmm = {
         ]
       }
     },
+    {
+      "name": "DynamicRoyaltyPriceLinear",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceMint",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "startPrice",
+            "type": "u64"
+          },
+          {
+            "name": "endPrice",
+            "type": "u64"
+          },
+          {
+            "name": "startMultiplierBp",
+            "type": "u16"
+          },
+          {
+            "name": "endMultiplierBp",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DynamicRoyalty",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "kind",
+            "type": {
+              "defined": "DynamicRoyaltyKind"
+            }
+          },
+          {
+            "name": "overrideRoyaltyBp",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "priceLinear",
+            "type": {
+              "option": {
+                "defined": "DynamicRoyaltyPriceLinear"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Policy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "uuid",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "dynamicRoyalty",
+            "type": {
+              "option": {
+                "defined": "DynamicRoyalty"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "policy",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
     {
       "name": "Allowlist",
       "type": {
@@ -3083,6 +10356,123 @@ export const IDL: Mmm = {
           }
         ]
       }
+    },
+    {
+      "name": "PriceLadderStep",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DutchAuction",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "floor",
+            "type": "u64"
+          },
+          {
+            "name": "decayPerSecond",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenProgramVersion",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Original"
+          },
+          {
+            "name": "Token2022"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenStandard",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NonFungible"
+          },
+          {
+            "name": "FungibleAsset"
+          },
+          {
+            "name": "Fungible"
+          },
+          {
+            "name": "NonFungibleEdition"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UseMethod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Multiple"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateAuthority",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "None"
+          },
+          {
+            "name": "Address",
+            "fields": [
+              "publicKey"
+            ]
+          },
+          {
+            "name": "Collection",
+            "fields": [
+              "publicKey"
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "DynamicRoyaltyKind",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "PriceLinear"
+          }
+        ]
+      }
     }
   ],
   "errors": [
@@ -3230,6 +10620,86 @@ export const IDL: Mmm = {
       "code": 6028,
       "name": "UnexpectedMetadataUri",
       "msg": "Unexpected metadata uri"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTokenExtension",
+      "msg": "Invalid token extension"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTransferHookAccounts",
+      "msg": "Invalid transfer hook accounts"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidMplCoreAsset",
+      "msg": "Invalid mpl core asset"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidMplCoreCollection",
+      "msg": "Invalid mpl core collection"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidPendingOwner",
+      "msg": "Invalid pending owner"
+    },
+    {
+      "code": 6034,
+      "name": "CosignerRotationTimelocked",
+      "msg": "Cosigner rotation is timelocked"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidOperator",
+      "msg": "Invalid operator"
+    },
+    {
+      "code": 6036,
+      "name": "PoolPaused",
+      "msg": "Pool is paused"
+    },
+    {
+      "code": 6037,
+      "name": "PoolNotStarted",
+      "msg": "Pool is not started yet"
+    },
+    {
+      "code": 6038,
+      "name": "ProgramPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidTreasury",
+      "msg": "Invalid treasury"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidAdmin",
+      "msg": "Invalid admin"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidCurator",
+      "msg": "Invalid curator"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPriceLadder",
+      "msg": "Invalid price ladder"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidDutchAuction",
+      "msg": "Invalid dutch auction"
+    },
+    {
+      "code": 6044,
+      "name": "InvalidPriceMultiplier",
+      "msg": "Invalid price multiplier"
     }
   ]
 };
@@ -24,6 +24,7 @@ import { AllowlistKind } from './constants';
 import { IDL, Mmm } from './idl/mmm';
 import {
   getMMMBuysideSolEscrowPDA,
+  getMMMGlobalConfigPDA,
   getMMMPoolPDA,
   getMMMSellStatePDA,
   getTokenRecordPDA,
@@ -132,16 +133,27 @@ export class MMMClient {
     };
   }
 
+  // the fulfills pay the protocol fee to the treasury of the global config
+  private async getGlobalConfigAccounts() {
+    const { key: globalConfig } = getMMMGlobalConfigPDA(MMMProgramID);
+    const { treasury } = await this.program.account.globalConfig.fetch(
+      globalConfig,
+    );
+    return { globalConfig, treasury };
+  }
+
   async getInsCreatePool(
     args: anchor.IdlTypes<Mmm>['CreatePoolArgs'],
     owner: PublicKey,
     cosigner: PublicKey,
   ): Promise<TransactionInstruction> {
     const { key: poolKey } = getMMMPoolPDA(MMMProgramID, owner, args.uuid);
+    const { key: globalConfig } = getMMMGlobalConfigPDA(MMMProgramID);
     let builder = this.program.methods.createPool(args).accountsStrict({
       pool: poolKey,
       owner,
       cosigner,
+      globalConfig,
       systemProgram: SystemProgram.programId,
     });
     return await builder.instruction();
@@ -167,10 +179,12 @@ export class MMMClient {
     args: anchor.IdlTypes<Mmm>['UpdatePoolArgs'],
   ): Promise<TransactionInstruction> {
     if (!this.poolData) throw MMMClient.ErrPoolDataEmpty;
+    const { key: globalConfig } = getMMMGlobalConfigPDA(MMMProgramID);
     let builder = this.program.methods.updatePool(args).accountsStrict({
       pool: this.poolData.pool,
       owner: this.poolData.owner,
       cosigner: this.poolData.cosigner,
+      globalConfig,
     });
     return await builder.instruction();
   }
@@ -223,6 +237,7 @@ export class MMMClient {
       MMMProgramID,
       this.poolData.pool,
     );
+    const { globalConfig, treasury } = await this.getGlobalConfigAccounts();
     const assetMetadata = this.mpl.nfts().pdas().metadata({ mint: assetMint });
     const ownerTokenAccount = await getAssociatedTokenAddress(
       assetMint,
@@ -276,6 +291,8 @@ export class MMMClient {
         assetMasterEdition,
         referral: this.poolData.referral,
         cosigner: this.poolData.cosigner,
+        globalConfig,
+        treasury,
         payerAssetAccount: assetTokenAccount,
         ownerTokenAccount,
         sellState,
@@ -301,6 +318,8 @@ export class MMMClient {
         assetMetadata,
         referral: this.poolData.referral,
         cosigner: this.poolData.cosigner,
+        globalConfig,
+        treasury,
         payerAssetAccount: assetTokenAccount,
         ownerTokenAccount,
         sellState,
@@ -342,6 +361,7 @@ export class MMMClient {
       MMMProgramID,
       this.poolData.pool,
     );
+    const { globalConfig, treasury } = await this.getGlobalConfigAccounts();
     const assetMetadata = this.mpl.nfts().pdas().metadata({ mint: assetMint });
 
     const { key: sellState } = getMMMSellStatePDA(
@@ -386,6 +406,8 @@ export class MMMClient {
           assetMetadata,
           referral: this.poolData.referral,
           cosigner: this.poolData.cosigner,
+          globalConfig,
+          treasury,
           payerAssetAccount,
           sellState,
           sellsideEscrowTokenAccount,
@@ -413,6 +435,8 @@ export class MMMClient {
         assetMetadata,
         referral: this.poolData.referral,
         cosigner: this.poolData.cosigner,
+        globalConfig,
+        treasury,
         payerAssetAccount,
         sellState,
         sellsideEscrowTokenAccount,
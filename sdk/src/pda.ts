@@ -37,6 +37,36 @@ export const getMMMBuysideSolEscrowPDA = (
   return { key, bump };
 };
 
+export const getMMMGlobalConfigPDA = (programId: PublicKey) => {
+  const [key, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PREFIXES.GLOBAL_CONFIG)],
+    programId,
+  );
+  return { key, bump };
+};
+
+export const getMMMAllowlistRegistryPDA = (
+  programId: PublicKey,
+  uuid: PublicKey,
+) => {
+  const [key, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PREFIXES.ALLOWLIST_REGISTRY), uuid.toBuffer()],
+    programId,
+  );
+  return { key, bump };
+};
+
+export const getMMMOverflowAllowlistPDA = (
+  programId: PublicKey,
+  pool: PublicKey,
+) => {
+  const [key, bump] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PREFIXES.OVERFLOW_ALLOWLIST), pool.toBuffer()],
+    programId,
+  );
+  return { key, bump };
+};
+
 export const getTokenRecordPDA = (mint: PublicKey, tokenAccount: PublicKey) => {
   const [key, bump] = PublicKey.findProgramAddressSync(
     [
@@ -1,4 +1,5 @@
 import { BN } from '@project-serum/anchor';
+import { CurveKind } from './constants';
 
 export interface GetFulfillBuyPricesParams {
   totalPriceLamports: number;
@@ -47,3 +48,454 @@ export const getSolFulfillBuyPrices = (args: GetFulfillBuyPricesParams) => {
     makerFeePaid,
   };
 };
+
+export interface PriceLadderStep {
+  price: BN;
+  amount: BN;
+}
+
+// the ask of the dutch auction curve decays from the spot price by
+// decayPerSecond lamports every second since the auction started, down to the
+// floor
+export interface DutchAuction {
+  floor: BN;
+  decayPerSecond: BN;
+}
+
+// the pool fields that the pricing depends on, as decoded from the pool account
+export interface CurvePriceParams {
+  spotPrice: BN;
+  curveType: number;
+  curveDelta: BN;
+  sellCurveDelta: BN;
+  curveAnchorPrice: BN;
+  asymmetricCurveDelta: boolean;
+  buysidePaymentAmount: BN;
+  sellsideAssetAmount: BN;
+  priceLadder: PriceLadderStep[];
+  priceLadderPosition: BN;
+  dutchAuction: DutchAuction;
+  dutchAuctionStartedAt: BN;
+}
+
+const MAX_TOTAL_PRICE = new BN(8_000_000).mul(new BN(1_000_000_000));
+const BP = new BN(10000);
+const U64_MAX = new BN(2).pow(new BN(64)).subn(1);
+const U128_MAX = new BN(2).pow(new BN(128)).subn(1);
+
+// the checked helpers throw where the u64 and u128 arithmetic of the program
+// overflows, so that the prices are only quoted if the program can fill them
+const checkedSub = (a: BN, b: BN) => {
+  if (a.lt(b)) {
+    throw new Error('NumericOverflow');
+  }
+  return a.sub(b);
+};
+
+const checked = (max: BN) => (a: BN) => {
+  if (a.gt(max)) {
+    throw new Error('NumericOverflow');
+  }
+  return a;
+};
+const checkedU64 = checked(U64_MAX);
+const checkedU128 = checked(U128_MAX);
+
+const linearDown = (p: BN, delta: BN, n: BN): [BN, BN] => {
+  const steps = checkedU64(checkedSub(n, new BN(1)).mul(delta));
+  const total = checkedU64(
+    n.mul(checkedSub(checkedU64(p.muln(2)), steps)),
+  ).divn(2);
+  return [total, checkedSub(p, checkedU64(n.mul(delta)))];
+};
+
+const linearUp = (p: BN, delta: BN, n: BN): [BN, BN] => {
+  const steps = checkedU64(checkedSub(n, new BN(1)).mul(delta));
+  const total = checkedU64(
+    n.mul(checkedU64(checkedU64(p.muln(2)).add(steps))),
+  ).divn(2);
+  return [total, checkedU64(p.add(checkedU64(n.mul(delta))))];
+};
+
+// mirrors the fixed point closed form of the program, which bounds the prices
+// of the per unit loop from below by (p - q) * r^k + q, where every step of the
+// binary exponentiation over the bits of n is rounded down
+const FP_BITS = 64;
+const FP_ONE = new BN(1).shln(FP_BITS);
+const mulFp = (x: BN, y: BN) => checkedU128(x.mul(y).shrn(FP_BITS));
+
+const expLowerBound = (
+  p: BN,
+  num: BN,
+  den: BN,
+  loss: BN,
+  n: BN,
+): [BN, BN] => {
+  const stuck = num.gte(den) ? p.mul(num.sub(den)).lt(den) : p.isZero();
+  if (stuck) {
+    return [checkedU64(p.mul(n)), p];
+  }
+
+  const lossFp = loss.shln(FP_BITS);
+  const r = num.shln(FP_BITS).div(den);
+  let pow = FP_ONE;
+  let sum = new BN(0);
+  for (let i = n.bitLength() - 1; i >= 0; i--) {
+    sum = checkedU128(sum.add(mulFp(sum, pow)));
+    pow = mulFp(pow, pow);
+    if (n.testn(i)) {
+      sum = checkedU128(sum.add(pow));
+      pow = mulFp(pow, r);
+    }
+  }
+
+  const pFp = p.shln(FP_BITS);
+  const saturatingSub = (a: BN, b: BN) => BN.max(a.sub(b), new BN(0));
+  let total: BN;
+  let next: BN;
+  if (num.gt(den)) {
+    const qDown = lossFp.div(num.sub(den));
+    const qUp = lossFp.add(num.sub(den)).subn(1).div(num.sub(den));
+    const pMinusQ = saturatingSub(pFp, qUp);
+    total = checkedU128(mulFp(pMinusQ, sum).add(checkedU128(n.mul(qDown))));
+    next = checkedU128(mulFp(pMinusQ, pow).add(qDown));
+  } else {
+    const qDown = lossFp.div(den.sub(num));
+    const qUp = lossFp.add(den.sub(num)).subn(1).div(den.sub(num));
+    total = saturatingSub(
+      mulFp(pFp, sum),
+      saturatingSub(BN.min(n.mul(qUp), U128_MAX), mulFp(qDown, sum)),
+    );
+    next = saturatingSub(mulFp(pFp, pow), qUp.sub(mulFp(qDown, pow)));
+  }
+  // the first price is p, which the bound of the down curve can fall below
+  if (!n.isZero()) {
+    total = BN.max(total, pFp);
+  }
+  // the prices are integers, so the bounds are rounded up
+  const ceil = (x: BN) => checkedU64(x.add(FP_ONE).subn(1).shrn(FP_BITS));
+  return [ceil(total), ceil(next)];
+};
+
+// the units are exact while the denominator of the ratio divides the price,
+// and the rest of the units are priced by the lower bound
+const exp = (p: BN, num: BN, den: BN, n: BN): [BN, BN] => {
+  const gcd = num.gcd(den);
+  const b = den.div(gcd);
+  let exactUnits = new BN(0);
+  let rest = p;
+  while (
+    exactUnits.lt(n) &&
+    b.gtn(1) &&
+    !rest.isZero() &&
+    rest.mod(b).isZero()
+  ) {
+    exactUnits = exactUnits.addn(1);
+    rest = rest.div(b);
+  }
+
+  const [exactTotal, exactPrice] = expLowerBound(
+    p,
+    num,
+    den,
+    new BN(0),
+    exactUnits,
+  );
+  const [total, next] = expLowerBound(
+    exactPrice,
+    num,
+    den,
+    den.sub(gcd),
+    n.sub(exactUnits),
+  );
+  return [checkedU64(exactTotal.add(total)), next];
+};
+
+const expDown = (p: BN, delta: BN, n: BN): [BN, BN] =>
+  exp(p, BP, delta.add(BP), n);
+
+const expUp = (p: BN, delta: BN, n: BN): [BN, BN] =>
+  exp(p, delta.add(BP), BP, n);
+
+const curveSegment = (
+  curveType: number,
+  p: BN,
+  delta: BN,
+  up: boolean,
+  n: BN,
+): [BN, BN] => {
+  switch (curveType) {
+    case CurveKind.linear:
+    case CurveKind.reverseLinear:
+      return (up ? linearUp : linearDown)(p, delta, n);
+    case CurveKind.exp:
+    case CurveKind.reverseExp:
+      return (up ? expUp : expDown)(p, delta, n);
+    default:
+      throw new Error('InvalidCurveType');
+  }
+};
+
+// the number of units from p until the price reaches the anchor, or null if it
+// doesn't within n units, the exp curves are searched by the next price
+const unitsToAnchor = (
+  curveType: number,
+  p: BN,
+  delta: BN,
+  up: boolean,
+  anchor: BN,
+  n: BN,
+) => {
+  let units: BN;
+  if (
+    curveType === CurveKind.linear ||
+    curveType === CurveKind.reverseLinear
+  ) {
+    if (delta.isZero()) {
+      return null;
+    }
+    const distance = p.sub(anchor).abs();
+    units = distance.add(delta).subn(1).div(delta);
+  } else {
+    const reached = (m: BN) => {
+      try {
+        const next = curveSegment(curveType, p, delta, up, m)[1];
+        return up ? next.gte(anchor) : next.lte(anchor);
+      } catch (e) {
+        return true;
+      }
+    };
+    if (!reached(n)) {
+      return null;
+    }
+    let lo = new BN(1);
+    let hi = n;
+    while (lo.lt(hi)) {
+      const mid = lo.add(hi.sub(lo).divn(2));
+      if (reached(mid)) {
+        hi = mid;
+      } else {
+        lo = mid.addn(1);
+      }
+    }
+    units = lo;
+  }
+  return units.lte(n) ? units : null;
+};
+
+// mirrors the closed form of the asymmetric curve in the program, the units
+// toward the anchor and the units from the anchor on are priced by a segment
+// each
+const asymmetric = (
+  pool: CurvePriceParams,
+  n: BN,
+  fulfillBuy: boolean,
+): [BN, BN] => {
+  const p = pool.spotPrice;
+  if (n.isZero()) {
+    return [new BN(0), p];
+  }
+  const buyUp =
+    pool.curveType === CurveKind.reverseLinear ||
+    pool.curveType === CurveKind.reverseExp;
+  const up = buyUp === fulfillBuy;
+  const anchor = pool.curveAnchorPrice;
+  const [towardDelta, awayDelta] = fulfillBuy
+    ? [pool.sellCurveDelta, pool.curveDelta]
+    : [pool.curveDelta, pool.sellCurveDelta];
+
+  let total = new BN(0);
+  let price = p;
+  let units = n;
+  if (up ? p.lt(anchor) : p.gt(anchor)) {
+    const toAnchor = unitsToAnchor(
+      pool.curveType,
+      p,
+      towardDelta,
+      up,
+      anchor,
+      n,
+    );
+    [total, price] = curveSegment(
+      pool.curveType,
+      p,
+      towardDelta,
+      up,
+      toAnchor ?? n,
+    );
+    if (toAnchor !== null) {
+      price = anchor;
+      units = n.sub(toAnchor);
+    } else {
+      units = new BN(0);
+    }
+  }
+  if (!units.isZero()) {
+    const [awayTotal, next] = curveSegment(
+      pool.curveType,
+      price,
+      awayDelta,
+      up,
+      units,
+    );
+    total = checkedU64(total.add(awayTotal));
+    price = next;
+  }
+  if (fulfillBuy) {
+    return [total, price];
+  }
+  // for sales, all prices will be one "step" away from the spot price
+  return [checkedU64(checkedSub(total, p).add(price)), price];
+};
+
+const ladderSteps = (ladder: PriceLadderStep[]) => {
+  const steps: PriceLadderStep[] = [];
+  for (const step of ladder) {
+    if (step.price.isZero()) {
+      break;
+    }
+    steps.push(step);
+  }
+  return steps;
+};
+
+const ladderPrice = (ladder: PriceLadderStep[], position: BN) => {
+  let start = new BN(0);
+  let price = new BN(0);
+  for (const step of ladderSteps(ladder)) {
+    price = step.price;
+    start = BN.min(start.add(step.amount), U64_MAX);
+    if (position.lt(start)) {
+      break;
+    }
+  }
+  return price;
+};
+
+const ladderTotal = (ladder: PriceLadderStep[], from: BN, to: BN) => {
+  const steps = ladderSteps(ladder);
+  let total = new BN(0);
+  let start = new BN(0);
+  steps.forEach((step, i) => {
+    const end =
+      i === steps.length - 1
+        ? U64_MAX
+        : BN.min(start.add(step.amount), U64_MAX);
+    const units = BN.max(BN.min(end, to).sub(BN.max(start, from)), new BN(0));
+    total = checkedU64(total.add(checkedU64(units.mul(step.price))));
+    start = end;
+  });
+  return total;
+};
+
+const curvePrices = (
+  pool: CurvePriceParams,
+  n: BN,
+  fulfillBuy: boolean,
+  now: number,
+): [BN, BN] => {
+  const p = pool.spotPrice;
+  const delta = pool.curveDelta;
+  if (pool.asymmetricCurveDelta) {
+    return asymmetric(pool, n, fulfillBuy);
+  }
+  switch (pool.curveType) {
+    case CurveKind.constantProduct: {
+      const x = pool.buysidePaymentAmount;
+      const y = checkedU64(pool.sellsideAssetAmount.add(delta));
+      if (fulfillBuy) {
+        const yAfter = y.add(n);
+        const total = x.mul(n).div(yAfter);
+        return [total, x.sub(total).div(yAfter)];
+      }
+      // the sales are priced from the payment reserve
+      if (x.isZero()) {
+        throw new Error('InvalidSpotPrice');
+      }
+      const yAfter = checkedSub(y, n);
+      if (yAfter.isZero()) {
+        throw new Error('NumericOverflow');
+      }
+      const total = checkedU64(x.mul(n).add(yAfter.subn(1)).div(yAfter));
+      return [total, checkedU64(x.add(total).div(yAfter))];
+    }
+    case CurveKind.priceLadder: {
+      const position = pool.priceLadderPosition;
+      if (fulfillBuy) {
+        const end = position.add(n);
+        return [
+          ladderTotal(pool.priceLadder, position, end),
+          ladderPrice(pool.priceLadder, end),
+        ];
+      }
+      const start = BN.max(position.sub(n), new BN(0));
+      const beyond = n.sub(position.sub(start));
+      return [
+        checkedU64(
+          ladderTotal(pool.priceLadder, start, position).add(
+            checkedU64(beyond.mul(pool.priceLadder[0].price)),
+          ),
+        ),
+        ladderPrice(pool.priceLadder, start),
+      ];
+    }
+    case CurveKind.dutchAuction: {
+      if (fulfillBuy) {
+        throw new Error('InvalidCurveType');
+      }
+      const elapsed = BN.max(
+        new BN(now).sub(pool.dutchAuctionStartedAt),
+        new BN(0),
+      );
+      const decayed = p.sub(pool.dutchAuction.decayPerSecond.mul(elapsed));
+      const ask = BN.max(decayed, pool.dutchAuction.floor);
+      return linearUp(ask, delta, n);
+    }
+  }
+
+  const buyUp =
+    pool.curveType === CurveKind.reverseLinear ||
+    pool.curveType === CurveKind.reverseExp;
+  // the price moves down for fulfill_buy, unless the curve is reversed
+  const up = buyUp === fulfillBuy;
+  let ret: [BN, BN];
+  switch (pool.curveType) {
+    case CurveKind.linear:
+    case CurveKind.reverseLinear:
+      ret = (up ? linearUp : linearDown)(p, delta, n);
+      break;
+    case CurveKind.exp:
+    case CurveKind.reverseExp:
+      ret = (up ? expUp : expDown)(p, delta, n);
+      break;
+    default:
+      throw new Error('InvalidCurveType');
+  }
+  if (fulfillBuy) {
+    return ret;
+  }
+  // for sales, all prices will be one "step" away from the spot price
+  return [checkedU64(checkedSub(ret[0], p).add(ret[1])), ret[1]];
+};
+
+// getSolTotalPriceAndNextPrice mirrors get_sol_total_price_and_next_price of
+// the program, now is the unix timestamp that the dutch auction decays until
+export const getSolTotalPriceAndNextPrice = (
+  pool: CurvePriceParams,
+  n: number,
+  fulfillBuy: boolean,
+  allowlistMultiplierBp = 10000,
+  now = Math.floor(Date.now() / 1000),
+) => {
+  const [curveTotalPrice, nextPrice] = curvePrices(
+    pool,
+    new BN(n),
+    fulfillBuy,
+    now,
+  );
+  const totalPrice = curveTotalPrice.muln(allowlistMultiplierBp).div(BP);
+  if (totalPrice.isZero() || totalPrice.gt(MAX_TOTAL_PRICE)) {
+    throw new Error('NumericOverflow');
+  }
+  return { totalPrice, nextPrice };
+};
@@ -11,10 +11,19 @@ import {
   Mmm,
   CurveKind,
   AllowlistKind,
+  getMMMBuysideSolEscrowPDA,
   getMMMPoolPDA,
+  getMMMSellStatePDA,
   MMMProgramID,
 } from '../sdk/src';
-import { airdrop, getEmptyAllowLists } from './utils';
+import {
+  airdrop,
+  createPool,
+  createPoolWithExampleDeposits,
+  getEmptyAllowLists,
+  getEmptyPriceLadder,
+  getGlobalConfig,
+} from './utils';
 
 describe('mmm-admin', () => {
   const { connection } = anchor.AnchorProvider.env();
@@ -28,6 +37,11 @@ describe('mmm-admin', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+
+  before(async () => {
+    ({ globalConfig } = await getGlobalConfig(program));
+  });
 
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
@@ -54,13 +68,19 @@ describe('mmm-admin', () => {
           spotPrice: new anchor.BN(1 * LAMPORTS_PER_SOL),
           curveType: CurveKind.linear,
           curveDelta: new anchor.BN(0),
+          sellCurveDelta: null,
           reinvestFulfillBuy: true,
           reinvestFulfillSell: true,
           expiry: new anchor.BN(42),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 200,
           referral: referral.publicKey,
           cosignerAnnotation: new Array(32).fill(0),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: getEmptyPriceLadder(),
+          dutchAuction: null,
 
           uuid: uuid.publicKey,
           paymentMint: PublicKey.default,
@@ -69,6 +89,7 @@ describe('mmm-admin', () => {
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolKey,
           systemProgram: SystemProgram.programId,
         })
@@ -100,6 +121,89 @@ describe('mmm-admin', () => {
       assert.deepEqual(poolAccountInfo.paymentMint, PublicKey.default);
       assert.deepEqual(poolAccountInfo.allowlists, allowlists);
     });
+
+    it('with a price ladder', async () => {
+      const priceLadder = [
+        {
+          price: new anchor.BN(2 * LAMPORTS_PER_SOL),
+          amount: new anchor.BN(3),
+        },
+        {
+          price: new anchor.BN(1 * LAMPORTS_PER_SOL),
+          amount: new anchor.BN(0),
+        },
+        ...getEmptyPriceLadder(4),
+      ];
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        curveType: CurveKind.priceLadder,
+        priceLadder,
+      });
+
+      // the spot price is a cache of the ladder
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.equal(poolAccountInfo.spotPrice.toNumber(), 2 * LAMPORTS_PER_SOL);
+      assert.equal(poolAccountInfo.priceLadderPosition.toNumber(), 0);
+      assert.equal(
+        poolAccountInfo.priceLadder[1].price.toNumber(),
+        1 * LAMPORTS_PER_SOL,
+      );
+
+      try {
+        await createPool(program, {
+          owner: wallet.publicKey,
+          cosigner,
+          curveType: CurveKind.priceLadder,
+        });
+        assert.fail('Should have failed with an empty price ladder');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidPriceLadder');
+      }
+    });
+
+    it('with a dutch auction', async () => {
+      const dutchAuction = {
+        floor: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+        decayPerSecond: new anchor.BN(1000),
+      };
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        curveType: CurveKind.dutchAuction,
+        dutchAuction,
+      });
+
+      // the auction starts with the pool
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.equal(poolAccountInfo.curveType, CurveKind.dutchAuction);
+      assert.equal(
+        poolAccountInfo.dutchAuction.floor.toNumber(),
+        dutchAuction.floor.toNumber(),
+      );
+      assert.equal(
+        poolAccountInfo.dutchAuction.decayPerSecond.toNumber(),
+        1000,
+      );
+      assert.isAbove(poolAccountInfo.dutchAuctionStartedAt.toNumber(), 0);
+
+      for (const invalidDutchAuction of [
+        null,
+        { ...dutchAuction, floor: new anchor.BN(2 * LAMPORTS_PER_SOL) },
+      ]) {
+        try {
+          await createPool(program, {
+            owner: wallet.publicKey,
+            cosigner,
+            curveType: CurveKind.dutchAuction,
+            dutchAuction: invalidDutchAuction,
+          });
+          assert.fail('Should have failed with an invalid dutch auction');
+        } catch (e) {
+          assert.include(`${e}`, 'InvalidDutchAuction');
+        }
+      }
+    });
   });
 
   describe('Can update sol mmm', () => {
@@ -123,13 +227,19 @@ describe('mmm-admin', () => {
           spotPrice: new anchor.BN(1 * LAMPORTS_PER_SOL),
           curveType: CurveKind.linear,
           curveDelta: new anchor.BN(0),
+          sellCurveDelta: null,
           reinvestFulfillBuy: true,
           reinvestFulfillSell: true,
           expiry: new anchor.BN(42),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 200,
           referral: referral.publicKey,
           cosignerAnnotation: new Array(32).fill(0),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: getEmptyPriceLadder(),
+          dutchAuction: null,
 
           uuid: uuid.publicKey,
           paymentMint: PublicKey.default,
@@ -138,6 +248,7 @@ describe('mmm-admin', () => {
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolKey,
           systemProgram: SystemProgram.programId,
         })
@@ -149,17 +260,24 @@ describe('mmm-admin', () => {
           spotPrice: new anchor.BN(2 * LAMPORTS_PER_SOL),
           curveType: CurveKind.exp,
           curveDelta: new anchor.BN(888),
+          sellCurveDelta: null,
           reinvestFulfillBuy: true,
           reinvestFulfillSell: true,
           expiry: new anchor.BN(0),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 150,
           referral: PublicKey.default,
           cosignerAnnotation: new Array(32).fill(0).map((_, index) => index),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: null,
+          dutchAuction: null,
         })
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolKey,
         })
         .signers([cosigner])
@@ -188,4 +306,439 @@ describe('mmm-admin', () => {
       assert.deepEqual(poolAccountInfo.allowlists, allowlists);
     });
   });
+
+  describe('Can transfer the pool ownership', () => {
+    it('happy path', async () => {
+      const newOwner = Keypair.generate();
+      const [poolData] = await Promise.all([
+        createPoolWithExampleDeposits(
+          program,
+          connection,
+          [AllowlistKind.fvca],
+          { owner: wallet.publicKey, cosigner },
+          'sell',
+        ),
+        airdrop(connection, newOwner.publicKey, 10),
+      ]);
+      const { key: sellState } = getMMMSellStatePDA(
+        program.programId,
+        poolData.poolKey,
+        poolData.nft.mintAddress,
+      );
+
+      await program.methods
+        .proposePoolOwner({ newOwner: newOwner.publicKey })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolData.poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      let poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+      assert.deepEqual(poolAccountInfo.owner, wallet.publicKey);
+      assert.deepEqual(poolAccountInfo.pendingOwner, newOwner.publicKey);
+
+      await program.methods
+        .acceptPoolOwner()
+        .accountsStrict({
+          newOwner: newOwner.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolData.poolKey,
+        })
+        .signers([newOwner, cosigner])
+        .rpc();
+      await program.methods
+        .syncSellStateOwner()
+        .accountsStrict({ pool: poolData.poolKey })
+        .remainingAccounts([
+          { pubkey: sellState, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      // the pool keeps the address derived from the original owner
+      poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+      assert.deepEqual(poolAccountInfo.owner, newOwner.publicKey);
+      assert.deepEqual(poolAccountInfo.originalOwner, wallet.publicKey);
+      assert.deepEqual(poolAccountInfo.pendingOwner, PublicKey.default);
+      const sellStateAccountInfo = await program.account.sellState.fetch(
+        sellState,
+      );
+      assert.deepEqual(sellStateAccountInfo.poolOwner, newOwner.publicKey);
+
+      try {
+        await program.methods
+          .proposePoolOwner({ newOwner: wallet.publicKey })
+          .accountsStrict({
+            owner: wallet.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolData.poolKey,
+          })
+          .signers([cosigner])
+          .rpc();
+        assert.fail('Should have failed with the previous owner');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidOwner');
+      }
+    });
+
+    it('drops the delegations of the previous owner', async () => {
+      const newOwner = Keypair.generate();
+      const operator = Keypair.generate();
+      const [{ poolKey }] = await Promise.all([
+        createPool(program, {
+          owner: wallet.publicKey,
+          cosigner,
+        }),
+        airdrop(connection, newOwner.publicKey, 10),
+      ]);
+      await program.methods
+        .updatePoolOperator({ operator: operator.publicKey })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      // requested by the owner alone, so it's pending until the timelock passes
+      await program.methods
+        .updatePoolCosigner({
+          newCosigner: operator.publicKey,
+          noCosigner: true,
+        })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .rpc();
+
+      await program.methods
+        .proposePoolOwner({ newOwner: newOwner.publicKey })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      await program.methods
+        .acceptPoolOwner()
+        .accountsStrict({
+          newOwner: newOwner.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([newOwner, cosigner])
+        .rpc();
+
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(poolAccountInfo.owner, newOwner.publicKey);
+      assert.deepEqual(poolAccountInfo.operator, PublicKey.default);
+      assert.deepEqual(poolAccountInfo.pendingCosigner, PublicKey.default);
+      assert.isFalse(poolAccountInfo.pendingNoCosigner);
+      assert.equal(poolAccountInfo.cosignerRotationRequestedAt.toNumber(), 0);
+
+      // the operator of the previous owner can't reprice the pool
+      try {
+        await program.methods
+          .operatorUpdatePool({
+            spotPrice: new anchor.BN(1),
+            curveDelta: new anchor.BN(0),
+            sellCurveDelta: null,
+            lpFeeBp: 0,
+            expiry: new anchor.BN(0),
+            startTime: new anchor.BN(0),
+            buysideExpiry: new anchor.BN(0),
+            sellsideExpiry: new anchor.BN(0),
+            cosignerAnnotation: new Array(32).fill(0),
+          })
+          .accountsStrict({
+            operator: operator.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+            globalConfig,
+          })
+          .signers([operator, cosigner])
+          .rpc();
+        assert.fail('Should have failed with the previous operator');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidOperator');
+      }
+    });
+
+    it('can only be accepted by the pending owner', async () => {
+      const newOwner = Keypair.generate();
+      const stranger = Keypair.generate();
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+
+      try {
+        await program.methods
+          .acceptPoolOwner()
+          .accountsStrict({
+            newOwner: newOwner.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+          })
+          .signers([newOwner, cosigner])
+          .rpc();
+        assert.fail('Should have failed without a pending owner');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidPendingOwner');
+      }
+
+      await program.methods
+        .proposePoolOwner({ newOwner: newOwner.publicKey })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      try {
+        await program.methods
+          .acceptPoolOwner()
+          .accountsStrict({
+            newOwner: stranger.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+          })
+          .signers([stranger, cosigner])
+          .rpc();
+        assert.fail('Should have failed with a stranger');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidPendingOwner');
+      }
+
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(poolAccountInfo.owner, wallet.publicKey);
+      assert.deepEqual(poolAccountInfo.pendingOwner, newOwner.publicKey);
+    });
+  });
+
+  describe('Can rotate the pool cosigner', () => {
+    it('happy path', async () => {
+      const newCosigner = Keypair.generate();
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+
+      // signed by the current cosigner, the rotation is applied right away
+      await program.methods
+        .updatePoolCosigner({
+          newCosigner: newCosigner.publicKey,
+          noCosigner: false,
+        })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      let poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(poolAccountInfo.cosigner, newCosigner.publicKey);
+      assert.isFalse(poolAccountInfo.noCosigner);
+      assert.equal(poolAccountInfo.cosignerRotationRequestedAt.toNumber(), 0);
+
+      const { key: solEscrowKey } = getMMMBuysideSolEscrowPDA(
+        program.programId,
+        poolKey,
+      );
+      const depositBuy = () =>
+        program.methods
+          .solDepositBuy({ paymentAmount: new anchor.BN(LAMPORTS_PER_SOL) })
+          .accountsStrict({
+            owner: wallet.publicKey,
+            cosigner: newCosigner.publicKey,
+            pool: poolKey,
+            buysideSolEscrowAccount: solEscrowKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      try {
+        await depositBuy();
+        assert.fail('Should have failed without the cosigner signature');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidCosigner');
+      }
+
+      // in the no-cosigner mode the owner can act alone
+      await program.methods
+        .updatePoolCosigner({
+          newCosigner: newCosigner.publicKey,
+          noCosigner: true,
+        })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: newCosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([newCosigner])
+        .rpc();
+      await depositBuy();
+      poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.isTrue(poolAccountInfo.noCosigner);
+      assert.equal(
+        poolAccountInfo.buysidePaymentAmount.toNumber(),
+        LAMPORTS_PER_SOL,
+      );
+    });
+
+    it('is timelocked without the current cosigner', async () => {
+      const newCosigner = Keypair.generate();
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+      const updatePoolCosigner = () =>
+        program.methods
+          .updatePoolCosigner({
+            newCosigner: newCosigner.publicKey,
+            noCosigner: false,
+          })
+          .accountsStrict({
+            owner: wallet.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+          })
+          .rpc();
+
+      // the first call by the owner alone only requests the rotation
+      await updatePoolCosigner();
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(poolAccountInfo.cosigner, cosigner.publicKey);
+      assert.deepEqual(poolAccountInfo.pendingCosigner, newCosigner.publicKey);
+      assert.isAbove(poolAccountInfo.cosignerRotationRequestedAt.toNumber(), 0);
+
+      try {
+        await updatePoolCosigner();
+        assert.fail('Should have failed before the timelock passed');
+      } catch (e) {
+        assert.include(`${e}`, 'CosignerRotationTimelocked');
+      }
+    });
+  });
+
+  describe('Can delegate the pricing to an operator', () => {
+    const getOperatorUpdatePoolArgs = (spotPrice: number) => ({
+      spotPrice: new anchor.BN(spotPrice),
+      curveDelta: new anchor.BN(0),
+      sellCurveDelta: null,
+      lpFeeBp: 100,
+      expiry: new anchor.BN(0),
+      startTime: new anchor.BN(0),
+      buysideExpiry: new anchor.BN(0),
+      sellsideExpiry: new anchor.BN(0),
+      cosignerAnnotation: new Array(32).fill(0),
+    });
+
+    it('happy path', async () => {
+      const operator = Keypair.generate();
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+
+      await program.methods
+        .updatePoolOperator({ operator: operator.publicKey })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      await program.methods
+        .operatorUpdatePool(getOperatorUpdatePoolArgs(2 * LAMPORTS_PER_SOL))
+        .accountsStrict({
+          operator: operator.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+          globalConfig,
+        })
+        .signers([operator, cosigner])
+        .rpc();
+
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(poolAccountInfo.operator, operator.publicKey);
+      assert.deepEqual(poolAccountInfo.owner, wallet.publicKey);
+      assert.equal(poolAccountInfo.spotPrice.toNumber(), 2 * LAMPORTS_PER_SOL);
+      assert.equal(poolAccountInfo.lpFeeBp, 100);
+    });
+
+    it('rejects the other signers', async () => {
+      const operator = Keypair.generate();
+      const stranger = Keypair.generate();
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+      const operatorUpdatePool = (signer: Keypair) =>
+        program.methods
+          .operatorUpdatePool(getOperatorUpdatePoolArgs(2 * LAMPORTS_PER_SOL))
+          .accountsStrict({
+            operator: signer.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+            globalConfig,
+          })
+          .signers([signer, cosigner])
+          .rpc();
+
+      try {
+        await operatorUpdatePool(operator);
+        assert.fail('Should have failed without an operator');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidOperator');
+      }
+
+      await program.methods
+        .updatePoolOperator({ operator: operator.publicKey })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+      try {
+        await operatorUpdatePool(stranger);
+        assert.fail('Should have failed with a stranger');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidOperator');
+      }
+
+      // the operator can't act as the owner
+      const { key: solEscrowKey } = getMMMBuysideSolEscrowPDA(
+        program.programId,
+        poolKey,
+      );
+      try {
+        await program.methods
+          .solDepositBuy({ paymentAmount: new anchor.BN(LAMPORTS_PER_SOL) })
+          .accountsStrict({
+            owner: operator.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+            buysideSolEscrowAccount: solEscrowKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([operator, cosigner])
+          .rpc();
+        assert.fail('Should have failed with the operator as the owner');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidOwner');
+      }
+
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.equal(poolAccountInfo.spotPrice.toNumber(), 1 * LAMPORTS_PER_SOL);
+    });
+  });
 });
@@ -0,0 +1,436 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  getAssociatedTokenAddress,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  getMMMAllowlistRegistryPDA,
+  getMMMOverflowAllowlistPDA,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createPool,
+  fillAllowlists,
+  getMetaplexInstance,
+  mintNfts,
+} from './utils';
+
+describe('mmm-allowlist', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  const mintNft = async () =>
+    (
+      await mintNfts(connection, { numNfts: 1, recipient: wallet.publicKey })
+    )[0];
+
+  const depositSell = async (
+    poolKey: PublicKey,
+    nft: Awaited<ReturnType<typeof mintNft>>,
+    allowlistAuxAccount: PublicKey,
+  ) => {
+    const metaplexInstance = getMetaplexInstance(connection);
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolKey,
+      nft.mintAddress,
+    );
+    await program.methods
+      .depositSell({ assetAmount: new anchor.BN(1), allowlistAux: null })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        assetMetadata: nft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: nft.mintAddress }),
+        assetMint: nft.mintAddress,
+        assetTokenAccount: nft.tokenAddress!,
+        sellsideEscrowTokenAccount: await getAssociatedTokenAddress(
+          nft.mintAddress,
+          poolKey,
+          true,
+        ),
+        allowlistAuxAccount,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([cosigner])
+      .rpc();
+  };
+
+  const updateOverflowAllowlist = (
+    poolKey: PublicKey,
+    allowlists: { kind: number; value: PublicKey }[],
+  ) =>
+    program.methods
+      .updateOverflowAllowlist({ allowlists })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        overflowAllowlist: getMMMOverflowAllowlistPDA(
+          program.programId,
+          poolKey,
+        ).key,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cosigner])
+      .rpc();
+
+  describe('mutable allowlists', () => {
+    const updateAllowlists = (
+      poolKey: PublicKey,
+      allowlists: { kind: number; value: PublicKey }[],
+    ) =>
+      program.methods
+        .updateAllowlists({
+          allowlists: fillAllowlists(allowlists, 6),
+          allowlistPriceMultipliersBp: [0, 0, 0, 0, 0, 0],
+        })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+
+    it('updates the allowlists of the pool', async () => {
+      const nft = await mintNft();
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+      try {
+        await depositSell(poolKey, nft, SystemProgram.programId);
+        assert.fail('Should have failed before the update');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+
+      const allowlists = [{ kind: AllowlistKind.mint, value: nft.mintAddress }];
+      await updateAllowlists(poolKey, allowlists);
+      await depositSell(poolKey, nft, SystemProgram.programId);
+
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(
+        poolAccountInfo.allowlists,
+        fillAllowlists(allowlists, 6),
+      );
+      assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 1);
+
+      try {
+        await updateAllowlists(poolKey, [{ kind: 13, value: nft.mintAddress }]);
+        assert.fail('Should have failed with an unknown kind');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+    });
+
+    it('extends the allowlists with the overflow allowlist', async () => {
+      const [nft, otherNft] = await Promise.all([mintNft(), mintNft()]);
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        allowlists: fillAllowlists(
+          [{ kind: AllowlistKind.mint, value: otherNft.mintAddress }],
+          6,
+        ),
+      });
+      const { key: overflowAllowlist } = getMMMOverflowAllowlistPDA(
+        program.programId,
+        poolKey,
+      );
+      await updateOverflowAllowlist(poolKey, [
+        { kind: AllowlistKind.mint, value: nft.mintAddress },
+      ]);
+
+      // the overflow allowlist has to be passed in as the allowlist aux account
+      try {
+        await depositSell(poolKey, nft, SystemProgram.programId);
+        assert.fail('Should have failed without the overflow allowlist');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+      await depositSell(poolKey, nft, overflowAllowlist);
+      await depositSell(poolKey, otherNft, overflowAllowlist);
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 2);
+
+      // an empty allowlists closes the overflow allowlist
+      await updateOverflowAllowlist(poolKey, []);
+      assert.isNull(await connection.getAccountInfo(overflowAllowlist));
+    });
+  });
+
+  describe('allowlist registry', () => {
+    const curator = Keypair.generate();
+    const curatorProgram = new anchor.Program<Mmm>(
+      IDL,
+      MMMProgramID,
+      new anchor.AnchorProvider(connection, new anchor.Wallet(curator), {
+        commitment: 'processed',
+      }),
+    );
+
+    const createRegistry = async (
+      entries: { kind: number; value: PublicKey }[],
+    ) => {
+      const uuid = Keypair.generate().publicKey;
+      const { key: registry } = getMMMAllowlistRegistryPDA(
+        program.programId,
+        uuid,
+      );
+      await curatorProgram.methods
+        .createAllowlistRegistry({ uuid, entries })
+        .accountsStrict({
+          curator: curator.publicKey,
+          registry,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      return registry;
+    };
+
+    before(async () => {
+      await airdrop(connection, curator.publicKey, 10);
+    });
+
+    it('resolves the registry entries of the pool', async () => {
+      const [nft, otherNft] = await Promise.all([mintNft(), mintNft()]);
+      const registry = await createRegistry([
+        { kind: AllowlistKind.mint, value: nft.mintAddress },
+      ]);
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        allowlists: fillAllowlists(
+          [{ kind: AllowlistKind.registry, value: registry }],
+          6,
+        ),
+      });
+
+      // the registry has to be passed in as the allowlist aux account
+      try {
+        await depositSell(poolKey, nft, SystemProgram.programId);
+        assert.fail('Should have failed without the registry');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+      await depositSell(poolKey, nft, registry);
+
+      // the updates of the curator apply to the pool right away
+      await curatorProgram.methods
+        .updateAllowlistRegistry({
+          newCurator: curator.publicKey,
+          entries: [{ kind: AllowlistKind.mint, value: otherNft.mintAddress }],
+        })
+        .accountsStrict({ curator: curator.publicKey, registry })
+        .rpc();
+      const registryAccount = await program.account.allowlistRegistry.fetch(
+        registry,
+      );
+      assert.equal(registryAccount.revision.toNumber(), 1);
+      await depositSell(poolKey, otherNft, registry);
+
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 2);
+    });
+
+    it('ignores a registry that the pool does not reference', async () => {
+      const nft = await mintNft();
+      const registry = await createRegistry([
+        { kind: AllowlistKind.mint, value: nft.mintAddress },
+      ]);
+      const otherRegistry = await createRegistry([
+        { kind: AllowlistKind.mint, value: nft.mintAddress },
+      ]);
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        allowlists: fillAllowlists(
+          [{ kind: AllowlistKind.registry, value: otherRegistry }],
+          6,
+        ),
+      });
+
+      try {
+        await depositSell(poolKey, nft, registry);
+        assert.fail('Should have failed with a registry of another pool');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+    });
+
+    it('resolves the aux entries from one account only', async () => {
+      const nft = await mintNft();
+      const registry = await createRegistry([
+        { kind: AllowlistKind.mint, value: nft.mintAddress },
+      ]);
+      const otherRegistry = await createRegistry([
+        { kind: AllowlistKind.mint, value: nft.mintAddress },
+      ]);
+
+      // the taker could pick the price tier of the nft by the registry
+      try {
+        await createPool(program, {
+          owner: wallet.publicKey,
+          cosigner,
+          allowlists: fillAllowlists(
+            [
+              { kind: AllowlistKind.registry, value: registry },
+              { kind: AllowlistKind.registry, value: otherRegistry },
+            ],
+            6,
+          ),
+        });
+        assert.fail('Should have failed with two registries');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+
+      // or by passing in the overflow allowlist in place of the registry
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        allowlists: fillAllowlists(
+          [{ kind: AllowlistKind.registry, value: registry }],
+          6,
+        ),
+      });
+      try {
+        await updateOverflowAllowlist(poolKey, [
+          { kind: AllowlistKind.mint, value: nft.mintAddress },
+        ]);
+        assert.fail('Should have failed with the registry of the pool');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+    });
+
+    it('only lets the curator update the registry', async () => {
+      const registry = await createRegistry([
+        { kind: AllowlistKind.mint, value: Keypair.generate().publicKey },
+      ]);
+      try {
+        await program.methods
+          .updateAllowlistRegistry({
+            newCurator: wallet.publicKey,
+            entries: [],
+          })
+          .accountsStrict({ curator: wallet.publicKey, registry })
+          .rpc();
+        assert.fail('Should have failed with another curator');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidCurator');
+      }
+    });
+
+    it('rejects registry entries in the overflow allowlist', async () => {
+      const registry = await createRegistry([
+        { kind: AllowlistKind.mint, value: Keypair.generate().publicKey },
+      ]);
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+      const { key: overflowAllowlist } = getMMMOverflowAllowlistPDA(
+        program.programId,
+        poolKey,
+      );
+      try {
+        await program.methods
+          .updateOverflowAllowlist({
+            allowlists: [{ kind: AllowlistKind.registry, value: registry }],
+          })
+          .accountsStrict({
+            owner: wallet.publicKey,
+            cosigner: cosigner.publicKey,
+            pool: poolKey,
+            overflowAllowlist,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([cosigner])
+          .rpc();
+        assert.fail('Should have failed with a registry entry');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+    });
+  });
+
+  describe('allowlist price multipliers', () => {
+    const updateAllowlists = (
+      poolKey: PublicKey,
+      allowlistPriceMultipliersBp: number[],
+    ) =>
+      program.methods
+        .updateAllowlists({
+          allowlists: fillAllowlists(
+            [{ kind: AllowlistKind.fvca, value: wallet.publicKey }],
+            6,
+          ),
+          allowlistPriceMultipliersBp,
+        })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolKey,
+        })
+        .signers([cosigner])
+        .rpc();
+
+    it('bounds the multipliers', async () => {
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+      });
+      // 0 keeps the price, e.g. for the entries without a multiplier
+      await updateAllowlists(poolKey, [20000, 5000, 0, 0, 0, 0]);
+      const poolAccountInfo = await program.account.pool.fetch(poolKey);
+      assert.deepEqual(
+        poolAccountInfo.allowlistPriceMultipliersBp,
+        [20000, 5000, 0, 0, 0, 0],
+      );
+
+      try {
+        await updateAllowlists(poolKey, [20001, 0, 0, 0, 0, 0]);
+        assert.fail('Should have failed with a multiplier above the bound');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidPriceMultiplier');
+      }
+    });
+  });
+});
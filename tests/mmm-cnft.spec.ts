@@ -0,0 +1,157 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+  SPL_NOOP_PROGRAM_ID,
+} from '@solana/spl-account-compression';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  ALLOWLIST_FLAG_DENY,
+  AllowlistKind,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  BUBBLEGUM_PROGRAM_ID,
+  createPool,
+  createTree,
+  fillAllowlists,
+  getTreeRoot,
+  mintCnft,
+} from './utils';
+
+describe('mmm-cnft', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+  const creator = Keypair.generate();
+  let merkleTree: PublicKey;
+  let treeAuthority: PublicKey;
+
+  before(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+    ({ merkleTree, treeAuthority } = await createTree(
+      connection,
+      wallet.payer,
+    ));
+  });
+
+  const mintWalletCnft = () =>
+    mintCnft(connection, wallet.payer, {
+      merkleTree,
+      owner: wallet.publicKey,
+      creators: [{ address: creator.publicKey, verified: false, share: 100 }],
+    });
+
+  const cnftDepositSell = async (
+    poolKey: PublicKey,
+    cnft: Awaited<ReturnType<typeof mintWalletCnft>>,
+  ) =>
+    program.methods
+      .cnftDepositSell({
+        root: await getTreeRoot(connection, merkleTree),
+        metadataArgs: cnft.metadataArgs,
+        nonce: cnft.nonce,
+        index: cnft.index,
+        allowlistAux: null,
+      })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        leafDelegate: wallet.publicKey,
+        treeAuthority,
+        merkleTree,
+        sellState: getMMMSellStatePDA(program.programId, poolKey, cnft.assetId)
+          .key,
+        allowlistAuxAccount: SystemProgram.programId,
+        logWrapper: SPL_NOOP_PROGRAM_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([cosigner])
+      .rpc();
+
+  it('deposits a compressed nft', async () => {
+    const cnft = await mintWalletCnft();
+    const { poolKey } = await createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      allowlists: fillAllowlists(
+        [{ kind: AllowlistKind.mint, value: cnft.assetId }],
+        6,
+      ),
+    });
+
+    await cnftDepositSell(poolKey, cnft);
+    const poolAccountInfo = await program.account.pool.fetch(poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 1);
+    const sellStateAccountInfo = await program.account.sellState.fetch(
+      getMMMSellStatePDA(program.programId, poolKey, cnft.assetId).key,
+    );
+    assert.deepEqual(sellStateAccountInfo.assetMint, cnft.assetId);
+    assert.equal(sellStateAccountInfo.assetAmount.toNumber(), 1);
+  });
+
+  it('rejects an unverified first creator', async () => {
+    const cnft = await mintWalletCnft();
+    const { poolKey } = await createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      allowlists: fillAllowlists(
+        [{ kind: AllowlistKind.fvca, value: creator.publicKey }],
+        6,
+      ),
+    });
+
+    try {
+      await cnftDepositSell(poolKey, cnft);
+      assert.fail('Should have failed with an unverified creator');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidAllowLists');
+    }
+    const poolAccountInfo = await program.account.pool.fetch(poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 0);
+  });
+
+  it('rejects pools with merkle or update authority entries', async () => {
+    const cnft = await mintWalletCnft();
+    // the leaves have no update authority and there is no room for a merkle
+    // proof of the allowlist, so even the deny entries reject every cnft
+    for (const kind of [
+      AllowlistKind.merkle | ALLOWLIST_FLAG_DENY,
+      AllowlistKind.updateAuthority,
+    ]) {
+      const { poolKey } = await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        allowlists: fillAllowlists(
+          [
+            { kind: AllowlistKind.mint, value: cnft.assetId },
+            { kind, value: Keypair.generate().publicKey },
+          ],
+          6,
+        ),
+      });
+
+      try {
+        await cnftDepositSell(poolKey, cnft);
+        assert.fail('Should have failed with a merkle or update authority');
+      } catch (e) {
+        assert.include(`${e}`, 'InvalidAllowLists');
+      }
+    }
+  });
+});
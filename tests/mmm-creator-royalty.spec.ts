@@ -8,6 +8,7 @@ import {
 import {
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
 } from '@solana/web3.js';
@@ -25,6 +26,7 @@ import {
   airdrop,
   assertTx,
   createPoolWithExampleDeposits,
+  getGlobalConfig,
   getMetaplexInstance,
   getSellStatePDARent,
   getTokenAccountRent,
@@ -44,6 +46,12 @@ describe('mmm-creator-royalty', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
 
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
@@ -124,6 +132,8 @@ describe('mmm-creator-royalty', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -237,6 +247,8 @@ describe('mmm-creator-royalty', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
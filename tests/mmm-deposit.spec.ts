@@ -8,6 +8,7 @@ import {
 import {
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
 } from '@solana/web3.js';
@@ -27,6 +28,7 @@ import {
   createPool,
   createPoolWithExampleDeposits,
   getEmptyAllowLists,
+  getGlobalConfig,
   getMetadataURI,
   getMetaplexInstance,
   mintCollection,
@@ -45,6 +47,12 @@ describe('mmm-deposit', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
 
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
@@ -201,6 +209,8 @@ describe('mmm-deposit', () => {
               payer: seller.publicKey,
               owner: wallet.publicKey,
               cosigner: cosigner.publicKey,
+              globalConfig,
+              treasury,
               referral: poolData.referral.publicKey,
               pool: poolData.poolKey,
               buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -0,0 +1,248 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  createMint,
+  getAccount as getTokenAccount,
+  getAssociatedTokenAddress,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  getMMMBuysideSolEscrowPDA,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import { airdrop, createPool, fillAllowlists, getGlobalConfig } from './utils';
+
+describe('mmm-ext', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  // a token-2022 nft without extensions, owned by the wallet
+  const createExtNft = async () => {
+    const mint = await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      null,
+      0,
+      Keypair.generate(),
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    const tokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      mint,
+      wallet.publicKey,
+      false,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    await mintTo(
+      connection,
+      wallet.payer,
+      mint,
+      tokenAccount.address,
+      wallet.publicKey,
+      1,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    return { mint, tokenAccount: tokenAccount.address };
+  };
+
+  const getExtAta = (mint: PublicKey, owner: PublicKey) =>
+    getAssociatedTokenAddress(mint, owner, true, TOKEN_2022_PROGRAM_ID);
+
+  const extDepositSell = async (
+    poolKey: PublicKey,
+    nft: Awaited<ReturnType<typeof createExtNft>>,
+  ) =>
+    program.methods
+      .extDepositSell({ assetAmount: new anchor.BN(1), allowlistAux: null })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        assetMint: nft.mint,
+        assetTokenAccount: nft.tokenAccount,
+        sellsideEscrowTokenAccount: await getExtAta(nft.mint, poolKey),
+        sellState: getMMMSellStatePDA(program.programId, poolKey, nft.mint)
+          .key,
+        allowlistAuxAccount: SystemProgram.programId,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([cosigner])
+      .rpc();
+
+  it('deposits, sells and withdraws token-2022 assets', async () => {
+    const buyer = Keypair.generate();
+    const [nft, otherNft] = await Promise.all([
+      createExtNft(),
+      createExtNft(),
+      airdrop(connection, buyer.publicKey, 10),
+    ]);
+    const { poolKey, referral } = await createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      allowlists: fillAllowlists(
+        [
+          { kind: AllowlistKind.mint, value: nft.mint },
+          { kind: AllowlistKind.mint, value: otherNft.mint },
+        ],
+        6,
+      ),
+    });
+
+    await extDepositSell(poolKey, nft);
+    await extDepositSell(poolKey, otherNft);
+    let poolAccountInfo = await program.account.pool.fetch(poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 2);
+    const poolNftAta = await getExtAta(nft.mint, poolKey);
+    const poolNftAccount = await getTokenAccount(
+      connection,
+      poolNftAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    assert.equal(Number(poolNftAccount.amount), 1);
+
+    const buyerNftAta = await getExtAta(nft.mint, buyer.publicKey);
+    await program.methods
+      .solExtFulfillSell({
+        assetAmount: new anchor.BN(1),
+        maxPaymentAmount: new anchor.BN(2 * LAMPORTS_PER_SOL),
+        allowlistAux: null,
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: buyer.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        referral: referral.publicKey,
+        pool: poolKey,
+        globalConfig,
+        treasury,
+        buysideSolEscrowAccount: getMMMBuysideSolEscrowPDA(
+          program.programId,
+          poolKey,
+        ).key,
+        assetMint: nft.mint,
+        sellsideEscrowTokenAccount: poolNftAta,
+        payerAssetAccount: buyerNftAta,
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState: getMMMSellStatePDA(program.programId, poolKey, nft.mint)
+          .key,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([buyer, cosigner])
+      .rpc();
+    const buyerNftAccount = await getTokenAccount(
+      connection,
+      buyerNftAta,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    assert.equal(Number(buyerNftAccount.amount), 1);
+
+    await program.methods
+      .extWithdrawSell({ assetAmount: new anchor.BN(1), allowlistAux: null })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        assetMint: otherNft.mint,
+        assetTokenAccount: otherNft.tokenAccount,
+        sellsideEscrowTokenAccount: await getExtAta(otherNft.mint, poolKey),
+        buysideSolEscrowAccount: getMMMBuysideSolEscrowPDA(
+          program.programId,
+          poolKey,
+        ).key,
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState: getMMMSellStatePDA(
+          program.programId,
+          poolKey,
+          otherNft.mint,
+        ).key,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([cosigner])
+      .rpc();
+    const ownerNftAccount = await getTokenAccount(
+      connection,
+      otherNft.tokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    assert.equal(Number(ownerNftAccount.amount), 1);
+    poolAccountInfo = await program.account.pool.fetch(poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 0);
+  });
+
+  it('rejects the metaplex allowlists for token-2022 assets', async () => {
+    const nft = await createExtNft();
+    // the token-2022 mints have no metaplex creators, so an fvca entry of the
+    // mint authority never matches
+    const { poolKey } = await createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      allowlists: fillAllowlists(
+        [{ kind: AllowlistKind.fvca, value: wallet.publicKey }],
+        6,
+      ),
+    });
+
+    try {
+      await extDepositSell(poolKey, nft);
+      assert.fail('Should have failed with an fvca allowlist');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidAllowLists');
+    }
+    const poolAccountInfo = await program.account.pool.fetch(poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 0);
+  });
+});
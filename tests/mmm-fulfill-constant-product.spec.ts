@@ -0,0 +1,210 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  getAssociatedTokenAddress,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  CurveKind,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createPoolWithExampleDeposits,
+  getGlobalConfig,
+  getMetaplexInstance,
+} from './utils';
+
+describe('mmm-fulfill-constant-product', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+  const metaplexInstance = getMetaplexInstance(connection);
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  type PoolData = Awaited<ReturnType<typeof createPoolWithExampleDeposits>>;
+
+  // the seller sells the extra nft into the pool
+  const fulfillBuy = async (poolData: PoolData, seller: Keypair) => {
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.extraNft.mintAddress,
+    );
+    return program.methods
+      .solFulfillBuy({
+        assetAmount: new anchor.BN(1),
+        minPaymentAmount: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: seller.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.extraNft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.extraNft.mintAddress }),
+        assetMint: poolData.extraNft.mintAddress,
+        payerAssetAccount: poolData.extraNft.tokenAddress!,
+        sellsideEscrowTokenAccount: poolData.poolAtaExtraNft,
+        ownerTokenAccount: await getAssociatedTokenAddress(
+          poolData.extraNft.mintAddress,
+          wallet.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([seller, cosigner])
+      .rpc();
+  };
+
+  // the buyer buys the deposited nft from the pool
+  const fulfillSell = async (poolData: PoolData, buyer: Keypair) => {
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.nft.mintAddress,
+    );
+    return program.methods
+      .solFulfillSell({
+        assetAmount: new anchor.BN(1),
+        maxPaymentAmount: new anchor.BN(2 * LAMPORTS_PER_SOL),
+        buysideCreatorRoyaltyBp: 0,
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: buyer.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.nft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.nft.mintAddress }),
+        assetMint: poolData.nft.mintAddress,
+        sellsideEscrowTokenAccount: poolData.poolAtaNft,
+        payerAssetAccount: await getAssociatedTokenAddress(
+          poolData.nft.mintAddress,
+          buyer.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([buyer, cosigner])
+      .rpc();
+  };
+
+  const assertSpotPriceFromReserves = async (poolData: PoolData) => {
+    const poolAccountInfo = await program.account.pool.fetch(
+      poolData.poolKey,
+    );
+    assert.equal(
+      poolAccountInfo.buysidePaymentAmount.toNumber(),
+      await connection.getBalance(poolData.poolPaymentEscrow),
+    );
+    assert.equal(
+      poolAccountInfo.spotPrice.toNumber(),
+      poolAccountInfo.buysidePaymentAmount
+        .div(
+          poolAccountInfo.sellsideAssetAmount.add(poolAccountInfo.curveDelta),
+        )
+        .toNumber(),
+    );
+    return poolAccountInfo;
+  };
+
+  it('derives the spot price from the reserves without reinvest', async () => {
+    const trader = Keypair.generate();
+    const [poolData] = await Promise.all([
+      createPoolWithExampleDeposits(
+        program,
+        connection,
+        [AllowlistKind.fvca],
+        {
+          owner: wallet.publicKey,
+          cosigner,
+          curveType: CurveKind.constantProduct,
+          curveDelta: new anchor.BN(4), // virtual asset reserve
+          reinvestFulfillBuy: false,
+          reinvestFulfillSell: false,
+        },
+        'both',
+        trader.publicKey,
+      ),
+      airdrop(connection, trader.publicKey, 10),
+    ]);
+    // 10 SOL against 6 deposited and 4 virtual assets
+    let poolAccountInfo = await assertSpotPriceFromReserves(poolData);
+    assert.equal(poolAccountInfo.spotPrice.toNumber(), LAMPORTS_PER_SOL);
+
+    // without reinvest the bought nft goes to the owner, so only the payment
+    // reserve moves
+    await fulfillBuy(poolData, trader);
+    poolAccountInfo = await assertSpotPriceFromReserves(poolData);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 6);
+
+    // without reinvest the payment goes to the owner, so only the asset
+    // reserve moves
+    const buysidePaymentAmount = poolAccountInfo.buysidePaymentAmount;
+    await fulfillSell(poolData, trader);
+    poolAccountInfo = await assertSpotPriceFromReserves(poolData);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 5);
+    assert.equal(
+      poolAccountInfo.buysidePaymentAmount.toNumber(),
+      buysidePaymentAmount.toNumber(),
+    );
+  });
+});
@@ -8,6 +8,7 @@ import {
 import {
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
 } from '@solana/web3.js';
@@ -26,6 +27,7 @@ import {
   assertIsBetween,
   assertTx,
   createPoolWithExampleDeposits,
+  getGlobalConfig,
   getMetaplexInstance,
   getSellStatePDARent,
   getTokenAccountRent,
@@ -47,6 +49,12 @@ describe('mmm-fulfill-exp', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
 
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
@@ -106,6 +114,8 @@ describe('mmm-fulfill-exp', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -210,18 +220,25 @@ describe('mmm-fulfill-exp', () => {
         .updatePool({
           curveType: CurveKind.exp,
           curveDelta: new anchor.BN(500),
+          sellCurveDelta: null,
           spotPrice: new anchor.BN(2.1 * LAMPORTS_PER_SOL),
           reinvestFulfillBuy: false,
           reinvestFulfillSell: false,
           expiry: new anchor.BN(0),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 200,
           referral: poolData.referral.publicKey,
           cosignerAnnotation: new Array(32).fill(0).map((_, index) => index),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: null,
+          dutchAuction: null,
         })
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolData.poolKey,
         })
         .signers([cosigner])
@@ -250,6 +267,8 @@ describe('mmm-fulfill-exp', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -379,6 +398,8 @@ describe('mmm-fulfill-exp', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -488,6 +509,8 @@ describe('mmm-fulfill-exp', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -638,6 +661,8 @@ describe('mmm-fulfill-exp', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -759,18 +784,25 @@ describe('mmm-fulfill-exp', () => {
         .updatePool({
           curveType: CurveKind.exp,
           curveDelta: new anchor.BN(125), // 125 bp
+          sellCurveDelta: null,
           spotPrice: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
           reinvestFulfillBuy: true,
           reinvestFulfillSell: true,
           expiry: new anchor.BN(0),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 200,
           referral: poolData.referral.publicKey,
           cosignerAnnotation: new Array(32).fill(0).map((_, index) => index),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: null,
+          dutchAuction: null,
         })
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolData.poolKey,
         })
         .signers([cosigner])
@@ -796,6 +828,8 @@ describe('mmm-fulfill-exp', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -979,6 +1013,8 @@ describe('mmm-fulfill-exp', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -1072,6 +1108,8 @@ describe('mmm-fulfill-exp', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
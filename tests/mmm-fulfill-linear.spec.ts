@@ -8,6 +8,7 @@ import {
 import {
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
 } from '@solana/web3.js';
@@ -27,6 +28,7 @@ import {
   assertIsBetween,
   assertTx,
   createPoolWithExampleDeposits,
+  getGlobalConfig,
   getMetadataURI,
   getMetaplexInstance,
   getSellStatePDARent,
@@ -48,6 +50,12 @@ describe('mmm-fulfill-linear', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
 
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
@@ -107,6 +115,8 @@ describe('mmm-fulfill-linear', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -211,18 +221,25 @@ describe('mmm-fulfill-linear', () => {
         .updatePool({
           curveType: CurveKind.linear,
           curveDelta: new anchor.BN(LAMPORTS_PER_SOL).div(new anchor.BN(10)), // 0.1 SOL
+          sellCurveDelta: null,
           spotPrice: new anchor.BN(2.1 * LAMPORTS_PER_SOL),
           reinvestFulfillBuy: false,
           reinvestFulfillSell: false,
           expiry: new anchor.BN(0),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 200,
           referral: poolData.referral.publicKey,
           cosignerAnnotation: new Array(32).fill(0).map((_, index) => index),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: null,
+          dutchAuction: null,
         })
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolData.poolKey,
         })
         .signers([cosigner])
@@ -250,6 +267,8 @@ describe('mmm-fulfill-linear', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -374,6 +393,8 @@ describe('mmm-fulfill-linear', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -513,6 +534,8 @@ describe('mmm-fulfill-linear', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -611,18 +634,25 @@ describe('mmm-fulfill-linear', () => {
         .updatePool({
           curveType: CurveKind.linear,
           curveDelta: new anchor.BN(LAMPORTS_PER_SOL).div(new anchor.BN(10)), // 0.1 SOL
+          sellCurveDelta: null,
           spotPrice: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
           reinvestFulfillBuy: true,
           reinvestFulfillSell: true,
           expiry: new anchor.BN(0),
+          startTime: new anchor.BN(0),
+          buysideExpiry: new anchor.BN(0),
+          sellsideExpiry: new anchor.BN(0),
           lpFeeBp: 200,
           referral: poolData.referral.publicKey,
           cosignerAnnotation: new Array(32).fill(0).map((_, index) => index),
           buysideCreatorRoyaltyBp: 0,
+          priceLadder: null,
+          dutchAuction: null,
         })
         .accountsStrict({
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
           pool: poolData.poolKey,
         })
         .signers([cosigner])
@@ -646,6 +676,8 @@ describe('mmm-fulfill-linear', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -819,6 +851,8 @@ describe('mmm-fulfill-linear', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -910,6 +944,8 @@ describe('mmm-fulfill-linear', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -1032,6 +1068,8 @@ describe('mmm-fulfill-linear', () => {
             payer: seller.publicKey,
             owner: wallet.publicKey,
             cosigner: cosigner.publicKey,
+            globalConfig,
+            treasury,
             referral: poolData.referral.publicKey,
             pool: poolData.poolKey,
             buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -0,0 +1,224 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  getAssociatedTokenAddress,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  CurveKind,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createPoolWithExampleDeposits,
+  getGlobalConfig,
+  getMetaplexInstance,
+} from './utils';
+
+describe('mmm-fulfill-reverse', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+  const metaplexInstance = getMetaplexInstance(connection);
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  type PoolData = Awaited<ReturnType<typeof createPoolWithExampleDeposits>>;
+
+  // the seller sells the extra nft into the pool
+  const fulfillBuy = async (poolData: PoolData, seller: Keypair) => {
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.extraNft.mintAddress,
+    );
+    return program.methods
+      .solFulfillBuy({
+        assetAmount: new anchor.BN(1),
+        minPaymentAmount: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: seller.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.extraNft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.extraNft.mintAddress }),
+        assetMint: poolData.extraNft.mintAddress,
+        payerAssetAccount: poolData.extraNft.tokenAddress!,
+        sellsideEscrowTokenAccount: poolData.poolAtaExtraNft,
+        ownerTokenAccount: await getAssociatedTokenAddress(
+          poolData.extraNft.mintAddress,
+          wallet.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([seller, cosigner])
+      .rpc();
+  };
+
+  // the buyer buys the deposited nft from the pool
+  const fulfillSell = async (poolData: PoolData, buyer: Keypair) => {
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.nft.mintAddress,
+    );
+    return program.methods
+      .solFulfillSell({
+        assetAmount: new anchor.BN(1),
+        maxPaymentAmount: new anchor.BN(2 * LAMPORTS_PER_SOL),
+        buysideCreatorRoyaltyBp: 0,
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: buyer.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.nft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.nft.mintAddress }),
+        assetMint: poolData.nft.mintAddress,
+        sellsideEscrowTokenAccount: poolData.poolAtaNft,
+        payerAssetAccount: await getAssociatedTokenAddress(
+          poolData.nft.mintAddress,
+          buyer.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([buyer, cosigner])
+      .rpc();
+  };
+
+  // the reverse curves buy up and sell down from the spot price, so a fulfill
+  // buy and a fulfill sell walk the spot price up by one step and back
+  const assertRoundTrip = async (
+    curveType: CurveKind,
+    curveDelta: anchor.BN,
+    nextSpotPrice: number,
+  ) => {
+    const trader = Keypair.generate();
+    const [poolData] = await Promise.all([
+      createPoolWithExampleDeposits(
+        program,
+        connection,
+        [AllowlistKind.fvca],
+        {
+          owner: wallet.publicKey,
+          cosigner,
+          curveType,
+          curveDelta,
+          spotPrice: new anchor.BN(LAMPORTS_PER_SOL),
+        },
+        'both',
+        trader.publicKey,
+      ),
+      airdrop(connection, trader.publicKey, 10),
+    ]);
+    let poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    const sellsideAssetAmount = poolAccountInfo.sellsideAssetAmount.toNumber();
+
+    // the pool buys at the spot price, and the price goes up
+    await fulfillBuy(poolData, trader);
+    poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.equal(poolAccountInfo.spotPrice.toNumber(), nextSpotPrice);
+    assert.equal(
+      poolAccountInfo.sellsideAssetAmount.toNumber(),
+      sellsideAssetAmount + 1,
+    );
+
+    // the pool sells one step below the spot price, i.e. back at 1 SOL, and
+    // the payment is reinvested
+    const escrowBalance = await connection.getBalance(
+      poolData.poolPaymentEscrow,
+    );
+    await fulfillSell(poolData, trader);
+    poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.equal(poolAccountInfo.spotPrice.toNumber(), LAMPORTS_PER_SOL);
+    assert.equal(
+      poolAccountInfo.sellsideAssetAmount.toNumber(),
+      sellsideAssetAmount,
+    );
+    assert.equal(
+      await connection.getBalance(poolData.poolPaymentEscrow),
+      escrowBalance + LAMPORTS_PER_SOL,
+    );
+    assert.equal(
+      poolAccountInfo.buysidePaymentAmount.toNumber(),
+      escrowBalance + LAMPORTS_PER_SOL,
+    );
+  };
+
+  it('can fulfill a reverse linear pool', async () => {
+    await assertRoundTrip(
+      CurveKind.reverseLinear,
+      new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+      1.1 * LAMPORTS_PER_SOL,
+    );
+  });
+
+  it('can fulfill a reverse exp pool', async () => {
+    await assertRoundTrip(
+      CurveKind.reverseExp,
+      new anchor.BN(1000), // 10%
+      1.1 * LAMPORTS_PER_SOL,
+    );
+  });
+});
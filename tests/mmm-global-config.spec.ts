@@ -0,0 +1,289 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  getAssociatedTokenAddress,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  CurveKind,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createPool,
+  createPoolWithExampleDeposits,
+  getGlobalConfig,
+  getGlobalConfigAdminProgram,
+  getMetaplexInstance,
+  getTokenAccountRent,
+  sendAndAssertTx,
+  SIGNATURE_FEE_LAMPORTS,
+} from './utils';
+
+describe('mmm-global-config', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const adminProgram = getGlobalConfigAdminProgram(program);
+  const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  const updateGlobalConfig = (args: {
+    protocolFeeBp?: number;
+    maxLpFeeBp?: number;
+    maxReferralFeeBp?: number;
+    treasury?: PublicKey;
+  }) =>
+    adminProgram.methods
+      .updateGlobalConfig({
+        newAdmin: adminProgram.provider.publicKey!,
+        protocolFeeBp: args.protocolFeeBp ?? 0,
+        maxLpFeeBp: args.maxLpFeeBp ?? 1000, // MAX_LP_FEE_BP
+        maxReferralFeeBp: args.maxReferralFeeBp ?? 500, // MAX_REFERRAL_FEE_BP
+      })
+      .accountsStrict({
+        admin: adminProgram.provider.publicKey!,
+        globalConfig,
+        treasury: args.treasury ?? treasury,
+      })
+      .rpc();
+
+  const setGlobalPause = (paused: boolean) =>
+    adminProgram.methods
+      .setGlobalPause({ paused })
+      .accountsStrict({
+        admin: adminProgram.provider.publicKey!,
+        globalConfig,
+      })
+      .rpc();
+
+  // buys one sft from the pool at 1 SOL, with the buyer paying the tx fees
+  const fulfillSell = async (
+    poolData: Awaited<ReturnType<typeof createPoolWithExampleDeposits>>,
+    buyer: Keypair,
+    takerFeeBp: number,
+  ) => {
+    const metaplexInstance = getMetaplexInstance(connection);
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.sft.mintAddress,
+    );
+    const tx = await program.methods
+      .solFulfillSell({
+        assetAmount: new anchor.BN(1),
+        maxPaymentAmount: new anchor.BN(2 * LAMPORTS_PER_SOL),
+        buysideCreatorRoyaltyBp: 0,
+        allowlistAux: '',
+        takerFeeBp,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: buyer.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.sft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.sft.mintAddress }),
+        assetMint: poolData.sft.mintAddress,
+        sellsideEscrowTokenAccount: poolData.poolAtaSft,
+        payerAssetAccount: await getAssociatedTokenAddress(
+          poolData.sft.mintAddress,
+          buyer.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .transaction();
+
+    const blockhashData = await connection.getLatestBlockhash();
+    tx.feePayer = buyer.publicKey;
+    tx.recentBlockhash = blockhashData.blockhash;
+    tx.partialSign(cosigner, buyer);
+    await sendAndAssertTx(connection, tx, blockhashData, false);
+  };
+
+  const createExamplePool = async (buyer: Keypair) => {
+    const [poolData] = await Promise.all([
+      createPoolWithExampleDeposits(
+        program,
+        connection,
+        [AllowlistKind.mint],
+        {
+          owner: wallet.publicKey,
+          cosigner,
+          curveType: CurveKind.linear,
+          curveDelta: new anchor.BN(0),
+          spotPrice: new anchor.BN(1 * LAMPORTS_PER_SOL),
+          lpFeeBp: 200,
+          reinvestFulfillBuy: false,
+          reinvestFulfillSell: false,
+        },
+        'both',
+      ),
+      airdrop(connection, buyer.publicKey, 10),
+    ]);
+    return poolData;
+  };
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  afterEach(async () => {
+    // the other specs run without protocol fee and with the default bounds
+    await setGlobalPause(false);
+    await updateGlobalConfig({});
+  });
+
+  it('rejects a treasury that is not rent exempt', async () => {
+    try {
+      await updateGlobalConfig({ treasury: Keypair.generate().publicKey });
+      assert.fail('Should have failed with an empty treasury');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidTreasury');
+    }
+  });
+
+  it('charges the protocol fee next to the lp and referral fees', async () => {
+    const buyer = Keypair.generate();
+    const poolData = await createExamplePool(buyer);
+    await updateGlobalConfig({ protocolFeeBp: 50 });
+
+    const [
+      initBuyerBalance,
+      initWalletBalance,
+      initReferralBalance,
+      initTreasuryBalance,
+    ] = await Promise.all([
+      connection.getBalance(buyer.publicKey),
+      connection.getBalance(wallet.publicKey),
+      connection.getBalance(poolData.referral.publicKey),
+      connection.getBalance(treasury),
+    ]);
+    await fulfillSell(poolData, buyer, 100);
+
+    const expectedLpFees = 1 * LAMPORTS_PER_SOL * 0.02;
+    const expectedReferralFees = 1 * LAMPORTS_PER_SOL * 0.01;
+    const expectedProtocolFees = 1 * LAMPORTS_PER_SOL * 0.005;
+    const [buyerBalance, walletBalance, referralBalance, treasuryBalance] =
+      await Promise.all([
+        connection.getBalance(buyer.publicKey),
+        connection.getBalance(wallet.publicKey),
+        connection.getBalance(poolData.referral.publicKey),
+        connection.getBalance(treasury),
+      ]);
+    assert.equal(
+      buyerBalance,
+      initBuyerBalance -
+        1 * LAMPORTS_PER_SOL -
+        expectedLpFees -
+        expectedReferralFees -
+        expectedProtocolFees -
+        SIGNATURE_FEE_LAMPORTS * 2 -
+        (await getTokenAccountRent(connection)),
+    );
+    assert.equal(
+      walletBalance,
+      initWalletBalance + 1 * LAMPORTS_PER_SOL + expectedLpFees,
+    );
+    assert.equal(referralBalance, initReferralBalance + expectedReferralFees);
+    assert.equal(treasuryBalance, initTreasuryBalance + expectedProtocolFees);
+  });
+
+  it('rejects the fulfills while paused', async () => {
+    const buyer = Keypair.generate();
+    const poolData = await createExamplePool(buyer);
+    await setGlobalPause(true);
+
+    try {
+      await fulfillSell(poolData, buyer, 0);
+      assert.fail('Should have failed while paused');
+    } catch (e) {
+      assert.include(`${e}`, '"Custom":6038'); // ProgramPaused
+    }
+    let poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 6);
+
+    await setGlobalPause(false);
+    await fulfillSell(poolData, buyer, 0);
+    poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 5);
+  });
+
+  it('bounds override the lp and referral fee constants', async () => {
+    // lower than MAX_LP_FEE_BP
+    await updateGlobalConfig({ maxLpFeeBp: 100 });
+    try {
+      await createPool(program, {
+        owner: wallet.publicKey,
+        cosigner,
+        lpFeeBp: 200,
+      });
+      assert.fail('Should have failed with an lp fee above the bound');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidBP');
+    }
+    await createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      lpFeeBp: 100,
+    });
+
+    // higher than MAX_REFERRAL_FEE_BP
+    await updateGlobalConfig({ maxReferralFeeBp: 800 });
+    const buyer = Keypair.generate();
+    const poolData = await createExamplePool(buyer);
+    const initReferralBalance = await connection.getBalance(
+      poolData.referral.publicKey,
+    );
+    await fulfillSell(poolData, buyer, 700);
+    assert.equal(
+      await connection.getBalance(poolData.referral.publicKey),
+      initReferralBalance + 1 * LAMPORTS_PER_SOL * 0.07,
+    );
+
+    await updateGlobalConfig({ maxReferralFeeBp: 50 });
+    try {
+      await fulfillSell(poolData, buyer, 100);
+      assert.fail('Should have failed with a taker fee above the bound');
+    } catch (e) {
+      assert.include(`${e}`, '"Custom":6022'); // InvalidMakerOrTakerFeeBP
+    }
+  });
+});
@@ -0,0 +1,148 @@
+import * as anchor from '@project-serum/anchor';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import { Mmm, AllowlistKind, CurveKind, IDL, MMMProgramID } from '../sdk/src';
+import { airdrop, getSellStatePDARent } from './utils';
+// v0 accounts loaded by the validator, see [[test.validator.account]] in
+// Anchor.toml. the pool has the legacy size, the sell state is trimmed to its
+// v0 fields so that migrate_sell_state has to realloc it
+import legacyPool from './fixtures/legacy-pool.json';
+import legacySellState from './fixtures/legacy-sell-state.json';
+
+const POOL_LEN = 849; // see Pool::LEN
+const SELL_STATE_LEN = 344; // see SellState::LEN
+const POOL_VERSION = 2;
+const SELL_STATE_VERSION = 1;
+
+describe('mmm-migrate', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const pool = new PublicKey(legacyPool.pubkey);
+  const sellState = new PublicKey(legacySellState.pubkey);
+
+  const migratePool = (account: PublicKey) =>
+    program.methods
+      .migratePool()
+      .accountsStrict({
+        payer: wallet.publicKey,
+        pool: account,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ skipPreflight: true });
+
+  const migrateSellState = (account: PublicKey) =>
+    program.methods
+      .migrateSellState()
+      .accountsStrict({
+        payer: wallet.publicKey,
+        sellState: account,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ skipPreflight: true });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 10);
+  });
+
+  it('migrates a v0 pool in place', async () => {
+    const before = await connection.getAccountInfo(pool);
+    assert.equal(before!.data.length, POOL_LEN);
+    // a v0 pool still deserializes, and the fields added since v0 are zero
+    const v0 = await program.account.pool.fetch(pool);
+    assert.equal(v0.version, 0);
+    assert.equal(v0.spotPrice.toNumber(), 1.5 * anchor.web3.LAMPORTS_PER_SOL);
+    assert.equal(v0.curveType, CurveKind.exp);
+    assert.equal(v0.curveDelta.toNumber(), 300);
+    assert.isTrue(v0.reinvestFulfillBuy);
+    assert.isFalse(v0.reinvestFulfillSell);
+    assert.equal(v0.lpFeeBp, 150);
+    assert.equal(v0.buysideCreatorRoyaltyBp, 500);
+    assert.deepEqual(v0.cosignerAnnotation, new Array(32).fill(7));
+    assert.equal(v0.sellsideAssetAmount.toNumber(), 1);
+    assert.equal(v0.lpFeeEarned.toNumber(), 12345);
+    assert.equal(v0.allowlists[0].kind, AllowlistKind.mcc);
+    assert.equal(
+      v0.buysidePaymentAmount.toNumber(),
+      2 * anchor.web3.LAMPORTS_PER_SOL,
+    );
+    assert.isTrue(v0.originalOwner.equals(PublicKey.default));
+    assert.isTrue(v0.operator.equals(PublicKey.default));
+    assert.deepEqual(v0.allowlistPriceMultipliersBp, [0, 0, 0, 0, 0, 0]);
+    assert.isFalse(v0.asymmetricCurveDelta);
+
+    await migratePool(pool);
+    const after = await connection.getAccountInfo(pool);
+    assert.equal(after!.data.length, POOL_LEN);
+    assert.equal(after!.lamports, before!.lamports);
+    assert.isTrue(after!.owner.equals(MMMProgramID));
+    const migrated = await program.account.pool.fetch(pool);
+    assert.equal(migrated.version, POOL_VERSION);
+    assert.deepEqual({ ...migrated, version: 0 }, v0);
+
+    // migrating again is a no-op
+    await migratePool(pool);
+    const again = await connection.getAccountInfo(pool);
+    assert.equal(again!.lamports, after!.lamports);
+    assert.isTrue(again!.data.equals(after!.data));
+  });
+
+  it('reallocs a v0 sell state to the current layout', async () => {
+    const before = await connection.getAccountInfo(sellState);
+    assert.equal(before!.data.length, legacySellState.account.space);
+
+    await migrateSellState(sellState);
+    const after = await connection.getAccountInfo(sellState);
+    assert.equal(after!.data.length, SELL_STATE_LEN);
+    // the payer tops up the rent of the extra space
+    assert.equal(after!.lamports, await getSellStatePDARent(connection));
+    // the v0 fields stay as they are, followed by the version and the padding
+    const v0Len = before!.data.length;
+    assert.isTrue(after!.data.subarray(0, v0Len).equals(before!.data));
+    assert.equal(after!.data[v0Len], SELL_STATE_VERSION);
+    assert.isTrue(after!.data.subarray(v0Len + 1).every((b) => b === 0));
+    const migrated = await program.account.sellState.fetch(sellState);
+    assert.equal(migrated.version, SELL_STATE_VERSION);
+    assert.isTrue(migrated.pool.equals(pool));
+    assert.isTrue(
+      migrated.poolOwner.equals(
+        new PublicKey('35Ljodnr1AUcR8RiXdEM4wwUAqiLvkMNiS1PqbNmE8xW'),
+      ),
+    );
+    assert.isTrue(
+      migrated.assetMint.equals(
+        new PublicKey('HH6Q7SXgCKwjd2DT1NBQEpaDRud9E5usX2aMYhWCQmnM'),
+      ),
+    );
+    assert.equal(migrated.assetAmount.toNumber(), 1);
+    assert.deepEqual(migrated.cosignerAnnotation, new Array(32).fill(7));
+
+    // migrating again is a no-op
+    await migrateSellState(sellState);
+    const again = await connection.getAccountInfo(sellState);
+    assert.equal(again!.lamports, after!.lamports);
+    assert.isTrue(again!.data.equals(after!.data));
+  });
+
+  it('rejects the accounts of the other type', async () => {
+    try {
+      await migratePool(sellState);
+      assert.fail('migrate_pool should have failed');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidAccountState');
+    }
+    try {
+      await migrateSellState(pool);
+      assert.fail('migrate_sell_state should have failed');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidAccountState');
+    }
+  });
+});
@@ -33,6 +33,7 @@ import {
   createPoolWithExampleMip1Deposits,
   createProgrammableNft,
   getEmptyAllowLists,
+  getGlobalConfig,
   getSellStatePDARent,
   getTokenAccountRent,
   MIP1_COMPUTE_UNITS,
@@ -56,6 +57,8 @@ describe('mmm-mip1', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
   let defaultRules: PublicKey;
   const DEFAULT_ACCOUNTS = {
     tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
@@ -77,6 +80,10 @@ describe('mmm-mip1', () => {
     defaultRules = rulesRes.ruleSetAddress;
   });
 
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
   });
@@ -215,6 +222,97 @@ describe('mmm-mip1', () => {
     assert.equal(await connection.getBalance(poolData.poolKey), 0);
   });
 
+  it('can deposit mip1 NFTs into a transferred pool', async () => {
+    const newOwner = Keypair.generate();
+    await airdrop(connection, newOwner.publicKey, 10);
+    const nftRes = await createProgrammableNft(
+      connection,
+      nftCreator,
+      newOwner.publicKey,
+      defaultRules,
+    );
+    const poolData = await createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      allowlists: [
+        { value: nftCreator.publicKey, kind: AllowlistKind.fvca },
+        ...getEmptyAllowLists(5),
+      ],
+    });
+    await program.methods
+      .proposePoolOwner({ newOwner: newOwner.publicKey })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolData.poolKey,
+      })
+      .signers([cosigner])
+      .rpc();
+    await program.methods
+      .acceptPoolOwner()
+      .accountsStrict({
+        newOwner: newOwner.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolData.poolKey,
+      })
+      .signers([newOwner, cosigner])
+      .rpc();
+
+    // the auth rules match the pool pda, which is still derived from the
+    // original owner
+    const poolAta = await getAssociatedTokenAddress(
+      nftRes.mintAddress,
+      poolData.poolKey,
+      true,
+    );
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      nftRes.mintAddress,
+    );
+    await program.methods
+      .mip1DepositSell({
+        assetAmount: new anchor.BN(1),
+        allowlistAux: null,
+      })
+      .accountsStrict({
+        owner: newOwner.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolData.poolKey,
+        assetMetadata: nftRes.metadataAddress,
+        assetMint: nftRes.mintAddress,
+        assetMasterEdition: nftRes.masterEditionAddress,
+        assetTokenAccount: nftRes.tokenAddress,
+        sellsideEscrowTokenAccount: poolAta,
+        sellState,
+        allowlistAuxAccount: SystemProgram.programId,
+        authorizationRules: defaultRules,
+        ownerTokenRecord: getTokenRecordPDA(
+          nftRes.mintAddress,
+          nftRes.tokenAddress,
+        ).key,
+        destinationTokenRecord: getTokenRecordPDA(nftRes.mintAddress, poolAta)
+          .key,
+
+        ...DEFAULT_ACCOUNTS,
+      })
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: MIP1_COMPUTE_UNITS }),
+      ])
+      .signers([newOwner, cosigner])
+      .rpc({ skipPreflight: true });
+
+    const [poolTokenEscrow, sellStateAccountInfo, poolAccountInfo] =
+      await Promise.all([
+        getTokenAccount(connection, poolAta),
+        program.account.sellState.fetch(sellState),
+        program.account.pool.fetch(poolData.poolKey),
+      ]);
+    assert.equal(Number(poolTokenEscrow.amount), 1);
+    assert.deepEqual(sellStateAccountInfo.poolOwner, newOwner.publicKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 1);
+  });
+
   it('can fulfill sell - happy path', async () => {
     const buyer = Keypair.generate();
     const [poolData] = await Promise.all([
@@ -281,6 +379,8 @@ describe('mmm-mip1', () => {
         payer: buyer.publicKey,
         owner: wallet.publicKey,
         cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
         referral: poolData.referral.publicKey,
         pool: poolData.poolKey,
         buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -437,6 +537,8 @@ describe('mmm-mip1', () => {
         payer: seller.publicKey,
         owner: wallet.publicKey,
         cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
         referral: poolData.referral.publicKey,
         pool: poolData.poolKey,
         buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -622,6 +724,8 @@ describe('mmm-mip1', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -759,6 +863,8 @@ describe('mmm-mip1', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -947,6 +1053,8 @@ describe('mmm-mip1', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -1087,6 +1195,8 @@ describe('mmm-mip1', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -0,0 +1,150 @@
+import * as anchor from '@project-serum/anchor';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  getMMMBuysideSolEscrowPDA,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createMplCoreAsset,
+  createMplCoreCollection,
+  createPool,
+  fillAllowlists,
+  MPL_CORE_PROGRAM_ID,
+} from './utils';
+
+describe('mmm-mpl-core', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  const createCollectionPool = async (collection: PublicKey) =>
+    createPool(program, {
+      owner: wallet.publicKey,
+      cosigner,
+      allowlists: fillAllowlists(
+        [{ kind: AllowlistKind.mcc, value: collection }],
+        6,
+      ),
+    });
+
+  const mplCoreDepositSell = (
+    poolKey: PublicKey,
+    asset: PublicKey,
+    collection: PublicKey,
+  ) =>
+    program.methods
+      .mplCoreDepositSell({ allowlistAux: null })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        asset,
+        collection,
+        sellState: getMMMSellStatePDA(program.programId, poolKey, asset).key,
+        allowlistAuxAccount: SystemProgram.programId,
+        systemProgram: SystemProgram.programId,
+        assetProgram: MPL_CORE_PROGRAM_ID,
+      })
+      .signers([cosigner])
+      .rpc();
+
+  const getAssetOwner = async (asset: PublicKey) => {
+    const assetAccount = await connection.getAccountInfo(asset);
+    // the owner follows the key of BaseAssetV1
+    return new PublicKey(assetAccount!.data.subarray(1, 33));
+  };
+
+  it('deposits and withdraws a core asset of the collection', async () => {
+    const collection = await createMplCoreCollection(connection, wallet.payer);
+    const asset = await createMplCoreAsset(connection, wallet.payer, {
+      owner: wallet.publicKey,
+      collection,
+    });
+    const { poolKey } = await createCollectionPool(collection);
+
+    await mplCoreDepositSell(poolKey, asset, collection);
+    assert.deepEqual(await getAssetOwner(asset), poolKey);
+    let poolAccountInfo = await program.account.pool.fetch(poolKey);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 1);
+    const sellState = getMMMSellStatePDA(program.programId, poolKey, asset).key;
+    const sellStateAccountInfo = await program.account.sellState.fetch(
+      sellState,
+    );
+    assert.deepEqual(sellStateAccountInfo.assetMint, asset);
+    assert.equal(sellStateAccountInfo.assetAmount.toNumber(), 1);
+
+    await program.methods
+      .mplCoreWithdrawSell()
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        asset,
+        collection,
+        buysideSolEscrowAccount: getMMMBuysideSolEscrowPDA(
+          program.programId,
+          poolKey,
+        ).key,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        assetProgram: MPL_CORE_PROGRAM_ID,
+      })
+      .signers([cosigner])
+      .rpc();
+    assert.deepEqual(await getAssetOwner(asset), wallet.publicKey);
+    assert.isNull(await connection.getAccountInfo(sellState));
+  });
+
+  it('rejects a core asset of another collection', async () => {
+    const [collection, otherCollection] = await Promise.all([
+      createMplCoreCollection(connection, wallet.payer),
+      createMplCoreCollection(connection, wallet.payer),
+    ]);
+    const [asset, assetWithoutCollection] = await Promise.all([
+      createMplCoreAsset(connection, wallet.payer, {
+        owner: wallet.publicKey,
+        collection: otherCollection,
+      }),
+      createMplCoreAsset(connection, wallet.payer, {
+        owner: wallet.publicKey,
+      }),
+    ]);
+    const { poolKey } = await createCollectionPool(collection);
+
+    try {
+      await mplCoreDepositSell(poolKey, asset, otherCollection);
+      assert.fail('Should have failed with another collection');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidAllowLists');
+    }
+    try {
+      await mplCoreDepositSell(
+        poolKey,
+        assetWithoutCollection,
+        MPL_CORE_PROGRAM_ID,
+      );
+      assert.fail('Should have failed without a collection');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidAllowLists');
+    }
+    assert.deepEqual(await getAssetOwner(asset), wallet.publicKey);
+  });
+});
@@ -9,6 +9,7 @@ import {
   ComputeBudgetProgram,
   Keypair,
   LAMPORTS_PER_SOL,
+  PublicKey,
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
@@ -31,6 +32,7 @@ import {
   createPoolWithExampleOcpDeposits,
   createTestMintAndTokenOCP,
   getEmptyAllowLists,
+  getGlobalConfig,
   getSellStatePDARent,
   getTokenAccountRent,
   OCP_COMPUTE_UNITS,
@@ -54,6 +56,8 @@ describe('mmm-ocp', () => {
     provider,
   ) as anchor.Program<Mmm>;
   const cosigner = Keypair.generate();
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
   const DEFAULT_ACCOUNTS = {
     ocpProgram: OCP_PROGRAM_ID,
     cmtProgram: CMT_PROGRAM,
@@ -64,6 +68,10 @@ describe('mmm-ocp', () => {
     rent: SYSVAR_RENT_PUBKEY,
   };
 
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
   beforeEach(async () => {
     await airdrop(connection, wallet.publicKey, 50);
   });
@@ -255,6 +263,8 @@ describe('mmm-ocp', () => {
         payer: buyer.publicKey,
         owner: wallet.publicKey,
         cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
         referral: poolData.referral.publicKey,
         pool: poolData.poolKey,
         buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -405,6 +415,8 @@ describe('mmm-ocp', () => {
         payer: seller.publicKey,
         owner: wallet.publicKey,
         cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
         referral: poolData.referral.publicKey,
         pool: poolData.poolKey,
         buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -580,6 +592,8 @@ describe('mmm-ocp', () => {
           payer: seller.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
@@ -707,6 +721,8 @@ describe('mmm-ocp', () => {
           payer: buyer.publicKey,
           owner: wallet.publicKey,
           cosigner: cosigner.publicKey,
+          globalConfig,
+          treasury,
           referral: poolData.referral.publicKey,
           pool: poolData.poolKey,
           buysideSolEscrowAccount: poolData.poolPaymentEscrow,
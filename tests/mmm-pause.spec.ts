@@ -0,0 +1,225 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  getAssociatedTokenAddress,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createPoolWithExampleDeposits,
+  getGlobalConfig,
+  getMetaplexInstance,
+} from './utils';
+
+describe('mmm-pause', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+  const metaplexInstance = getMetaplexInstance(connection);
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  type PoolData = Awaited<ReturnType<typeof createPoolWithExampleDeposits>>;
+
+  const setPoolPause = (
+    poolData: PoolData,
+    buysidePaused: boolean,
+    sellsidePaused: boolean,
+  ) =>
+    program.methods
+      .setPoolPause({ buysidePaused, sellsidePaused })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolData.poolKey,
+      })
+      .signers([cosigner])
+      .rpc();
+
+  // the seller sells the extra nft into the pool
+  const fulfillBuy = async (poolData: PoolData, seller: Keypair) => {
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.extraNft.mintAddress,
+    );
+    return program.methods
+      .solFulfillBuy({
+        assetAmount: new anchor.BN(1),
+        minPaymentAmount: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: seller.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.extraNft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.extraNft.mintAddress }),
+        assetMint: poolData.extraNft.mintAddress,
+        payerAssetAccount: poolData.extraNft.tokenAddress!,
+        sellsideEscrowTokenAccount: poolData.poolAtaExtraNft,
+        ownerTokenAccount: await getAssociatedTokenAddress(
+          poolData.extraNft.mintAddress,
+          wallet.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([seller, cosigner])
+      .rpc();
+  };
+
+  // the buyer buys the deposited nft from the pool
+  const fulfillSell = async (poolData: PoolData, buyer: Keypair) => {
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.nft.mintAddress,
+    );
+    return program.methods
+      .solFulfillSell({
+        assetAmount: new anchor.BN(1),
+        maxPaymentAmount: new anchor.BN(2 * LAMPORTS_PER_SOL),
+        buysideCreatorRoyaltyBp: 0,
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: buyer.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        globalConfig,
+        treasury,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        buysideSolEscrowAccount: poolData.poolPaymentEscrow,
+        assetMetadata: poolData.nft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.nft.mintAddress }),
+        assetMint: poolData.nft.mintAddress,
+        sellsideEscrowTokenAccount: poolData.poolAtaNft,
+        payerAssetAccount: await getAssociatedTokenAddress(
+          poolData.nft.mintAddress,
+          buyer.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([buyer, cosigner])
+      .rpc();
+  };
+
+  it('pauses the buyside only', async () => {
+    const trader = Keypair.generate();
+    const [poolData] = await Promise.all([
+      createPoolWithExampleDeposits(
+        program,
+        connection,
+        [AllowlistKind.fvca],
+        { owner: wallet.publicKey, cosigner },
+        'both',
+        trader.publicKey,
+      ),
+      airdrop(connection, trader.publicKey, 10),
+    ]);
+
+    await setPoolPause(poolData, true, false);
+    let poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.isTrue(poolAccountInfo.buysidePaused);
+    assert.isFalse(poolAccountInfo.sellsidePaused);
+
+    try {
+      await fulfillBuy(poolData, trader);
+      assert.fail('Should have failed on the paused buyside');
+    } catch (e) {
+      assert.include(`${e}`, 'PoolPaused');
+    }
+    await fulfillSell(poolData, trader);
+
+    await setPoolPause(poolData, false, false);
+    await fulfillBuy(poolData, trader);
+    poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.isFalse(poolAccountInfo.buysidePaused);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 6);
+  });
+
+  it('pauses the sellside only', async () => {
+    const trader = Keypair.generate();
+    const [poolData] = await Promise.all([
+      createPoolWithExampleDeposits(
+        program,
+        connection,
+        [AllowlistKind.fvca],
+        { owner: wallet.publicKey, cosigner },
+        'both',
+        trader.publicKey,
+      ),
+      airdrop(connection, trader.publicKey, 10),
+    ]);
+
+    await setPoolPause(poolData, false, true);
+    try {
+      await fulfillSell(poolData, trader);
+      assert.fail('Should have failed on the paused sellside');
+    } catch (e) {
+      assert.include(`${e}`, 'PoolPaused');
+    }
+    await fulfillBuy(poolData, trader);
+
+    const poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.isTrue(poolAccountInfo.sellsidePaused);
+    assert.equal(poolAccountInfo.sellsideAssetAmount.toNumber(), 7);
+  });
+});
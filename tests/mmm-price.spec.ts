@@ -0,0 +1,46 @@
+import * as anchor from '@project-serum/anchor';
+import { assert } from 'chai';
+import { getSolTotalPriceAndNextPrice } from '../sdk/src';
+// generated by test_price_fixtures of the program from the rust pricing, run it
+// with UPDATE_PRICE_FIXTURES=1 to regenerate the fixtures
+import prices from './fixtures/prices.json';
+
+describe('mmm-price', () => {
+  prices.forEach((price, i) => {
+    const { pool, n, fulfillBuy, allowlistMultiplierBp } = price;
+    it(`matches the program for the fixture ${i}`, () => {
+      const params = {
+        spotPrice: new anchor.BN(pool.spotPrice),
+        curveType: pool.curveType,
+        curveDelta: new anchor.BN(pool.curveDelta),
+        sellCurveDelta: new anchor.BN(pool.sellCurveDelta),
+        curveAnchorPrice: new anchor.BN(pool.curveAnchorPrice),
+        asymmetricCurveDelta: pool.asymmetricCurveDelta,
+        buysidePaymentAmount: new anchor.BN(pool.buysidePaymentAmount),
+        sellsideAssetAmount: new anchor.BN(pool.sellsideAssetAmount),
+        priceLadder: [],
+        priceLadderPosition: new anchor.BN(0),
+        dutchAuction: {
+          floor: new anchor.BN(0),
+          decayPerSecond: new anchor.BN(0),
+        },
+        dutchAuctionStartedAt: new anchor.BN(0),
+      };
+      const getPrices = () =>
+        getSolTotalPriceAndNextPrice(
+          params,
+          n,
+          fulfillBuy,
+          allowlistMultiplierBp,
+        );
+
+      if (price.error) {
+        assert.throws(getPrices, price.error);
+        return;
+      }
+      const { totalPrice, nextPrice } = getPrices();
+      assert.equal(totalPrice.toString(), price.totalPrice);
+      assert.equal(nextPrice.toString(), price.nextPrice);
+    });
+  });
+});
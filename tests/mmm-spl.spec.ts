@@ -0,0 +1,289 @@
+import * as anchor from '@project-serum/anchor';
+import {
+  createMint,
+  getAccount as getTokenAccount,
+  getAssociatedTokenAddress,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  Mmm,
+  AllowlistKind,
+  getMMMBuysideSolEscrowPDA,
+  getMMMSellStatePDA,
+  IDL,
+  MMMProgramID,
+} from '../sdk/src';
+import {
+  airdrop,
+  createPoolWithExampleDeposits,
+  getGlobalConfig,
+  getMetaplexInstance,
+} from './utils';
+
+describe('mmm-spl', () => {
+  const { connection } = anchor.AnchorProvider.env();
+  const wallet = new anchor.Wallet(Keypair.generate());
+  const provider = new anchor.AnchorProvider(connection, wallet, {
+    commitment: 'processed',
+  });
+  const program = new anchor.Program(
+    IDL,
+    MMMProgramID,
+    provider,
+  ) as anchor.Program<Mmm>;
+  const cosigner = Keypair.generate();
+  const metaplexInstance = getMetaplexInstance(connection);
+  let globalConfig: PublicKey;
+  let treasury: PublicKey;
+
+  before(async () => {
+    ({ globalConfig, treasury } = await getGlobalConfig(program));
+  });
+
+  beforeEach(async () => {
+    await airdrop(connection, wallet.publicKey, 50);
+  });
+
+  // the payment mint has the same decimals as SOL, so that the prices read the
+  // same as the prices of the sol pools
+  const createPaymentMint = async () => {
+    const paymentMint = await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      null,
+      9,
+    );
+    const ownerPaymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentMint,
+      wallet.publicKey,
+    );
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentMint,
+      ownerPaymentTokenAccount.address,
+      wallet.publicKey,
+      100 * LAMPORTS_PER_SOL,
+    );
+    return { paymentMint, ownerPaymentTokenAccount };
+  };
+
+  const createSplPool = async (paymentMint: PublicKey, seller: PublicKey) =>
+    createPoolWithExampleDeposits(
+      program,
+      connection,
+      [AllowlistKind.fvca],
+      { owner: wallet.publicKey, cosigner, paymentMint },
+      'sell',
+      seller,
+    );
+
+  const splDepositBuy = async (
+    poolKey: PublicKey,
+    paymentMint: PublicKey,
+    ownerPaymentTokenAccount: PublicKey,
+    paymentAmount: number,
+  ) =>
+    program.methods
+      .splDepositBuy({ paymentAmount: new anchor.BN(paymentAmount) })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolKey,
+        paymentMint,
+        ownerPaymentTokenAccount,
+        buysidePaymentEscrowAccount: await getAssociatedTokenAddress(
+          paymentMint,
+          poolKey,
+          true,
+        ),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([cosigner])
+      .rpc();
+
+  it('trades in the spl payment mint', async () => {
+    const seller = Keypair.generate();
+    const [{ paymentMint, ownerPaymentTokenAccount }] = await Promise.all([
+      createPaymentMint(),
+      airdrop(connection, seller.publicKey, 10),
+    ]);
+    const poolData = await createSplPool(paymentMint, seller.publicKey);
+    const buysidePaymentEscrowAccount = await getAssociatedTokenAddress(
+      paymentMint,
+      poolData.poolKey,
+      true,
+    );
+
+    await splDepositBuy(
+      poolData.poolKey,
+      paymentMint,
+      ownerPaymentTokenAccount.address,
+      10 * LAMPORTS_PER_SOL,
+    );
+    let poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.deepEqual(poolAccountInfo.paymentMint, paymentMint);
+    assert.equal(
+      poolAccountInfo.buysidePaymentAmount.toNumber(),
+      10 * LAMPORTS_PER_SOL,
+    );
+
+    // the seller sells the extra nft into the pool for the payment mint
+    const { key: sellState } = getMMMSellStatePDA(
+      program.programId,
+      poolData.poolKey,
+      poolData.extraNft.mintAddress,
+    );
+    const sellerPaymentTokenAccount = await getAssociatedTokenAddress(
+      paymentMint,
+      seller.publicKey,
+    );
+    await program.methods
+      .splFulfillBuy({
+        assetAmount: new anchor.BN(1),
+        minPaymentAmount: new anchor.BN(0.9 * LAMPORTS_PER_SOL),
+        allowlistAux: '',
+        takerFeeBp: 0,
+        makerFeeBp: 0,
+      })
+      .accountsStrict({
+        payer: seller.publicKey,
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        referral: poolData.referral.publicKey,
+        pool: poolData.poolKey,
+        globalConfig,
+        treasury,
+        paymentMint,
+        buysidePaymentEscrowAccount,
+        payerPaymentTokenAccount: sellerPaymentTokenAccount,
+        ownerPaymentTokenAccount: ownerPaymentTokenAccount.address,
+        referralPaymentTokenAccount: await getAssociatedTokenAddress(
+          paymentMint,
+          poolData.referral.publicKey,
+        ),
+        treasuryPaymentTokenAccount: await getAssociatedTokenAddress(
+          paymentMint,
+          treasury,
+        ),
+        assetMetadata: poolData.extraNft.metadataAddress,
+        assetMasterEdition: metaplexInstance
+          .nfts()
+          .pdas()
+          .masterEdition({ mint: poolData.extraNft.mintAddress }),
+        assetMint: poolData.extraNft.mintAddress,
+        payerAssetAccount: poolData.extraNft.tokenAddress!,
+        sellsideEscrowTokenAccount: poolData.poolAtaExtraNft,
+        ownerTokenAccount: await getAssociatedTokenAddress(
+          poolData.extraNft.mintAddress,
+          wallet.publicKey,
+        ),
+        allowlistAuxAccount: SystemProgram.programId,
+        sellState,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([seller, cosigner])
+      .rpc();
+
+    const sellerPaymentBalance = Number(
+      (await getTokenAccount(connection, sellerPaymentTokenAccount)).amount,
+    );
+    assert.isAtLeast(sellerPaymentBalance, 0.9 * LAMPORTS_PER_SOL);
+    assert.isAtMost(sellerPaymentBalance, 1 * LAMPORTS_PER_SOL);
+    poolAccountInfo = await program.account.pool.fetch(poolData.poolKey);
+    assert.equal(
+      poolAccountInfo.buysidePaymentAmount.toNumber(),
+      Number(
+        (await getTokenAccount(connection, buysidePaymentEscrowAccount)).amount,
+      ),
+    );
+
+    const getOwnerPaymentBalance = async () =>
+      Number(
+        (await getTokenAccount(connection, ownerPaymentTokenAccount.address))
+          .amount,
+      );
+    const initOwnerPaymentBalance = await getOwnerPaymentBalance();
+    await program.methods
+      .splWithdrawBuy({ paymentAmount: new anchor.BN(LAMPORTS_PER_SOL) })
+      .accountsStrict({
+        owner: wallet.publicKey,
+        cosigner: cosigner.publicKey,
+        pool: poolData.poolKey,
+        paymentMint,
+        ownerPaymentTokenAccount: ownerPaymentTokenAccount.address,
+        buysidePaymentEscrowAccount,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([cosigner])
+      .rpc();
+    assert.equal(
+      await getOwnerPaymentBalance(),
+      initOwnerPaymentBalance + LAMPORTS_PER_SOL,
+    );
+  });
+
+  it('rejects the other payment mints', async () => {
+    const seller = Keypair.generate();
+    const [{ paymentMint }, other] = await Promise.all([
+      createPaymentMint(),
+      createPaymentMint(),
+    ]);
+    const poolData = await createSplPool(paymentMint, seller.publicKey);
+
+    try {
+      await splDepositBuy(
+        poolData.poolKey,
+        other.paymentMint,
+        other.ownerPaymentTokenAccount.address,
+        LAMPORTS_PER_SOL,
+      );
+      assert.fail('Should have failed with another payment mint');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidPaymentMint');
+    }
+
+    try {
+      await program.methods
+        .solDepositBuy({ paymentAmount: new anchor.BN(LAMPORTS_PER_SOL) })
+        .accountsStrict({
+          owner: wallet.publicKey,
+          cosigner: cosigner.publicKey,
+          pool: poolData.poolKey,
+          buysideSolEscrowAccount: getMMMBuysideSolEscrowPDA(
+            program.programId,
+            poolData.poolKey,
+          ).key,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([cosigner])
+        .rpc();
+      assert.fail('Should have failed with sol on an spl pool');
+    } catch (e) {
+      assert.include(`${e}`, 'InvalidPaymentMint');
+    }
+  });
+});
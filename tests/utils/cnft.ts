@@ -0,0 +1,136 @@
+import {
+  createCreateTreeInstruction,
+  createMintV1Instruction,
+  getLeafAssetId,
+  MetadataArgs,
+  PROGRAM_ID as BUBBLEGUM_PROGRAM_ID,
+  TokenProgramVersion,
+  TokenStandard,
+  TreeConfig,
+} from '@metaplex-foundation/mpl-bubblegum';
+import * as anchor from '@project-serum/anchor';
+import {
+  ConcurrentMerkleTreeAccount,
+  createAllocTreeIx,
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+  SPL_NOOP_PROGRAM_ID,
+} from '@solana/spl-account-compression';
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  Transaction,
+} from '@solana/web3.js';
+
+export { BUBBLEGUM_PROGRAM_ID };
+
+// the canopy covers the whole tree, so the transfers don't need proof accounts
+const TREE_MAX_DEPTH = 3;
+const TREE_MAX_BUFFER_SIZE = 8;
+
+export const getTreeAuthority = (merkleTree: PublicKey) =>
+  PublicKey.findProgramAddressSync(
+    [merkleTree.toBuffer()],
+    BUBBLEGUM_PROGRAM_ID,
+  )[0];
+
+export const createTree = async (conn: Connection, payer: Keypair) => {
+  const merkleTree = Keypair.generate();
+  const treeAuthority = getTreeAuthority(merkleTree.publicKey);
+  const allocTreeIx = await createAllocTreeIx(
+    conn,
+    merkleTree.publicKey,
+    payer.publicKey,
+    { maxDepth: TREE_MAX_DEPTH, maxBufferSize: TREE_MAX_BUFFER_SIZE },
+    TREE_MAX_DEPTH,
+  );
+  const createTreeIx = createCreateTreeInstruction(
+    {
+      treeAuthority,
+      merkleTree: merkleTree.publicKey,
+      payer: payer.publicKey,
+      treeCreator: payer.publicKey,
+      logWrapper: SPL_NOOP_PROGRAM_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+    },
+    {
+      maxDepth: TREE_MAX_DEPTH,
+      maxBufferSize: TREE_MAX_BUFFER_SIZE,
+      public: false,
+    },
+  );
+  await sendAndConfirmTransaction(
+    conn,
+    new Transaction().add(allocTreeIx, createTreeIx),
+    [payer, merkleTree],
+  );
+  return { merkleTree: merkleTree.publicKey, treeAuthority };
+};
+
+export const getTreeRoot = async (conn: Connection, merkleTree: PublicKey) =>
+  Array.from(
+    (
+      await ConcurrentMerkleTreeAccount.fromAccountAddress(conn, merkleTree)
+    ).getCurrentRoot(),
+  );
+
+// mintCnft mints a compressed nft to the owner with the tree creator as the
+// payer, and returns the metadata args in the shape of the mmm instructions
+export const mintCnft = async (
+  conn: Connection,
+  payer: Keypair,
+  args: {
+    merkleTree: PublicKey;
+    owner: PublicKey;
+    creators: { address: PublicKey; verified: boolean; share: number }[];
+  },
+) => {
+  const treeAuthority = getTreeAuthority(args.merkleTree);
+  const { numMinted } = await TreeConfig.fromAccountAddress(
+    conn,
+    treeAuthority,
+  );
+  const nonce = new anchor.BN(numMinted.toString());
+  const message: MetadataArgs = {
+    name: 'cnft',
+    symbol: 'CNFT',
+    uri: 'https://example.com/cnft.json',
+    sellerFeeBasisPoints: 100,
+    primarySaleHappened: false,
+    isMutable: true,
+    editionNonce: null,
+    tokenStandard: TokenStandard.NonFungible,
+    collection: null,
+    uses: null,
+    tokenProgramVersion: TokenProgramVersion.Original,
+    creators: args.creators,
+  };
+  const mintIx = createMintV1Instruction(
+    {
+      treeAuthority,
+      leafOwner: args.owner,
+      leafDelegate: args.owner,
+      merkleTree: args.merkleTree,
+      payer: payer.publicKey,
+      treeDelegate: payer.publicKey,
+      logWrapper: SPL_NOOP_PROGRAM_ID,
+      compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+    },
+    { message },
+  );
+  await sendAndConfirmTransaction(conn, new Transaction().add(mintIx), [
+    payer,
+  ]);
+
+  return {
+    assetId: await getLeafAssetId(args.merkleTree, nonce),
+    nonce,
+    index: nonce.toNumber(),
+    metadataArgs: {
+      ...message,
+      tokenStandard: { nonFungible: {} },
+      tokenProgramVersion: { original: {} },
+    },
+  };
+};
@@ -77,6 +77,14 @@ export const getEmptyAllowLists = (num: number) => {
   return new Array(num).fill(emptyAllowList);
 };
 
+export const getEmptyPriceLadder = (num = 6) => {
+  const emptyStep = {
+    price: new anchor.BN(0),
+    amount: new anchor.BN(0),
+  };
+  return new Array(num).fill(emptyStep);
+};
+
 export const airdrop = async (
   connection: Connection,
   to: PublicKey,
@@ -1,5 +1,7 @@
+export * from './cnft';
 export * from './generic';
 export * from './mip1';
 export * from './mmm';
+export * from './mplCore';
 export * from './nfts';
 export * from './ocp';
@@ -22,15 +22,19 @@ import {
 import {
   AllowlistKind,
   CurveKind,
+  DutchAuction,
   getMMMBuysideSolEscrowPDA,
+  getMMMGlobalConfigPDA,
   getMMMPoolPDA,
   getMMMSellStatePDA,
   getTokenRecordPDA,
   Mmm,
 } from '../../sdk/src';
 import {
+  airdrop,
   fillAllowlists,
   getEmptyAllowLists,
+  getEmptyPriceLadder,
   getKeypair,
   MIP1_COMPUTE_UNITS,
   OCP_COMPUTE_UNITS,
@@ -41,6 +45,57 @@ import { createTestMintAndTokenOCP } from './ocp';
 import { PROGRAM_ID as TOKEN_METADATA_PROGRAM_ID } from '@metaplex-foundation/mpl-token-metadata';
 import { PROGRAM_ID as AUTHORIZATION_RULES_PROGRAM_ID } from '@metaplex-foundation/mpl-token-auth-rules';
 
+const BPF_LOADER_UPGRADEABLE_PROGRAM_ID = new PublicKey(
+  'BPFLoaderUpgradeab1e11111111111111111111111',
+);
+
+// the global config admin is the upgrade authority of the program, i.e. the
+// local keypair that deploys it for the tests
+export const getGlobalConfigAdminProgram = (program: Program<Mmm>) =>
+  new anchor.Program<Mmm>(
+    program.idl,
+    program.programId,
+    new anchor.AnchorProvider(
+      program.provider.connection,
+      new anchor.Wallet(getKeypair()),
+      { commitment: 'processed' },
+    ),
+  );
+
+let globalConfigInit: Promise<void> | undefined = undefined;
+// getGlobalConfig initializes the global config once for all the specs, without
+// protocol fee, and returns the accounts that the fulfills need
+export const getGlobalConfig = async (program: Program<Mmm>) => {
+  const { key: globalConfig } = getMMMGlobalConfigPDA(program.programId);
+  if (!globalConfigInit) {
+    globalConfigInit = (async () => {
+      if (await program.account.globalConfig.fetchNullable(globalConfig)) {
+        return;
+      }
+      const treasury = Keypair.generate();
+      await airdrop(program.provider.connection, treasury.publicKey, 1);
+      const [programData] = PublicKey.findProgramAddressSync(
+        [program.programId.toBuffer()],
+        BPF_LOADER_UPGRADEABLE_PROGRAM_ID,
+      );
+      await getGlobalConfigAdminProgram(program)
+        .methods.initGlobalConfig({ protocolFeeBp: 0 })
+        .accountsStrict({
+          admin: getKeypair().publicKey,
+          globalConfig,
+          treasury: treasury.publicKey,
+          program: program.programId,
+          programData,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    })();
+  }
+  await globalConfigInit;
+  const { treasury } = await program.account.globalConfig.fetch(globalConfig);
+  return { globalConfig, treasury };
+};
+
 export const createPool = async (
   program: Program<Mmm>,
   args: {
@@ -53,6 +108,9 @@ export const createPool = async (
     reinvestFulfillBuy?: boolean;
     reinvestFulfillSell?: boolean;
     expiry?: anchor.BN;
+    startTime?: anchor.BN;
+    buysideExpiry?: anchor.BN;
+    sellsideExpiry?: anchor.BN;
     lpFeeBp?: number;
     referral?: PublicKey;
     referralBp?: number;
@@ -60,6 +118,8 @@ export const createPool = async (
     uuid?: PublicKey;
     paymentMint?: PublicKey;
     buysideCreatorRoyaltyBp?: number;
+    priceLadder?: ReturnType<typeof getEmptyPriceLadder>;
+    dutchAuction?: DutchAuction | null;
   },
 ) => {
   const referral = Keypair.generate();
@@ -77,14 +137,20 @@ export const createPool = async (
     spotPrice: new anchor.BN(1 * LAMPORTS_PER_SOL),
     curveType: CurveKind.linear,
     curveDelta: new anchor.BN(0),
+    sellCurveDelta: null,
     reinvestFulfillBuy: true,
     reinvestFulfillSell: true,
     expiry: new anchor.BN(0),
+    startTime: new anchor.BN(0),
+    buysideExpiry: new anchor.BN(0),
+    sellsideExpiry: new anchor.BN(0),
     lpFeeBp: 200,
     referral: referral.publicKey,
     referralBp: 300,
     cosignerAnnotation: new Array(32).fill(0),
     buysideCreatorRoyaltyBp: 0,
+    priceLadder: getEmptyPriceLadder(),
+    dutchAuction: null,
 
     owner: args.owner,
     cosigner: args.cosigner?.publicKey ?? args.owner,
@@ -94,10 +160,12 @@ export const createPool = async (
   };
   const { owner, cosigner: _, ...overrides } = args;
   const finalArgs = { ...defaults, ...overrides };
+  const { globalConfig } = await getGlobalConfig(program);
   let builder = program.methods.createPool(finalArgs).accountsStrict({
     owner: args.owner,
     cosigner: finalArgs.cosigner,
     pool: poolKey,
+    globalConfig,
     systemProgram: SystemProgram.programId,
   });
   if (args.cosigner) {
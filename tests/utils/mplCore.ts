@@ -0,0 +1,95 @@
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  sendAndConfirmTransaction,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js';
+
+export const MPL_CORE_PROGRAM_ID = new PublicKey(
+  'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d',
+);
+
+// the instruction discriminators of mpl-core
+const CREATE_V1 = 0;
+const CREATE_COLLECTION_V1 = 1;
+
+const serializeString = (value: string) => {
+  const bytes = Buffer.from(value, 'utf8');
+  const len = Buffer.alloc(4);
+  len.writeUInt32LE(bytes.length);
+  return Buffer.concat([len, bytes]);
+};
+
+// the optional accounts of mpl-core are set to the program id when omitted
+const optionalAccount = (pubkey: PublicKey | undefined, isSigner = false) => ({
+  pubkey: pubkey ?? MPL_CORE_PROGRAM_ID,
+  isSigner: !!pubkey && isSigner,
+  isWritable: false,
+});
+
+// createMplCoreCollection creates a core collection without plugins, the payer
+// is the update authority of the collection
+export const createMplCoreCollection = async (
+  conn: Connection,
+  payer: Keypair,
+) => {
+  const collection = Keypair.generate();
+  const ix = new TransactionInstruction({
+    programId: MPL_CORE_PROGRAM_ID,
+    keys: [
+      { pubkey: collection.publicKey, isSigner: true, isWritable: true },
+      optionalAccount(undefined), // update_authority, defaults to the payer
+      { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.concat([
+      Buffer.from([CREATE_COLLECTION_V1]),
+      serializeString('collection'),
+      serializeString('https://example.com/collection.json'),
+      Buffer.from([0]), // plugins: None
+    ]),
+  });
+  await sendAndConfirmTransaction(conn, new Transaction().add(ix), [
+    payer,
+    collection,
+  ]);
+  return collection.publicKey;
+};
+
+// createMplCoreAsset creates a core asset without plugins, owned by the owner,
+// and in the collection if it's given. The payer has to be the update authority
+// of the collection.
+export const createMplCoreAsset = async (
+  conn: Connection,
+  payer: Keypair,
+  args: { owner: PublicKey; collection?: PublicKey },
+) => {
+  const asset = Keypair.generate();
+  const ix = new TransactionInstruction({
+    programId: MPL_CORE_PROGRAM_ID,
+    keys: [
+      { pubkey: asset.publicKey, isSigner: true, isWritable: true },
+      { ...optionalAccount(args.collection), isWritable: !!args.collection },
+      optionalAccount(args.collection && payer.publicKey, true), // authority
+      { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+      optionalAccount(args.owner),
+      optionalAccount(undefined), // update_authority, defaults to the payer
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      optionalAccount(undefined), // log_wrapper
+    ],
+    data: Buffer.concat([
+      Buffer.from([CREATE_V1, 0]), // data_state: AccountState
+      serializeString('asset'),
+      serializeString('https://example.com/asset.json'),
+      Buffer.from([0]), // plugins: None
+    ]),
+  });
+  await sendAndConfirmTransaction(conn, new Transaction().add(ix), [
+    payer,
+    asset,
+  ]);
+  return asset.publicKey;
+};